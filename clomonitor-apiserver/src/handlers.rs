@@ -4,7 +4,7 @@ use crate::{
     views::DynVT,
 };
 use anyhow::Error;
-use askama_axum::Template;
+use askama::Template;
 use axum::{
     body::Body,
     extract::{Path, Query, RawQuery, State},
@@ -15,6 +15,7 @@ use axum::{
     response::{self, IntoResponse},
 };
 use clomonitor_core::{
+    diff::{self, Diff},
     linter::{CheckSet, Report},
     score::Score,
 };
@@ -218,6 +219,106 @@ pub(crate) async fn project_snapshot(
     }
 }
 
+/// Project data used to compare a project's snapshot against its current data.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ProjectData {
+    #[serde(default)]
+    repositories: Vec<RepositoryData>,
+}
+
+/// Repository data used to compare a project's snapshot against its current
+/// data.
+#[derive(Debug, Clone, Deserialize)]
+struct RepositoryData {
+    name: String,
+    score: Option<Score>,
+    report: Option<RepositoryReportData>,
+}
+
+/// Repository report data.
+#[derive(Debug, Clone, Deserialize)]
+struct RepositoryReportData {
+    data: Option<Report>,
+}
+
+/// Differences between a repository's report in a snapshot and its current
+/// one.
+#[derive(Debug, Clone, Serialize)]
+struct RepositoryDiff {
+    name: String,
+    diff: Diff,
+}
+
+/// Handler that returns the differences between the requested project
+/// snapshot and the project's current data.
+pub(crate) async fn project_snapshot_diff(
+    State(db): State<DynDB>,
+    Path((foundation, project, date)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    // Parse date
+    let date: Date =
+        Date::parse(&date, &SNAPSHOT_DATE_FORMAT).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    // Get project snapshot and current data from database
+    let Some(snapshot) = db
+        .project_snapshot(&foundation, &project, &date)
+        .await
+        .map_err(internal_error)?
+    else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+    let Some(current) = db
+        .project_data(&foundation, &project)
+        .await
+        .map_err(internal_error)?
+    else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    // Compare the reports of the repositories in both
+    let snapshot: ProjectData = serde_json::from_str(&snapshot).map_err(internal_error)?;
+    let current: ProjectData = serde_json::from_str(&current).map_err(internal_error)?;
+    let diffs = diff_repositories(&snapshot.repositories, &current.repositories);
+
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
+        (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+    ];
+    Ok((headers, json!({ "repositories": diffs }).to_string()))
+}
+
+/// Compare the reports of the base and head repositories provided, matching
+/// them by name. Repositories that are only available on one side are
+/// compared against an empty report.
+fn diff_repositories(base: &[RepositoryData], head: &[RepositoryData]) -> Vec<RepositoryDiff> {
+    let report_and_score = |repository: Option<&RepositoryData>| {
+        let report = repository
+            .and_then(|r| r.report.as_ref())
+            .and_then(|r| r.data.clone())
+            .unwrap_or_default();
+        let score = repository.and_then(|r| r.score.clone()).unwrap_or_default();
+        (report, score)
+    };
+
+    let mut names: Vec<&str> = head.iter().map(|r| r.name.as_str()).collect();
+    names.extend(
+        base.iter()
+            .map(|r| r.name.as_str())
+            .filter(|name| !head.iter().any(|r| r.name == *name)),
+    );
+    names
+        .into_iter()
+        .map(|name| {
+            let (base_report, base_score) = report_and_score(base.iter().find(|r| r.name == name));
+            let (head_report, head_score) = report_and_score(head.iter().find(|r| r.name == name));
+            RepositoryDiff {
+                name: name.to_string(),
+                diff: diff::diff(&base_report, &base_score, &head_report, &head_score),
+            }
+        })
+        .collect()
+}
+
 /// Template for the report summary SVG image.
 #[derive(Debug, Clone, Template)]
 #[template(path = "report-summary.svg")]
@@ -292,9 +393,15 @@ pub(crate) async fn report_summary_svg(
     // Render report summary SVG and return it if the score was found
     match score {
         Some(score) => {
-            let headers = [(CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}"))];
             let theme = params.get("theme").cloned();
-            Ok((headers, ReportSummaryTemplate::new(score, theme)))
+            let svg = ReportSummaryTemplate::new(score, theme)
+                .render()
+                .map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
+                (CONTENT_TYPE, ReportSummaryTemplate::MIME_TYPE.to_string()),
+            ];
+            Ok((headers, svg))
         }
         None => Err(StatusCode::NOT_FOUND),
     }
@@ -340,8 +447,15 @@ pub(crate) async fn repository_report_md(
     // Render repository report in markdown format and return it
     match report_md {
         Some(report_md) => {
-            let headers = [(CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}"))];
-            Ok((headers, report_md))
+            let md = report_md.render().map_err(internal_error)?;
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
+                (
+                    CONTENT_TYPE,
+                    RepositoryReportMDTemplate::MIME_TYPE.to_string(),
+                ),
+            ];
+            Ok((headers, md))
         }
         None => Err(StatusCode::NOT_FOUND),
     }
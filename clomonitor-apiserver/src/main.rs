@@ -52,7 +52,7 @@ async fn main() -> Result<()> {
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
         _ => s.init(),
-    };
+    }
 
     // Setup database
     debug!("setting up database");
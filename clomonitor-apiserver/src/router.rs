@@ -65,6 +65,10 @@ pub(crate) fn setup(cfg: &Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
             "/projects/:foundation/:project/snapshots/:date",
             get(project_snapshot),
         )
+        .route(
+            "/projects/:foundation/:project/snapshots/:date/diff",
+            get(project_snapshot_diff),
+        )
         .route("/stats", get(stats))
         .route("/stats/snapshots/:date", get(stats_snapshot));
 
@@ -136,7 +140,7 @@ mod tests {
     use mime::{APPLICATION_JSON, CSV, HTML};
     use mockall::predicate::*;
     use serde_json::json;
-    use std::{fs, future, sync::Arc};
+    use std::{collections::BTreeMap, fs, future, sync::Arc};
     use tera::Context;
     use time::Date;
     use tokio::sync::RwLock;
@@ -440,6 +444,105 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn project_snapshot_diff_found() {
+        let project_data = |readme: CheckOutput, score: f64| {
+            json!({
+                "repositories": [{
+                    "name": "repo",
+                    "score": Score {
+                        global: score,
+                        global_weight: 10,
+                        documentation: Some(score),
+                        documentation_weight: Some(10),
+                        ..Score::default()
+                    },
+                    "report": {
+                        "data": Report {
+                            documentation: Documentation {
+                                readme: Some(readme),
+                                ..Documentation::default()
+                            },
+                            ..Report::default()
+                        },
+                    },
+                }],
+            })
+            .to_string()
+        };
+        let snapshot_data = project_data(CheckOutput::not_passed(), 50.0);
+        let current_data = project_data(CheckOutput::passed(), 100.0);
+
+        let mut db = MockDB::new();
+        db.expect_project_snapshot()
+            .with(
+                eq(FOUNDATION),
+                eq(PROJECT),
+                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
+            )
+            .times(1)
+            .returning(move |_, _, _| Box::pin(future::ready(Ok(Some(snapshot_data.clone())))));
+        db.expect_project_data()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(move |_, _| Box::pin(future::ready(Ok(Some(current_data.clone())))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}/diff"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let diff: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(diff["repositories"][0]["name"], json!("repo"));
+        assert_eq!(
+            diff["repositories"][0]["diff"]["checks"],
+            json!([{
+                "check_id": "readme",
+                "section": "documentation",
+                "base": "not_passed",
+                "head": "passed",
+            }])
+        );
+        assert_eq!(
+            diff["repositories"][0]["diff"]["score"]["documentation"],
+            json!({"base": 50.0, "head": 100.0, "delta": 50.0})
+        );
+    }
+
+    #[tokio::test]
+    async fn project_snapshot_diff_snapshot_not_found() {
+        let mut db = MockDB::new();
+        db.expect_project_snapshot()
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}/diff"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn project_snapshot_not_found() {
         let mut db = MockDB::new();
@@ -526,6 +629,7 @@ mod tests {
             response.headers()[CACHE_CONTROL],
             format!("max-age={DEFAULT_API_MAX_AGE}")
         );
+        assert_eq!(response.headers()[CONTENT_TYPE], "image/svg+xml");
         let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
         let golden_path = "src/testdata/project-report-summary.golden.svg";
         // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
@@ -612,16 +716,25 @@ mod tests {
                     }),
                     report: Some(Report {
                         documentation: Documentation {
-                            adopters: Some(CheckOutput::passed()),
+                            adopters: Some(CheckOutput::passed().evidence(Some(Evidence {
+                                file: "README.md".to_string(),
+                                line: Some(7),
+                                pattern: Some(r"(?im)^#+.*adopters.*$".to_string()),
+                            }))),
                             code_of_conduct: Some(CheckOutput::passed()),
                             contributing: Some(CheckOutput::passed()),
                             changelog: Some(CheckOutput::passed()),
-                            governance: Some(CheckOutput::passed()),
+                            governance: Some(CheckOutput::passed().evidence(Some(Evidence {
+                                file: "GOVERNANCE.md".to_string(),
+                                line: None,
+                                pattern: Some("governance*".to_string()),
+                            }))),
                             maintainers: Some(CheckOutput::passed()),
                             readme: Some(CheckOutput::passed()),
                             roadmap: Some(CheckOutput::passed()),
                             summary_table: Some(CheckOutput::passed()),
                             website: Some(CheckOutput::passed()),
+                            ..Default::default()
                         },
                         license: License {
                             license_approved: Some(CheckOutput::passed()),
@@ -632,20 +745,26 @@ mod tests {
                             license_spdx_id: Some(
                                 CheckOutput::passed().value(Some("Apache-2.0".to_string())),
                             ),
+                            ..Default::default()
                         },
                         best_practices: BestPractices {
                             artifacthub_badge: Some(CheckOutput::exempt()),
                             cla: Some(CheckOutput::passed()),
                             community_meeting: Some(CheckOutput::passed()),
+                            contributor_diversity: Some(CheckOutput::passed()),
                             dco: Some(CheckOutput::passed()),
                             github_discussions: Some(CheckOutput::passed()),
                             openssf_badge: Some(CheckOutput::passed()),
                             openssf_scorecard_badge: Some(CheckOutput::passed()),
                             recent_release: Some(CheckOutput::passed()),
+                            release_cadence: Some(CheckOutput::passed()),
+                            responsiveness: Some(CheckOutput::passed()),
                             slack_presence: Some(CheckOutput::passed()),
+                            ..Default::default()
                         },
                         security: Security {
                             binary_artifacts: Some(CheckOutput::passed()),
+                            branch_protection: Some(CheckOutput::passed()),
                             code_review: Some(CheckOutput::passed()),
                             dangerous_workflow: Some(CheckOutput::passed()),
                             dependencies_policy: Some(CheckOutput::passed()),
@@ -656,10 +775,16 @@ mod tests {
                             security_policy: Some(CheckOutput::passed()),
                             signed_releases: Some(CheckOutput::passed()),
                             token_permissions: Some(CheckOutput::passed()),
+                            ..Default::default()
                         },
                         legal: Legal {
                             trademark_disclaimer: Some(CheckOutput::passed()),
+                            ..Default::default()
                         },
+                        scorecard_source: None,
+                        weights: BTreeMap::new(),
+                        warnings: vec![],
+                        durations: BTreeMap::new(),
                     }),
                 };
                 Box::pin(future::ready(Ok(Some(report_md))))
@@ -683,6 +808,7 @@ mod tests {
             response.headers()[CACHE_CONTROL],
             format!("max-age={DEFAULT_API_MAX_AGE}")
         );
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/markdown");
         let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
         let golden_path = "src/testdata/repository-report.golden.md";
         // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
@@ -690,6 +816,59 @@ mod tests {
         assert_eq!(body, golden);
     }
 
+    #[tokio::test]
+    async fn repository_report_md_remediation() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| {
+                let report_md = RepositoryReportMDTemplate {
+                    name: "artifact-hub".to_string(),
+                    url: "https://github.com/artifacthub/hub".to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    score: Some(Score {
+                        security: Some(0.0),
+                        security_weight: Some(1),
+                        ..Score::default()
+                    }),
+                    report: Some(Report {
+                        security: Security {
+                            security_policy: Some(CheckOutput::not_passed().remediation(Some(
+                                Remediation {
+                                    description: "Add a SECURITY.md file".to_string(),
+                                    templates: vec![],
+                                    docs_anchor: "security-policy".to_string(),
+                                },
+                            ))),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+                };
+                Box::pin(future::ready(Ok(Some(report_md))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/report.md"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains(
+            "  - [ ] Security policy ([_docs_](https://clomonitor.io/docs/topics/checks/#security-policy)) _how to fix_: Add a SECURITY.md file\n"
+        ));
+    }
+
     #[tokio::test]
     async fn repository_report_md_not_found() {
         let mut db = MockDB::new();
@@ -0,0 +1,416 @@
+use crate::{
+    linter::{CheckStatus, Report, Section},
+    score::Score,
+};
+use serde::{Deserialize, Serialize};
+
+/// Differences between two lint runs (base and head) of a repository.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Diff {
+    /// Checks whose status changed between both runs.
+    pub checks: Vec<CheckChange>,
+
+    /// Score changes, globally and per section.
+    pub score: ScoreDiff,
+
+    /// Checks exempted in head that were not exempted in base.
+    pub exemptions_added: Vec<String>,
+
+    /// Checks exempted in base that are not exempted in head anymore.
+    pub exemptions_removed: Vec<String>,
+}
+
+impl Diff {
+    /// Get the regressions found in head compared to base: checks that
+    /// passed in base and don't pass in head, as well as the global score
+    /// when it dropped more than the tolerance provided.
+    #[must_use]
+    pub fn regressions(&self, score_tolerance: f64) -> Regressions {
+        Regressions {
+            checks: self
+                .checks
+                .iter()
+                .filter(|change| change.is_regression())
+                .cloned()
+                .collect(),
+            score: self
+                .score
+                .global
+                .delta
+                .map_or(false, |delta| -delta > score_tolerance)
+                .then(|| self.score.global.clone()),
+        }
+    }
+}
+
+/// Regressions found in a lint run compared to a baseline one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Regressions {
+    /// Checks that passed in the baseline and don't pass anymore (or weren't
+    /// evaluated).
+    pub checks: Vec<CheckChange>,
+
+    /// Global score change, only set when it dropped more than the tolerance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<ScoreChange>,
+}
+
+impl Regressions {
+    /// Check if no regressions were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty() && self.score.is_none()
+    }
+}
+
+/// Change in the status of a check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckChange {
+    pub check_id: String,
+    pub section: Section,
+
+    /// Status of the check in the base run (none when it wasn't run).
+    pub base: Option<CheckStatus>,
+
+    /// Status of the check in the head run (none when it wasn't run).
+    pub head: Option<CheckStatus>,
+}
+
+impl CheckChange {
+    /// Check if the check passed in base and doesn't pass in head. Checks
+    /// that couldn't be evaluated or weren't run in head count as regressions
+    /// too, whereas checks exempted in head don't.
+    #[must_use]
+    pub fn is_regression(&self) -> bool {
+        matches!(self.base, Some(CheckStatus::Passed | CheckStatus::Warning))
+            && !matches!(
+                self.head,
+                Some(CheckStatus::Passed | CheckStatus::Warning | CheckStatus::Exempt)
+            )
+    }
+}
+
+/// Score changes, globally and per section.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreDiff {
+    pub global: ScoreChange,
+    pub documentation: ScoreChange,
+    pub license: ScoreChange,
+    pub best_practices: ScoreChange,
+    pub security: ScoreChange,
+    pub legal: ScoreChange,
+}
+
+/// Change in a score.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<f64>,
+
+    /// Head score minus base score (only when both are available).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+}
+
+impl ScoreChange {
+    fn new(base: Option<f64>, head: Option<f64>) -> Self {
+        Self {
+            base,
+            head,
+            delta: base.zip(head).map(|(base, head)| head - base),
+        }
+    }
+}
+
+/// Calculate the differences between the base and head lint runs provided.
+#[must_use]
+pub fn diff(
+    base_report: &Report,
+    base_score: &Score,
+    head_report: &Report,
+    head_score: &Score,
+) -> Diff {
+    let mut diff = Diff::default();
+
+    // Checks
+    let base_statuses = base_report.check_statuses();
+    let head_statuses = head_report.check_statuses();
+    let status = |statuses: &[(Section, &str, CheckStatus)], check_id: &str| {
+        statuses
+            .iter()
+            .find(|(_, id, _)| *id == check_id)
+            .map(|(_, _, status)| *status)
+    };
+    for (section, check_id, head) in &head_statuses {
+        let base = status(&base_statuses, check_id);
+        if base != Some(*head) {
+            diff.checks.push(CheckChange {
+                check_id: (*check_id).to_string(),
+                section: *section,
+                base,
+                head: Some(*head),
+            });
+        }
+    }
+    for (section, check_id, base) in &base_statuses {
+        if status(&head_statuses, check_id).is_none() {
+            diff.checks.push(CheckChange {
+                check_id: (*check_id).to_string(),
+                section: *section,
+                base: Some(*base),
+                head: None,
+            });
+        }
+    }
+
+    // Exemptions
+    for change in &diff.checks {
+        let base_exempt = change.base == Some(CheckStatus::Exempt);
+        let head_exempt = change.head == Some(CheckStatus::Exempt);
+        if head_exempt && !base_exempt {
+            diff.exemptions_added.push(change.check_id.clone());
+        } else if base_exempt && !head_exempt {
+            diff.exemptions_removed.push(change.check_id.clone());
+        }
+    }
+
+    // Score
+    diff.score = ScoreDiff {
+        global: ScoreChange::new(Some(base_score.global), Some(head_score.global)),
+        documentation: ScoreChange::new(base_score.documentation, head_score.documentation),
+        license: ScoreChange::new(base_score.license, head_score.license),
+        best_practices: ScoreChange::new(base_score.best_practices, head_score.best_practices),
+        security: ScoreChange::new(base_score.security, head_score.security),
+        legal: ScoreChange::new(base_score.legal, head_score.legal),
+    };
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::*;
+
+    #[test]
+    fn diff_no_changes() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score {
+            global: 100.0,
+            documentation: Some(100.0),
+            ..Score::default()
+        };
+
+        let diff = diff(&report, &score, &report, &score);
+        assert!(diff.checks.is_empty());
+        assert_eq!(
+            diff.score.documentation,
+            ScoreChange {
+                base: Some(100.0),
+                head: Some(100.0),
+                delta: Some(0.0),
+            }
+        );
+        assert_eq!(diff.score.license, ScoreChange::default());
+    }
+
+    #[test]
+    fn diff_with_changes() {
+        let base_report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::exempt()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let base_score = Score {
+            global: 40.0,
+            documentation: Some(40.0),
+            ..Score::default()
+        };
+        let head_report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::not_passed()),
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::exempt()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let head_score = Score {
+            global: 55.5,
+            documentation: Some(50.0),
+            security: Some(100.0),
+            ..Score::default()
+        };
+
+        assert_eq!(
+            diff(&base_report, &base_score, &head_report, &head_score),
+            Diff {
+                checks: vec![
+                    CheckChange {
+                        check_id: adopters::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::Exempt),
+                        head: Some(CheckStatus::NotPassed),
+                    },
+                    CheckChange {
+                        check_id: readme::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::NotPassed),
+                        head: Some(CheckStatus::Passed),
+                    },
+                    CheckChange {
+                        check_id: sbom::ID.to_string(),
+                        section: Section::Security,
+                        base: None,
+                        head: Some(CheckStatus::Exempt),
+                    },
+                    CheckChange {
+                        check_id: roadmap::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::Passed),
+                        head: None,
+                    },
+                ],
+                score: ScoreDiff {
+                    global: ScoreChange {
+                        base: Some(40.0),
+                        head: Some(55.5),
+                        delta: Some(15.5),
+                    },
+                    documentation: ScoreChange {
+                        base: Some(40.0),
+                        head: Some(50.0),
+                        delta: Some(10.0),
+                    },
+                    security: ScoreChange {
+                        base: None,
+                        head: Some(100.0),
+                        delta: None,
+                    },
+                    ..ScoreDiff::default()
+                },
+                exemptions_added: vec![sbom::ID.to_string()],
+                exemptions_removed: vec![adopters::ID.to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn regressions_found() {
+        let base_report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                governance: Some(CheckOutput::passed()),
+                maintainers: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::passed().warning(true)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let base_score = Score {
+            global: 80.0,
+            ..Score::default()
+        };
+        let head_report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::exempt()),
+                governance: Some(CheckOutput::not_evaluated()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let head_score = Score {
+            global: 70.0,
+            ..Score::default()
+        };
+
+        let diff = diff(&base_report, &base_score, &head_report, &head_score);
+        assert_eq!(
+            diff.regressions(5.0),
+            Regressions {
+                checks: vec![
+                    CheckChange {
+                        check_id: governance::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::Passed),
+                        head: Some(CheckStatus::NotEvaluated),
+                    },
+                    CheckChange {
+                        check_id: readme::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::Passed),
+                        head: Some(CheckStatus::NotPassed),
+                    },
+                    CheckChange {
+                        check_id: sbom::ID.to_string(),
+                        section: Section::Security,
+                        base: Some(CheckStatus::Warning),
+                        head: Some(CheckStatus::Failed),
+                    },
+                    CheckChange {
+                        check_id: maintainers::ID.to_string(),
+                        section: Section::Documentation,
+                        base: Some(CheckStatus::Passed),
+                        head: None,
+                    },
+                ],
+                score: Some(ScoreChange {
+                    base: Some(80.0),
+                    head: Some(70.0),
+                    delta: Some(-10.0),
+                }),
+            }
+        );
+        assert!(diff.regressions(10.0).score.is_none());
+    }
+
+    #[test]
+    fn regressions_not_found() {
+        let base_report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let head_report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score::default();
+
+        assert!(diff(&base_report, &score, &head_report, &score)
+            .regressions(0.0)
+            .is_empty());
+    }
+}
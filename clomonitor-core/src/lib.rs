@@ -1,6 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::doc_markdown, clippy::wildcard_imports)]
 
+pub mod diff;
+
 #[allow(clippy::module_name_repetitions)]
 pub mod linter;
 
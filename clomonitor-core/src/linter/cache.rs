@@ -0,0 +1,597 @@
+use super::{
+    check::{Check, CheckId, CheckInput, CheckOutput, RemoteData},
+    checks::datasource::security_insights::SECURITY_INSIGHTS_MANIFEST_FILE,
+    metadata::METADATA_FILE,
+    util::path::{self, Globs},
+    CheckSet, Section,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+#[cfg(feature = "mocks")]
+use mockall::automock;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+use time::OffsetDateTime;
+use tracing::warn;
+
+/// Type alias to represent a CheckCache trait object.
+pub type DynCheckCache = Arc<dyn CheckCache + Send + Sync>;
+
+/// Trait that defines the operations a checks results cache must support.
+///
+/// Checks outputs are stored along with the fingerprint of the inputs they
+/// were computed from, and are only reused while the fingerprint matches.
+#[async_trait]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait CheckCache {
+    /// Get the output cached for the key provided, as long as it was stored
+    /// with the same fingerprint.
+    async fn get(&self, key: &CacheKey, fingerprint: &str) -> Result<Option<CheckOutput<Value>>>;
+
+    /// Store the output provided for the key and fingerprint given.
+    async fn set(
+        &self,
+        key: &CacheKey,
+        fingerprint: &str,
+        output: &CheckOutput<Value>,
+    ) -> Result<()>;
+}
+
+/// Key used to identify a check output in the cache.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub url: String,
+    /// Path of the component the check was run on (empty for the repository
+    /// root).
+    pub path: String,
+    pub check_id: String,
+}
+
+impl CacheKey {
+    /// Create a new cache key for the check and input provided.
+    pub(crate) fn new(check: &dyn Check, input: &CheckInput<'_>) -> Self {
+        Self {
+            url: input.li.url.clone(),
+            path: input
+                .li
+                .subpath
+                .as_ref()
+                .map(|subpath| subpath.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            check_id: check.id().to_string(),
+        }
+    }
+}
+
+/// Inputs a check output depends on, used to compute its fingerprint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheInputs {
+    /// Globs of the files the check reads (relative to the root).
+    pub files: Vec<&'static str>,
+    /// Fields of the repository metadata obtained from the forge the check
+    /// reads (as serialized, i.e. `licenseInfo`).
+    pub forge_metadata: Vec<&'static str>,
+    /// The check reads the repository's git history.
+    pub git_head: bool,
+    /// The check uses the repository's OpenSSF Scorecard.
+    pub scorecard: bool,
+}
+
+/// In memory CheckCache implementation.
+#[derive(Default)]
+pub struct MemoryCheckCache {
+    entries: RwLock<HashMap<CacheKey, (String, CheckOutput<Value>)>>,
+}
+
+impl MemoryCheckCache {
+    /// Create a new MemoryCheckCache instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CheckCache for MemoryCheckCache {
+    async fn get(&self, key: &CacheKey, fingerprint: &str) -> Result<Option<CheckOutput<Value>>> {
+        let entries = self.entries.read().expect("lock not poisoned");
+        Ok(entries
+            .get(key)
+            .filter(|(entry_fingerprint, _)| entry_fingerprint == fingerprint)
+            .map(|(_, output)| output.clone()))
+    }
+
+    async fn set(
+        &self,
+        key: &CacheKey,
+        fingerprint: &str,
+        output: &CheckOutput<Value>,
+    ) -> Result<()> {
+        let mut entries = self.entries.write().expect("lock not poisoned");
+        entries.insert(key.clone(), (fingerprint.to_string(), output.clone()));
+        Ok(())
+    }
+}
+
+/// Wrapper around a check that reuses its cached output when the inputs it
+/// depends on haven't changed since it was last run.
+pub(crate) struct CachedCheck<'a> {
+    pub check: &'a dyn Check,
+    pub cache: &'a DynCheckCache,
+    /// Root of the repository. When linting a component, the metadata file
+    /// and security insights manifest at the repository root are used as a
+    /// fallback, so they are part of the fingerprint as well.
+    pub repository_root: &'a Path,
+}
+
+#[async_trait]
+impl Check for CachedCheck<'_> {
+    fn id(&self) -> CheckId {
+        self.check.id()
+    }
+
+    fn weight(&self) -> usize {
+        self.check.weight()
+    }
+
+    fn check_sets(&self) -> &[CheckSet] {
+        self.check.check_sets()
+    }
+
+    fn section(&self) -> Section {
+        self.check.section()
+    }
+
+    fn scorecard_name(&self) -> Option<&str> {
+        self.check.scorecard_name()
+    }
+
+    fn remote_data(&self) -> RemoteData {
+        self.check.remote_data()
+    }
+
+    fn is_async(&self) -> bool {
+        self.check.is_async()
+    }
+
+    fn cache_inputs(&self) -> Option<CacheInputs> {
+        self.check.cache_inputs()
+    }
+
+    async fn run(&self, input: &CheckInput<'_>) -> Result<CheckOutput<Value>> {
+        // Compute check fingerprint (checks that can't be cached are just run)
+        let fingerprint = match fingerprint(self.check, input, self.repository_root) {
+            Ok(Some(fingerprint)) => fingerprint,
+            Ok(None) => return self.check.run(input).await,
+            Err(err) => {
+                warn!(?err, check = self.id(), "error computing check fingerprint");
+                return self.check.run(input).await;
+            }
+        };
+        let key = CacheKey::new(self.check, input);
+
+        // Reuse cached output if available
+        match self.cache.get(&key, &fingerprint).await {
+            Ok(Some(output)) => return Ok(output),
+            Ok(None) => {}
+            Err(err) => warn!(?err, check = self.id(), "error getting cached check output"),
+        }
+
+        // Run check and cache its output (failures are not cached)
+        let output = self.check.run(input).await?;
+        if !output.failed {
+            if let Err(err) = self.cache.set(&key, &fingerprint, &output).await {
+                warn!(?err, check = self.id(), "error caching check output");
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Check if the output of the check provided for the input given is available
+/// in the cache.
+pub(crate) async fn is_cached(
+    check: &dyn Check,
+    cache: &DynCheckCache,
+    input: &CheckInput<'_>,
+    repository_root: &Path,
+) -> bool {
+    let Ok(Some(fingerprint)) = fingerprint(check, input, repository_root) else {
+        return false;
+    };
+    let key = CacheKey::new(check, input);
+    matches!(cache.get(&key, &fingerprint).await, Ok(Some(_)))
+}
+
+/// Compute the fingerprint of the check provided from the inputs it depends
+/// on. None is returned when the check cannot be cached.
+pub(crate) fn fingerprint(
+    check: &dyn Check,
+    input: &CheckInput<'_>,
+    repository_root: &Path,
+) -> Result<Option<String>> {
+    let Some(inputs) = check.cache_inputs() else {
+        return Ok(None);
+    };
+    let mut hasher = Sha256::new();
+    let mut update = |data: &[u8]| {
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(data);
+    };
+
+    // Linter version, check and linter options that affect its output
+    update(env!("CARGO_PKG_VERSION").as_bytes());
+    update(check.id().as_bytes());
+    update(&[u8::from(input.li.offline)]);
+    update(input.file_url(Path::new("")).as_bytes());
+
+    // Files the check depends on
+    let mut files: Vec<&str> = inputs.files.clone();
+    files.extend([METADATA_FILE, SECURITY_INSIGHTS_MANIFEST_FILE]);
+    if let Some(location) = input
+        .cm_md
+        .as_ref()
+        .and_then(|md| md.locations.as_ref())
+        .and_then(|locations| locations.get(check.id()))
+    {
+        // Remote locations are verified each time the check is run
+        if location.starts_with("https://") || location.starts_with("http://") {
+            return Ok(None);
+        }
+        files.push(location);
+    }
+    let mut roots = vec![input.li.root.as_path()];
+    if input.li.root != repository_root {
+        roots.push(repository_root);
+    }
+    for root in roots {
+        let globs = Globs {
+            root,
+            patterns: &files,
+            case_sensitive: false,
+        };
+        for file in path::matches(&globs)? {
+            update(path::relative(root, &file)?.to_string_lossy().as_bytes());
+            if file.is_file() {
+                update(&fs::read(&file)?);
+            }
+        }
+    }
+
+    // Repository metadata fields the check reads
+    if !inputs.forge_metadata.is_empty() {
+        let md = serde_json::to_value(&input.gh_md)?;
+        for field in &inputs.forge_metadata {
+            update(field.as_bytes());
+            update(&serde_json::to_vec(&md.get(field))?);
+        }
+    }
+
+    // Git history (identified by the commit HEAD points to)
+    if inputs.git_head {
+        let Some(head) = head_commit(&input.li.root) else {
+            return Ok(None);
+        };
+        update(head.as_bytes());
+    }
+
+    // OpenSSF Scorecard. The scorecard is only fetched when needed, so its
+    // results cannot be part of the fingerprint. The precomputed scorecard
+    // file is used when provided. Otherwise, results are reused for the same
+    // commit during the current day.
+    if inputs.scorecard {
+        update(&serde_json::to_vec(&check.scorecard_name())?);
+        if let Some(path) = &input.li.scorecard_json {
+            update(&fs::read(path)?);
+        } else {
+            let Some(head) = head_commit(&input.li.root) else {
+                return Ok(None);
+            };
+            update(
+                input
+                    .li
+                    .scorecard_api_url
+                    .as_deref()
+                    .unwrap_or_default()
+                    .as_bytes(),
+            );
+            update(head.as_bytes());
+            update(OffsetDateTime::now_utc().date().to_string().as_bytes());
+        }
+        let threshold = input.li.scorecard_thresholds.get(check.id());
+        update(&serde_json::to_vec(&threshold)?);
+    }
+
+    Ok(Some(hex::encode(hasher.finalize())))
+}
+
+/// Return the id of the commit the HEAD of the git repository the path
+/// provided belongs to points to.
+fn head_commit(path: &Path) -> Option<String> {
+    let repo = git2::Repository::discover(path).ok()?;
+    let commit = repo.head().ok()?.peel_to_commit().ok()?;
+    Some(commit.id().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        datasource::{forge::Forge, github::md::MdRepository},
+        LinterInput,
+    };
+    use anyhow::format_err;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    struct TestCheck {
+        runs: AtomicUsize,
+        cache_inputs: Option<CacheInputs>,
+        output: CheckOutput<Value>,
+    }
+
+    impl TestCheck {
+        fn new(cache_inputs: Option<CacheInputs>, output: CheckOutput<Value>) -> Self {
+            Self {
+                runs: AtomicUsize::new(0),
+                cache_inputs,
+                output,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Check for TestCheck {
+        fn id(&self) -> CheckId {
+            "test"
+        }
+
+        fn weight(&self) -> usize {
+            1
+        }
+
+        fn check_sets(&self) -> &[CheckSet] {
+            &[CheckSet::Code]
+        }
+
+        fn section(&self) -> Section {
+            Section::Documentation
+        }
+
+        fn cache_inputs(&self) -> Option<CacheInputs> {
+            self.cache_inputs.clone()
+        }
+
+        async fn run(&self, _input: &CheckInput<'_>) -> Result<CheckOutput<Value>> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Ok(self.output.clone())
+        }
+    }
+
+    fn readme_inputs() -> CacheInputs {
+        CacheInputs {
+            files: vec!["README*"],
+            ..CacheInputs::default()
+        }
+    }
+
+    fn input(li: &LinterInput) -> CheckInput<'_> {
+        CheckInput {
+            li,
+            forge: Forge::GitHub,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: None,
+        }
+    }
+
+    fn linter_input(root: &Path) -> LinterInput {
+        LinterInput {
+            root: root.to_owned(),
+            url: "https://github.com/owner/repo".to_string(),
+            ..LinterInput::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn memory_cache_get_requires_same_fingerprint() {
+        let cache = MemoryCheckCache::new();
+        let key = CacheKey {
+            url: "https://github.com/owner/repo".to_string(),
+            path: String::new(),
+            check_id: "test".to_string(),
+        };
+        cache
+            .set(&key, "fp1", &CheckOutput::passed())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cache.get(&key, "fp1").await.unwrap(),
+            Some(CheckOutput::passed())
+        );
+        assert_eq!(cache.get(&key, "fp2").await.unwrap(), None);
+    }
+
+    #[test]
+    fn fingerprint_changes_when_files_change() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let input = input(&li);
+        let check = TestCheck::new(Some(readme_inputs()), CheckOutput::passed());
+
+        fs::write(dir.path().join("README.md"), "# Readme").unwrap();
+        let fp1 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+        fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+        let fp2 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+        fs::write(dir.path().join("README.md"), "# Readme updated").unwrap();
+        let fp3 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+
+        assert_eq!(fp1, fp2);
+        assert_ne!(fp2, fp3);
+    }
+
+    #[test]
+    fn fingerprint_not_cacheable_check() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let check = TestCheck::new(None, CheckOutput::passed());
+
+        assert!(fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn fingerprint_only_uses_metadata_fields_read() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let mut input = input(&li);
+        let check = TestCheck::new(
+            Some(CacheInputs {
+                forge_metadata: vec!["homepageUrl"],
+                ..CacheInputs::default()
+            }),
+            CheckOutput::passed(),
+        );
+
+        let fp1 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+        input.gh_md.security_policy_url = Some("https://security.policy".to_string());
+        let fp2 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+        input.gh_md.homepage_url = Some("https://project.io".to_string());
+        let fp3 = fingerprint(&check, &input, dir.path()).unwrap().unwrap();
+
+        assert_eq!(fp1, fp2);
+        assert_ne!(fp2, fp3);
+    }
+
+    #[test]
+    fn fingerprint_changes_when_git_head_changes() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let check = TestCheck::new(
+            Some(CacheInputs {
+                git_head: true,
+                ..CacheInputs::default()
+            }),
+            CheckOutput::passed(),
+        );
+
+        assert!(fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .is_none());
+
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let sig = git2::Signature::now("user", "user@example.com").unwrap();
+        let commit_id = repo
+            .commit(Some("HEAD"), &sig, &sig, "first", &tree, &[])
+            .unwrap();
+        let fp1 = fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .unwrap();
+        let parent = repo.find_commit(commit_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "second", &tree, &[&parent])
+            .unwrap();
+        let fp2 = fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .unwrap();
+
+        assert_ne!(fp1, fp2);
+    }
+
+    #[test]
+    fn fingerprint_scorecard_file_changes() {
+        let dir = TempDir::new().unwrap();
+        let scorecard_json = dir.path().join("scorecard.json");
+        let li = LinterInput {
+            scorecard_json: Some(scorecard_json.clone()),
+            ..linter_input(dir.path())
+        };
+        let check = TestCheck::new(
+            Some(CacheInputs {
+                scorecard: true,
+                ..CacheInputs::default()
+            }),
+            CheckOutput::passed(),
+        );
+
+        fs::write(&scorecard_json, r#"{"checks": []}"#).unwrap();
+        let fp1 = fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .unwrap();
+        fs::write(&scorecard_json, r#"{"checks": [{"name": "Code-Review"}]}"#).unwrap();
+        let fp2 = fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .unwrap();
+
+        assert_ne!(fp1, fp2);
+    }
+
+    #[test]
+    fn fingerprint_scorecard_not_a_git_repository() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let check = TestCheck::new(
+            Some(CacheInputs {
+                scorecard: true,
+                ..CacheInputs::default()
+            }),
+            CheckOutput::passed(),
+        );
+
+        assert!(fingerprint(&check, &input(&li), dir.path())
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn cached_check_reuses_output_while_inputs_dont_change() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Readme").unwrap();
+        let li = linter_input(dir.path());
+        let input = input(&li);
+        let check = TestCheck::new(Some(readme_inputs()), CheckOutput::passed());
+        let cache: DynCheckCache = Arc::new(MemoryCheckCache::new());
+        let cached_check = CachedCheck {
+            check: &check,
+            cache: &cache,
+            repository_root: dir.path(),
+        };
+
+        cached_check.run(&input).await.unwrap();
+        cached_check.run(&input).await.unwrap();
+        assert_eq!(check.runs.load(Ordering::SeqCst), 1);
+
+        fs::write(dir.path().join("README.md"), "# Readme updated").unwrap();
+        cached_check.run(&input).await.unwrap();
+        assert_eq!(check.runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn cached_check_does_not_cache_failures() {
+        let dir = TempDir::new().unwrap();
+        let li = linter_input(dir.path());
+        let input = input(&li);
+        let check = TestCheck::new(Some(readme_inputs()), CheckOutput::failed());
+        let cache: DynCheckCache = Arc::new(MemoryCheckCache::new());
+        let cached_check = CachedCheck {
+            check: &check,
+            cache: &cache,
+            repository_root: dir.path(),
+        };
+
+        cached_check.run(&input).await.unwrap();
+        cached_check.run(&input).await.unwrap();
+        assert_eq!(check.runs.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,78 +1,267 @@
 use super::{
-    checks::{signed_releases, CHECKS},
+    cache::CacheInputs,
     datasource::{
+        forge::Forge,
         github,
         scorecard::{scorecard, Scorecard, ScorecardCheck},
         security_insights::SecurityInsights,
     },
+    date_format, license_spdx_id,
     metadata::{Exemption, Metadata, METADATA_FILE},
-    CheckSet, LinterInput,
+    remediation::Remediation,
+    util::helpers::{find_exemption, should_skip_check},
+    CheckSet, LinterInput, Section,
 };
 use anyhow::{format_err, Context, Error, Result};
-use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use futures::FutureExt;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{fmt, ops::Not, panic::AssertUnwindSafe, path::Path};
+use time::{Date, OffsetDateTime};
 use which::which;
 
 /// Type alias to represent a check identifier.
 pub type CheckId = &'static str;
 
-/// Check configuration.
-pub(crate) struct CheckConfig {
-    pub weight: usize,
-    pub check_sets: Vec<CheckSet>,
-    pub scorecard_name: Option<String>,
+/// Trait that defines the operations a check must support to be registered in
+/// the linter's checks registry.
+#[async_trait]
+pub trait Check: Send + Sync {
+    /// Check identifier.
+    fn id(&self) -> CheckId;
+
+    /// Check score weight.
+    fn weight(&self) -> usize;
+
+    /// Check sets this check belongs to.
+    fn check_sets(&self) -> &[CheckSet];
+
+    /// Report section this check belongs to.
+    fn section(&self) -> Section;
+
+    /// Name of the OpenSSF Scorecard check this check relies on (if any).
+    fn scorecard_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Remote data this check relies on. Checks that don't override this
+    /// method are considered to always require remote data, so they won't be
+    /// run in offline mode.
+    fn remote_data(&self) -> RemoteData {
+        RemoteData::Required
+    }
+
+    /// Whether this check performs asynchronous operations (i.e. HTTP
+    /// requests) when run. Async checks are run concurrently, while the rest
+    /// are run one after the other so that the time spent on each of them is
+    /// measured accurately.
+    fn is_async(&self) -> bool {
+        false
+    }
+
+    /// Inputs this check's output depends on. When a cache is used, the
+    /// output of the check is reused while these inputs don't change. Checks
+    /// that don't override this method are never cached.
+    fn cache_inputs(&self) -> Option<CacheInputs> {
+        None
+    }
+
+    /// Guidance on how to get this check to pass, included in the output of
+    /// the check when it doesn't.
+    fn remediation(&self) -> Option<Remediation> {
+        None
+    }
+
+    /// Run the check on the input provided.
+    async fn run(&self, input: &CheckInput<'_>) -> Result<CheckOutput<Value>>;
+}
+
+/// Describes how a check relies on remote data (GitHub metadata, OpenSSF
+/// Scorecard, external websites, etc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteData {
+    /// The check can be answered entirely from the local checkout.
+    None,
+    /// The check looks at the local checkout first, and uses remote data when
+    /// it doesn't pass locally.
+    Fallback,
+    /// The check always needs remote data.
+    Required,
 }
 
 /// Input used by checks to perform their operations.
 #[derive(Debug)]
-pub(crate) struct CheckInput<'a> {
+pub struct CheckInput<'a> {
     pub li: &'a LinterInput,
+    pub forge: Forge,
     pub cm_md: Option<Metadata>,
     pub gh_md: github::md::MdRepository,
     pub scorecard: Result<Scorecard>,
     pub security_insights: Result<Option<SecurityInsights>>,
+
+    /// SPDX id of the license detected in the files located at the root.
+    pub license: Option<String>,
 }
 
-impl<'a> CheckInput<'a> {
-    pub(crate) async fn new(li: &LinterInput) -> Result<CheckInput> {
-        // Check if required external tools are available
-        if which("scorecard").is_err() {
-            return Err(format_err!(
-                "scorecard not found in PATH (https://github.com/ossf/scorecard#installation)"
-            ));
-        }
+impl CheckInput<'_> {
+    /// Prepare the check input for the linter input provided. When none of the
+    /// checks to run rely on remote data, the repository metadata is obtained
+    /// from the local checkout instead of from the forge. Recent issues and
+    /// pull requests are only fetched when `with_items` is set. The OpenSSF
+    /// Scorecard is not fetched here (see `fetch_scorecard`).
+    pub(crate) async fn new(
+        li: &LinterInput,
+        remote_data: bool,
+        with_items: bool,
+    ) -> Result<CheckInput<'_>> {
+        // Detect the forge hosting the repository (if not provided)
+        let forge = match li.forge {
+            Some(forge) => forge,
+            None => Forge::from_url(&li.url)?,
+        };
 
         // Get CLOMonitor metadata
         let cm_md = Metadata::from(li.root.join(METADATA_FILE))?;
 
-        // The next both actions (get GitHub metadata and get scorecard) make use
-        // of the GitHub token, which when used concurrently, may trigger some
-        // GitHub secondary rate limits. So they should not be run concurrently.
-
-        // Get GitHub metadata
-        let gh_md = github::metadata(&li.url, &li.github_token).await?;
+        // Get repository metadata (in offline mode only the local checkout is
+        // used)
+        let gh_md = if li.offline || !remote_data {
+            forge.local_metadata(&li.url, &li.root)?
+        } else {
+            forge.metadata(li, with_items).await?
+        };
 
-        // Get OpenSSF scorecard
-        let scorecard = scorecard(&li.url, &li.github_token)
-            .await
-            .context("error running scorecard command");
+        // OpenSSF scorecard (only available for GitHub repositories)
+        let scorecard = if li.offline {
+            Err(format_err!("scorecard not available in offline mode"))
+        } else if forge == Forge::GitHub {
+            Err(format_err!("scorecard not fetched"))
+        } else {
+            Err(format_err!(
+                "scorecard only available for GitHub repositories"
+            ))
+        };
 
         // Get OpenSSF security insights.
         let security_insights = SecurityInsights::new(&li.root);
 
+        // Detect license (shared by the license checks)
+        let license = license_spdx_id::detect_in_root(&li.root)?;
+
         // Prepare and return check input
         let ci = CheckInput {
             li,
+            forge,
             cm_md,
             gh_md,
             scorecard,
             security_insights,
+            license,
         };
         Ok(ci)
     }
+
+    /// Fetch the repository's OpenSSF Scorecard. This is done on demand, as
+    /// the scorecard is not needed when the checks relying on it are not run
+    /// or their output is cached. Fetching the repository metadata and the
+    /// scorecard may both use the GitHub token, so they should not be run
+    /// concurrently to avoid triggering GitHub secondary rate limits.
+    pub(crate) async fn fetch_scorecard(&mut self) -> Result<()> {
+        if self.li.offline || self.forge != Forge::GitHub {
+            return Ok(());
+        }
+
+        // The scorecard CLI is not needed when a precomputed scorecard source
+        // is provided
+        if self.li.scorecard_json.is_none()
+            && self.li.scorecard_api_url.is_none()
+            && which("scorecard").is_err()
+        {
+            return Err(format_err!(
+                "scorecard not found in PATH (https://github.com/ossf/scorecard#installation)"
+            ));
+        }
+
+        self.scorecard = scorecard(self.li).await.context("error getting scorecard");
+        Ok(())
+    }
+
+    /// Build the check input for the component whose root is the one of the
+    /// linter input provided. The remote data already collected for the
+    /// repository is reused, and the metadata file and security insights
+    /// manifest of the repository are used when the component has none.
+    pub(crate) fn component<'b>(&self, li: &'b LinterInput) -> Result<CheckInput<'b>> {
+        let cm_md = match Metadata::from(li.root.join(METADATA_FILE))? {
+            Some(cm_md) => Some(cm_md),
+            None => self.cm_md.clone(),
+        };
+        let security_insights = match SecurityInsights::new(&li.root) {
+            Ok(None) => match &self.security_insights {
+                Ok(security_insights) => Ok(security_insights.clone()),
+                Err(err) => Err(format_err!("{err:#}")),
+            },
+            result => result,
+        };
+        let scorecard = match &self.scorecard {
+            Ok(scorecard) => Ok(scorecard.clone()),
+            Err(err) => Err(format_err!("{err:#}")),
+        };
+
+        Ok(CheckInput {
+            li,
+            forge: self.forge,
+            cm_md,
+            gh_md: self.gh_md.clone(),
+            scorecard,
+            security_insights,
+            license: license_spdx_id::detect_in_root(&li.root)?,
+        })
+    }
+
+    /// Build a url to the file at the path provided in the repository. When
+    /// linting a component, the path is relative to the component's path.
+    pub(crate) fn file_url(&self, path: &Path) -> String {
+        match &self.li.subpath {
+            Some(subpath) => self
+                .forge
+                .build_url(&self.li.url, &self.gh_md, &subpath.join(path)),
+            None => self.forge.build_url(&self.li.url, &self.gh_md, path),
+        }
+    }
+}
+
+/// Status of a check, as summarized from its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Passed,
+    /// Passed, but with some issues worth a look.
+    Warning,
+    NotPassed,
+    Exempt,
+    /// The check failed to run.
+    Failed,
+    NotEvaluated,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = match self {
+            Self::Passed => "Passed",
+            Self::Warning => "Warning",
+            Self::NotPassed => "Not passed",
+            Self::Exempt => "Exempt",
+            Self::Failed => "Failed",
+            Self::NotEvaluated => "Not evaluated",
+        };
+        write!(f, "{output}")
+    }
 }
 
 /// Check output information.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckOutput<T = ()> {
     pub passed: bool,
 
@@ -85,15 +274,45 @@ pub struct CheckOutput<T = ()> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<Evidence>,
+
+    /// Fraction of the check weight awarded (from 0 to 1). When not set,
+    /// passed checks are awarded the full weight and the rest nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+
+    /// The check passed, but with some issues worth a look (i.e. partial
+    /// credit awarded).
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub warning: bool,
+
+    /// Guidance on how to get the check to pass (only set when it didn't).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<Remediation>,
+
     pub exempt: bool,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exemption_reason: Option<String>,
 
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "date_format::option"
+    )]
+    pub exemption_expires: Option<Date>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exemption_approved_by: Option<String>,
+
     pub failed: bool,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fail_reason: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub not_evaluated: bool,
 }
 
 impl<T> CheckOutput<T> {
@@ -133,6 +352,16 @@ impl<T> CheckOutput<T> {
         }
     }
 
+    /// Create a new CheckOutput instance with the not evaluated field set to
+    /// true.
+    #[must_use]
+    pub fn not_evaluated() -> Self {
+        Self {
+            not_evaluated: true,
+            ..Default::default()
+        }
+    }
+
     /// Url field setter.
     #[must_use]
     pub fn url(mut self, url: Option<String>) -> CheckOutput<T> {
@@ -154,6 +383,34 @@ impl<T> CheckOutput<T> {
         self
     }
 
+    /// Evidence field setter.
+    #[must_use]
+    pub fn evidence(mut self, evidence: Option<Evidence>) -> CheckOutput<T> {
+        self.evidence = evidence;
+        self
+    }
+
+    /// Score field setter.
+    #[must_use]
+    pub fn score(mut self, score: Option<f64>) -> CheckOutput<T> {
+        self.score = score;
+        self
+    }
+
+    /// Warning field setter.
+    #[must_use]
+    pub fn warning(mut self, warning: bool) -> CheckOutput<T> {
+        self.warning = warning;
+        self
+    }
+
+    /// Remediation field setter.
+    #[must_use]
+    pub fn remediation(mut self, remediation: Option<Remediation>) -> CheckOutput<T> {
+        self.remediation = remediation;
+        self
+    }
+
     /// Exemption reason field setter.
     #[must_use]
     pub fn exemption_reason(mut self, reason: Option<String>) -> CheckOutput<T> {
@@ -161,12 +418,97 @@ impl<T> CheckOutput<T> {
         self
     }
 
+    /// Exemption expires field setter.
+    #[must_use]
+    pub fn exemption_expires(mut self, expires: Option<Date>) -> CheckOutput<T> {
+        self.exemption_expires = expires;
+        self
+    }
+
+    /// Exemption approved by field setter.
+    #[must_use]
+    pub fn exemption_approved_by(mut self, approved_by: Option<String>) -> CheckOutput<T> {
+        self.exemption_approved_by = approved_by;
+        self
+    }
+
     /// Fail reason field setter.
     #[must_use]
     pub fn fail_reason(mut self, reason: Option<String>) -> CheckOutput<T> {
         self.fail_reason = reason;
         self
     }
+
+    /// Return the status of the check according to this output.
+    #[must_use]
+    pub fn status(&self) -> CheckStatus {
+        if self.not_evaluated {
+            CheckStatus::NotEvaluated
+        } else if self.failed {
+            CheckStatus::Failed
+        } else if self.exempt {
+            CheckStatus::Exempt
+        } else if self.passed && self.warning {
+            CheckStatus::Warning
+        } else if self.passed {
+            CheckStatus::Passed
+        } else {
+            CheckStatus::NotPassed
+        }
+    }
+
+    /// Return the fraction of the check weight awarded to this output. Exempt
+    /// checks get the full weight.
+    #[must_use]
+    pub fn credit(&self) -> f64 {
+        if self.exempt {
+            return 1.0;
+        }
+        match self.score {
+            Some(score) => score.clamp(0.0, 1.0),
+            None if self.passed => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Convert the check output into a new one with a different value type,
+    /// applying the function provided to the value.
+    pub(crate) fn map_value<U>(self, f: impl FnOnce(T) -> Option<U>) -> CheckOutput<U> {
+        CheckOutput {
+            passed: self.passed,
+            url: self.url,
+            value: self.value.and_then(f),
+            details: self.details,
+            evidence: self.evidence,
+            score: self.score,
+            warning: self.warning,
+            remediation: self.remediation,
+            exempt: self.exempt,
+            exemption_reason: self.exemption_reason,
+            exemption_expires: self.exemption_expires,
+            exemption_approved_by: self.exemption_approved_by,
+            failed: self.failed,
+            fail_reason: self.fail_reason,
+            not_evaluated: self.not_evaluated,
+        }
+    }
+}
+
+impl<T: Serialize> CheckOutput<T> {
+    /// Convert the check output into one holding a generic json value.
+    #[must_use]
+    pub fn into_json(self) -> CheckOutput<Value> {
+        self.map_value(|v| serde_json::to_value(v).ok())
+    }
+}
+
+impl CheckOutput<Value> {
+    /// Convert the check output into one holding a typed value. The value is
+    /// discarded if it cannot be deserialized into the requested type.
+    #[must_use]
+    pub fn from_json<T: DeserializeOwned>(self) -> CheckOutput<T> {
+        self.map_value(|v| serde_json::from_value(v).ok())
+    }
 }
 
 impl<T> Default for CheckOutput<T> {
@@ -176,32 +518,74 @@ impl<T> Default for CheckOutput<T> {
             url: None,
             value: None,
             details: None,
+            evidence: None,
+            score: None,
+            warning: false,
+            remediation: None,
             exempt: false,
             exemption_reason: None,
+            exemption_expires: None,
+            exemption_approved_by: None,
             failed: false,
             fail_reason: None,
+            not_evaluated: false,
         }
     }
 }
 
+/// Evidence of what made a check pass: the file found or the file, line and
+/// pattern that matched some content.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Evidence {
+    pub file: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
 impl<T> From<Exemption> for CheckOutput<T> {
     fn from(exemption: Exemption) -> Self {
-        Self::exempt().exemption_reason(Some(exemption.reason))
+        Self::exempt()
+            .exemption_reason(Some(exemption.reason))
+            .exemption_expires(exemption.expires)
+            .exemption_approved_by(exemption.approved_by)
     }
 }
 
-impl<T> From<Result<Option<&ScorecardCheck>, &Error>> for CheckOutput<T> {
-    fn from(sc_check: Result<Option<&ScorecardCheck>, &Error>) -> Self {
+impl<T> CheckOutput<T> {
+    /// Flag in the check output details that the exemption provided, which
+    /// was declared for this check, has expired.
+    pub(crate) fn flag_expired_exemption(mut self, exemption: &Exemption) -> Self {
+        let mut details = format!(
+            "**Exemption expired**: the exemption declared for this check expired after {}",
+            exemption
+                .expires
+                .map(|expires| expires.to_string())
+                .unwrap_or_default(),
+        );
+        if let Some(existing_details) = self.details {
+            details = format!("{details}\n\n{existing_details}");
+        }
+        self.details = Some(details);
+        self
+    }
+}
+
+impl<T> CheckOutput<T> {
+    /// Create a new CheckOutput instance from the OpenSSF Scorecard check
+    /// provided. The check passes when its score is equal or greater than the
+    /// pass threshold given.
+    pub(crate) fn from_scorecard_check(
+        sc_check: Result<Option<&ScorecardCheck>, &Error>,
+        pass_threshold: f64,
+    ) -> Self {
         match sc_check {
             Ok(sc_check) => match sc_check {
                 Some(sc_check) => {
-                    let signed_releases =
-                        CHECKS[signed_releases::ID].scorecard_name.as_ref().unwrap();
                     let mut output = CheckOutput::default();
-                    let pass_threshold = match &sc_check.name {
-                        n if n == signed_releases => 1.0,
-                        _ => 5.0,
-                    };
                     if sc_check.score >= pass_threshold {
                         output.passed = true;
                     }
@@ -234,69 +618,264 @@ impl<T> From<Result<Option<&ScorecardCheck>, &Error>> for CheckOutput<T> {
     }
 }
 
-/// Wrapper macro that takes care of running some common pre-check operations
-/// and the synchronous check function.
-macro_rules! run {
-    ($check:ident, $input:expr) => {
-        (|| {
-            // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
-                return None;
-            }
+/// Run the check provided on a component of the repository. When the check
+/// doesn't pass on the component, it's run again on the repository root (i.e.
+/// for files shared by all components, like LICENSE). Checks that always rely
+/// on remote data are not run again, as their output would be the same.
+pub(crate) async fn run_component_check(
+    check: &dyn Check,
+    component_input: &CheckInput<'_>,
+    root_input: &CheckInput<'_>,
+) -> Option<CheckOutput<Value>> {
+    let output = run_check(check, component_input).await?;
+    if output.passed
+        || output.exempt
+        || output.failed
+        || output.not_evaluated
+        || check.remote_data() == RemoteData::Required
+    {
+        return Some(output);
+    }
+    match run_check(check, root_input).await {
+        Some(root_output) if root_output.passed => Some(root_output),
+        _ => Some(output),
+    }
+}
 
-            // Check if an exemption has been declared for this check
-            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref()) {
-                return Some(CheckOutput::from(exemption));
-            }
+/// Run the check provided taking care of some common pre-check operations.
+/// None is returned when the check does not apply to the input provided.
+pub(crate) async fn run_check(
+    check: &dyn Check,
+    input: &CheckInput<'_>,
+) -> Option<CheckOutput<Value>> {
+    // Check if this check should be skipped
+    if should_skip_check(check, &input.li.check_sets, &input.li.custom_check_sets) {
+        return None;
+    }
 
-            // Call sync check function and wrap returned check output in an option
-            let output = match $check::check($input) {
-                Ok(output) => output,
-                Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
-            };
-            Some(output)
-        })()
-    };
-}
-pub(crate) use run;
-
-/// Wrapper macro that takes care of running some common pre-check operations
-/// and the asynchronous check function.
-macro_rules! run_async {
-    ($check:ident, $input:expr) => {
-        async {
-            // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
-                return None;
-            }
+    // Check if an exemption has been declared for this check (expired
+    // exemptions don't apply, but they are flagged in the check output)
+    let mut expired_exemption = None;
+    if let Some(exemption) = find_exemption(check.id(), input.cm_md.as_ref()) {
+        if !exemption.is_expired(OffsetDateTime::now_utc().date()) {
+            return Some(CheckOutput::from(exemption));
+        }
+        expired_exemption = Some(exemption);
+    }
 
-            // Check if an exemption has been declared for this check
-            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref()) {
-                return Some(CheckOutput::from(exemption));
-            }
+    // Checks that always need remote data can't be evaluated in offline mode
+    if input.li.offline && check.remote_data() == RemoteData::Required {
+        return Some(CheckOutput::not_evaluated());
+    }
 
-            // Call async check function and wrap returned check output in an option
-            let output = match $check::check($input).await {
-                Ok(output) => output,
-                Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
-            };
-            Some(output)
+    // OpenSSF Scorecard checks can only be evaluated on GitHub repositories
+    if check.scorecard_name().is_some() && input.forge != Forge::GitHub {
+        return Some(CheckOutput::not_evaluated().details(Some(
+            "OpenSSF Scorecard is only available for GitHub repositories".to_string(),
+        )));
+    }
+
+    // Run check (errors and panics are reported as check failures, so that a
+    // broken check doesn't make the whole lint fail)
+    let mut output = match AssertUnwindSafe(check.run(input)).catch_unwind().await {
+        Ok(Ok(output)) => output,
+        Ok(Err(err)) => CheckOutput::failed().fail_reason(Some(format!("{err:#}"))),
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown error");
+            CheckOutput::failed().fail_reason(Some(format!("check panicked: {msg}")))
         }
     };
+    if let Some(exemption) = expired_exemption {
+        output = output.flag_expired_exemption(&exemption);
+    }
+
+    // In offline mode, checks that didn't pass locally may still pass using
+    // remote data, so we can't tell the final result
+    if input.li.offline
+        && check.remote_data() == RemoteData::Fallback
+        && !output.passed
+        && !output.failed
+    {
+        return Some(CheckOutput::not_evaluated());
+    }
+
+    Some(output)
 }
-pub(crate) use run_async;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::datasource::scorecard::ScorecardCheckDocs;
+    use crate::linter::{
+        adopters, code_review,
+        datasource::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
+        datasource::scorecard::ScorecardCheckDocs,
+        governance, roadmap, sbom, website, CHECKS,
+    };
     use anyhow::{format_err, Result};
+    use std::path::PathBuf;
+    use time::macros::date;
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    fn offline_input(li: &LinterInput) -> CheckInput<'_> {
+        CheckInput {
+            li,
+            forge: Forge::GitHub,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("scorecard not available in offline mode")),
+            security_insights: Ok(None),
+            license: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn run_check_offline_remote_data_required() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            run_check(&CHECKS[website::ID], &offline_input(&li)).await,
+            Some(CheckOutput::not_evaluated())
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_offline_remote_data_fallback_not_passed() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Code],
+            offline: true,
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            run_check(&CHECKS[sbom::ID], &offline_input(&li)).await,
+            Some(CheckOutput::not_evaluated())
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_scorecard_check_not_hosted_on_github() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Code],
+            ..LinterInput::default()
+        };
+        let input = CheckInput {
+            forge: Forge::GitLab,
+            scorecard: Err(format_err!(
+                "scorecard only available for GitHub repositories"
+            )),
+            ..offline_input(&li)
+        };
+
+        assert_eq!(
+            run_check(&CHECKS[code_review::ID], &input).await,
+            Some(CheckOutput::not_evaluated().details(Some(
+                "OpenSSF Scorecard is only available for GitHub repositories".to_string()
+            )))
+        );
+    }
+
+    fn adopters_readme_evidence() -> Evidence {
+        Evidence {
+            file: "README.md".to_string(),
+            line: Some(7),
+            pattern: Some(r"(?im)^#+.*adopters.*$".to_string()),
+        }
+    }
+
+    struct BrokenCheck {
+        panic: bool,
+    }
+
+    #[async_trait]
+    impl Check for BrokenCheck {
+        fn id(&self) -> CheckId {
+            "broken"
+        }
+
+        fn weight(&self) -> usize {
+            1
+        }
+
+        fn check_sets(&self) -> &[CheckSet] {
+            &[CheckSet::Code]
+        }
+
+        fn section(&self) -> Section {
+            Section::BestPractices
+        }
+
+        fn remote_data(&self) -> RemoteData {
+            RemoteData::None
+        }
+
+        async fn run(&self, _input: &CheckInput<'_>) -> Result<CheckOutput<Value>> {
+            assert!(!self.panic, "broken check");
+            Err(format_err!("broken check"))
+        }
+    }
+
+    #[tokio::test]
+    async fn run_check_error() {
+        let li = LinterInput {
+            check_sets: vec![CheckSet::Code],
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            run_check(&BrokenCheck { panic: false }, &offline_input(&li)).await,
+            Some(CheckOutput::failed().fail_reason(Some("broken check".to_string())))
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_panic() {
+        let li = LinterInput {
+            check_sets: vec![CheckSet::Code],
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            run_check(&BrokenCheck { panic: true }, &offline_input(&li)).await,
+            Some(
+                CheckOutput::failed().fail_reason(Some("check panicked: broken check".to_string()))
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_offline_local_check() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            run_check(&CHECKS[adopters::ID], &offline_input(&li)).await,
+            Some(CheckOutput::passed().evidence(Some(adopters_readme_evidence())))
+        );
+    }
 
     #[test]
     fn check_output_from_exemption() {
         let exemption = Exemption {
             check: "test".to_string(),
             reason: "test".to_string(),
+            expires: Some(date!(2030 - 01 - 31)),
+            approved_by: Some("approver".to_string()),
         };
 
         assert_eq!(
@@ -304,11 +883,188 @@ mod tests {
             CheckOutput {
                 exempt: true,
                 exemption_reason: Some("test".to_string()),
+                exemption_expires: Some(date!(2030 - 01 - 31)),
+                exemption_approved_by: Some("approver".to_string()),
                 ..Default::default()
             }
         );
     }
 
+    #[test]
+    fn check_output_flag_expired_exemption() {
+        let exemption = Exemption {
+            check: "test".to_string(),
+            reason: "test".to_string(),
+            expires: Some(date!(2024 - 06 - 30)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            CheckOutput::<()>::not_passed()
+                .details(Some("details".to_string()))
+                .flag_expired_exemption(&exemption),
+            CheckOutput {
+                details: Some("**Exemption expired**: the exemption declared for this check expired after 2024-06-30\n\ndetails".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_exemption_not_expired() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+        let mut input = offline_input(&li);
+        input.cm_md = Some(Metadata {
+            exemptions: Some(vec![Exemption {
+                check: adopters::ID.to_string(),
+                reason: "test".to_string(),
+                expires: Some(date!(2999 - 12 - 31)),
+                ..Default::default()
+            }]),
+            license_scanning: None,
+            locations: None,
+        });
+
+        assert_eq!(
+            run_check(&CHECKS[adopters::ID], &input).await,
+            Some(
+                CheckOutput::exempt()
+                    .exemption_reason(Some("test".to_string()))
+                    .exemption_expires(Some(date!(2999 - 12 - 31)))
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn run_check_exemption_expired() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+        let mut input = offline_input(&li);
+        input.cm_md = Some(Metadata {
+            exemptions: Some(vec![Exemption {
+                check: adopters::ID.to_string(),
+                reason: "test".to_string(),
+                expires: Some(date!(2024 - 06 - 30)),
+                ..Default::default()
+            }]),
+            license_scanning: None,
+            locations: None,
+        });
+
+        assert_eq!(
+            run_check(&CHECKS[adopters::ID], &input).await,
+            Some(
+                CheckOutput::passed()
+                    .details(Some(
+                        "**Exemption expired**: the exemption declared for this check expired after 2024-06-30"
+                            .to_string()
+                    ))
+                    .evidence(Some(adopters_readme_evidence()))
+            )
+        );
+    }
+
+    fn component_li(root: &LinterInput) -> LinterInput {
+        LinterInput {
+            root: root.root.join("component"),
+            subpath: Some(PathBuf::from("component")),
+            ..root.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_component_check_passed_in_component() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+        let root_input = CheckInput {
+            gh_md: MdRepository {
+                name: "repo".to_string(),
+                owner: MdRepositoryOwner {
+                    login: "owner".to_string(),
+                    on: MdRepositoryOwnerOn::Organization,
+                },
+                ..MdRepository::default()
+            },
+            ..offline_input(&li)
+        };
+        let component_li = component_li(&li);
+        let component_input = root_input.component(&component_li).unwrap();
+
+        assert_eq!(
+            run_component_check(&CHECKS[governance::ID], &component_input, &root_input).await,
+            Some(
+                CheckOutput::passed()
+                    .url(Some(
+                        "https://github.com/owner/repo/blob/master/component/GOVERNANCE.md"
+                            .to_string()
+                    ))
+                    .evidence(Some(Evidence {
+                        file: "GOVERNANCE.md".to_string(),
+                        line: None,
+                        pattern: Some("governance*".to_string()),
+                    }))
+            )
+        );
+        assert_eq!(
+            run_component_check(&CHECKS[roadmap::ID], &component_input, &root_input).await,
+            Some(CheckOutput::passed().evidence(Some(Evidence {
+                file: "README.md".to_string(),
+                line: Some(5),
+                pattern: Some(r"(?im)^#+.*roadmap.*$".to_string()),
+            })))
+        );
+    }
+
+    #[tokio::test]
+    async fn run_component_check_falls_back_to_root() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            check_sets: vec![CheckSet::Community],
+            offline: true,
+            ..LinterInput::default()
+        };
+        let root_input = offline_input(&li);
+        let component_li = component_li(&li);
+        let component_input = root_input.component(&component_li).unwrap();
+
+        assert_eq!(
+            run_check(&CHECKS[adopters::ID], &component_input).await,
+            Some(CheckOutput::not_passed())
+        );
+        assert_eq!(
+            run_component_check(&CHECKS[adopters::ID], &component_input, &root_input).await,
+            Some(CheckOutput::passed().evidence(Some(adopters_readme_evidence())))
+        );
+    }
+
+    #[test]
+    fn check_input_component_uses_root_metadata() {
+        let li = LinterInput {
+            root: PathBuf::from(TESTDATA_PATH),
+            ..LinterInput::default()
+        };
+        let mut root_input = offline_input(&li);
+        root_input.cm_md = Metadata::from(li.root.join(METADATA_FILE)).unwrap();
+        let component_li = component_li(&li);
+        let component_input = root_input.component(&component_li).unwrap();
+
+        assert!(component_input.cm_md.is_some());
+        assert_eq!(component_input.cm_md, root_input.cm_md);
+    }
+
     #[test]
     fn check_output_from_scorecard_check_passed() {
         let sc_check = ScorecardCheck {
@@ -322,7 +1078,7 @@ mod tests {
         };
 
         assert_eq!(
-            CheckOutput::<()>::from(Ok(Some(&sc_check))),
+            CheckOutput::<()>::from_scorecard_check(Ok(Some(&sc_check)), 5.0),
             CheckOutput {
                 passed: true,
                 details: Some("# Code-Review OpenSSF Scorecard check\n\n**Score**: 8 (check passes with score >= 5)\n\n**Reason**: reason\n\n**Details**: \n\n>details\n\n**Please see the [check documentation](https://test.url) in the ossf/scorecard repository for more details**".to_string()),
@@ -344,7 +1100,7 @@ mod tests {
         };
 
         assert_eq!(
-            CheckOutput::<()>::from(Ok(Some(&sc_check))),
+            CheckOutput::<()>::from_scorecard_check(Ok(Some(&sc_check)), 5.0),
             CheckOutput {
                 passed: false,
                 details: Some("# Code-Review OpenSSF Scorecard check\n\n**Score**: 4 (check passes with score >= 5)\n\n**Reason**: reason\n\n**Details**: \n\n>details\n\n**Please see the [check documentation](https://test.url) in the ossf/scorecard repository for more details**".to_string()),
@@ -353,10 +1109,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_output_from_scorecard_check_not_passed_custom_threshold() {
+        let sc_check = ScorecardCheck {
+            name: "Code-Review".to_string(),
+            reason: "reason".to_string(),
+            details: None,
+            score: 7.0,
+            documentation: ScorecardCheckDocs {
+                url: "https://test.url".to_string(),
+            },
+        };
+
+        assert_eq!(
+            CheckOutput::<()>::from_scorecard_check(Ok(Some(&sc_check)), 8.5),
+            CheckOutput {
+                passed: false,
+                details: Some("# Code-Review OpenSSF Scorecard check\n\n**Score**: 7 (check passes with score >= 8.5)\n\n**Reason**: reason\n\n**Details**: -\n\n**Please see the [check documentation](https://test.url) in the ossf/scorecard repository for more details**".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn check_output_from_scorecard_check_not_available() {
         assert_eq!(
-            CheckOutput::<()>::from(Ok(None)),
+            CheckOutput::<()>::from_scorecard_check(Ok(None), 5.0),
             CheckOutput {
                 passed: false,
                 ..Default::default()
@@ -370,7 +1148,7 @@ mod tests {
         let sc_check: Result<Option<&ScorecardCheck>, &Error> = Err(&err);
 
         assert_eq!(
-            CheckOutput::<()>::from(sc_check),
+            CheckOutput::<()>::from_scorecard_check(sc_check, 5.0),
             CheckOutput {
                 failed: true,
                 fail_reason: Some("fake error".to_string()),
@@ -0,0 +1,251 @@
+use super::{util, CheckRegistry};
+use anyhow::{format_err, Context, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, path::Path, str::FromStr};
+
+/// Check sets define a set of checks that will be run on a given repository.
+/// Multiple check sets can be assigned to a repository.
+///
+/// In addition to the builtin check sets, custom check sets can be declared
+/// in configuration (see `CustomCheckSet`) and referenced by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum CheckSet {
+    Code,
+    CodeLite,
+    Community,
+    Docs,
+    Custom(String),
+}
+
+impl CheckSet {
+    /// Return the check set name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Code => "code",
+            Self::CodeLite => "code-lite",
+            Self::Community => "community",
+            Self::Docs => "docs",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for CheckSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name().to_uppercase())
+    }
+}
+
+impl FromStr for CheckSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "code" => Ok(Self::Code),
+            "code-lite" => Ok(Self::CodeLite),
+            "community" => Ok(Self::Community),
+            "docs" => Ok(Self::Docs),
+            "" => Err(format_err!("check set name cannot be empty")),
+            name => Ok(Self::Custom(name.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for CheckSet {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<CheckSet> for String {
+    fn from(check_set: CheckSet) -> Self {
+        check_set.name().to_string()
+    }
+}
+
+/// Custom check set declared in configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomCheckSet {
+    /// Name used to reference the check set.
+    pub name: String,
+
+    /// Identifiers of the checks included in the check set.
+    pub checks: Vec<String>,
+
+    /// Check score weight overrides (check id -> weight).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub weights: BTreeMap<String, usize>,
+}
+
+impl CustomCheckSet {
+    /// Load the custom check sets definitions from the YAML file located at
+    /// the path provided.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_file(path: &Path) -> Result<Vec<Self>> {
+        let content = util::fs::read_to_string(path).context("error reading check sets file")?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+}
+
+/// Find the definition of the custom check set provided.
+pub(crate) fn find_custom<'a>(
+    check_set: &CheckSet,
+    custom_check_sets: &'a [CustomCheckSet],
+) -> Option<&'a CustomCheckSet> {
+    match check_set {
+        CheckSet::Custom(name) => custom_check_sets.iter().find(|cs| &cs.name == name),
+        _ => None,
+    }
+}
+
+/// Check that the custom check sets provided are defined and only reference
+/// registered checks, returning the check score weight overrides they set.
+pub(crate) fn weight_overrides(
+    check_sets: &[CheckSet],
+    custom_check_sets: &[CustomCheckSet],
+    registry: &CheckRegistry,
+) -> Result<BTreeMap<String, usize>> {
+    let mut weights = BTreeMap::new();
+    for check_set in check_sets {
+        let CheckSet::Custom(name) = check_set else {
+            continue;
+        };
+        let Some(custom_check_set) = find_custom(check_set, custom_check_sets) else {
+            return Err(format_err!("custom check set {name} not defined"));
+        };
+        for check_id in custom_check_set
+            .checks
+            .iter()
+            .chain(custom_check_set.weights.keys())
+        {
+            if registry.get(check_id).is_none() {
+                return Err(format_err!(
+                    "custom check set {name} references unknown check {check_id}"
+                ));
+            }
+        }
+        weights.extend(custom_check_set.weights.clone());
+    }
+    Ok(weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{governance, CHECKS};
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    #[test]
+    fn check_set_from_str() {
+        assert_eq!("code".parse::<CheckSet>().unwrap(), CheckSet::Code);
+        assert_eq!("code-lite".parse::<CheckSet>().unwrap(), CheckSet::CodeLite);
+        assert_eq!(
+            "spec".parse::<CheckSet>().unwrap(),
+            CheckSet::Custom("spec".to_string())
+        );
+        assert!("".parse::<CheckSet>().is_err());
+    }
+
+    #[test]
+    fn check_set_serde_roundtrip() {
+        let check_sets = vec![CheckSet::Community, CheckSet::Custom("spec".to_string())];
+        let data = serde_json::to_string(&check_sets).unwrap();
+
+        assert_eq!(data, r#"["community","spec"]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<CheckSet>>(&data).unwrap(),
+            check_sets
+        );
+    }
+
+    #[test]
+    fn check_set_display() {
+        assert_eq!(CheckSet::CodeLite.to_string(), "CODE-LITE");
+        assert_eq!(CheckSet::Custom("spec".to_string()).to_string(), "SPEC");
+    }
+
+    #[test]
+    fn custom_check_sets_from_file() {
+        assert_eq!(
+            CustomCheckSet::from_file(&Path::new(TESTDATA_PATH).join("check-sets.yaml")).unwrap(),
+            vec![CustomCheckSet {
+                name: "spec".to_string(),
+                checks: vec![
+                    "readme".to_string(),
+                    "license_approved".to_string(),
+                    "governance".to_string(),
+                ],
+                weights: BTreeMap::from([("governance".to_string(), 5)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn find_custom_check_set() {
+        let custom_check_sets = vec![CustomCheckSet {
+            name: "spec".to_string(),
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            find_custom(&CheckSet::Custom("spec".to_string()), &custom_check_sets),
+            Some(&custom_check_sets[0])
+        );
+        assert!(find_custom(&CheckSet::Custom("web".to_string()), &custom_check_sets).is_none());
+        assert!(find_custom(&CheckSet::Code, &custom_check_sets).is_none());
+    }
+
+    #[test]
+    fn weight_overrides_success() {
+        let custom_check_sets = vec![CustomCheckSet {
+            name: "spec".to_string(),
+            checks: vec![governance::ID.to_string()],
+            weights: BTreeMap::from([(governance::ID.to_string(), 5)]),
+        }];
+
+        assert_eq!(
+            weight_overrides(
+                &[CheckSet::Code, CheckSet::Custom("spec".to_string())],
+                &custom_check_sets,
+                &CHECKS
+            )
+            .unwrap(),
+            BTreeMap::from([(governance::ID.to_string(), 5)])
+        );
+    }
+
+    #[test]
+    fn weight_overrides_custom_check_set_not_defined() {
+        assert_eq!(
+            weight_overrides(&[CheckSet::Custom("spec".to_string())], &[], &CHECKS)
+                .unwrap_err()
+                .to_string(),
+            "custom check set spec not defined"
+        );
+    }
+
+    #[test]
+    fn weight_overrides_unknown_check() {
+        let custom_check_sets = vec![CustomCheckSet {
+            name: "spec".to_string(),
+            checks: vec!["unknown".to_string()],
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            weight_overrides(
+                &[CheckSet::Custom("spec".to_string())],
+                &custom_check_sets,
+                &CHECKS
+            )
+            .unwrap_err()
+            .to_string(),
+            "custom check set spec references unknown check unknown"
+        );
+    }
+}
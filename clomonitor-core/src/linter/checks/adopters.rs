@@ -17,7 +17,7 @@ pub(crate) const WEIGHT: usize = 1;
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
 /// Patterns used to locate a file in the repository.
-const FILE_PATTERNS: [&str; 2] = ["adopters*", "users*"];
+pub(crate) const FILE_PATTERNS: [&str; 2] = ["adopters*", "users*"];
 
 lazy_static! {
     #[rustfmt::skip]
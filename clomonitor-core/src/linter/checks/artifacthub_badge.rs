@@ -26,9 +26,10 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    let url = readme_capture(&input.li.root, &[&ARTIFACTHUB_URL])?;
-    if url.is_some() {
-        return Ok(CheckOutput::passed().url(url));
+    if let Some((url, evidence)) = readme_capture(&input.li.root, &[&ARTIFACTHUB_URL])? {
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(Some(evidence)));
     }
 
     Ok(CheckOutput::not_passed())
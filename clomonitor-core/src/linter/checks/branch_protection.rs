@@ -0,0 +1,207 @@
+use super::datasource::github;
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput},
+    datasource::forge::Forge,
+    CheckSet,
+};
+use anyhow::Result;
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "branch_protection";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 3;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
+
+/// Check main function.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // Branch protection information is only available for GitHub repositories
+    if input.forge != Forge::GitHub {
+        return Ok(CheckOutput::not_evaluated().details(Some(
+            "Branch protection is only checked in GitHub repositories".to_string(),
+        )));
+    }
+
+    // Check which rules are enforced in the default branch. The branch
+    // protection rule is only visible to admins, so the rules viewable by
+    // non-admins are used when it's not available.
+    let default_branch_ref = input.gh_md.default_branch_ref.as_ref();
+    let branch = github::default_branch(default_branch_ref);
+    let (reviews, status_checks) = match (
+        default_branch_ref.and_then(|r| r.branch_protection_rule.as_ref()),
+        default_branch_ref.and_then(|r| r.ref_update_rule.as_ref()),
+    ) {
+        (Some(rule), _) => (
+            rule.requires_approving_reviews
+                && rule.required_approving_review_count.unwrap_or(0) > 0,
+            rule.requires_status_checks,
+        ),
+        (None, Some(rule)) => (
+            rule.required_approving_review_count.unwrap_or(0) > 0,
+            rule.required_status_check_contexts
+                .as_ref()
+                .map_or(false, |contexts| !contexts.is_empty()),
+        ),
+        (None, None) => {
+            return Ok(CheckOutput::not_passed().details(Some(format!(
+                "No branch protection rule found for the default branch ({branch})"
+            ))));
+        }
+    };
+
+    // Report the rules missing (if any)
+    let mut missing = Vec::new();
+    if !reviews {
+        missing.push("required reviews");
+    }
+    if !status_checks {
+        missing.push("required status checks");
+    }
+    if !missing.is_empty() {
+        return Ok(CheckOutput::not_passed().details(Some(format!(
+            "Branch protection rules missing in the default branch ({branch}): {}",
+            missing.join(", ")
+        ))));
+    }
+
+    Ok(CheckOutput::passed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        datasource::github::md::{
+            MdRepository, MdRepositoryDefaultBranchRef,
+            MdRepositoryDefaultBranchRefBranchProtectionRule,
+            MdRepositoryDefaultBranchRefRefUpdateRule,
+        },
+        LinterInput,
+    };
+    use anyhow::format_err;
+
+    fn check_with(forge: Forge, default_branch_ref: MdRepositoryDefaultBranchRef) -> CheckOutput {
+        check(&CheckInput {
+            li: &LinterInput::default(),
+            forge,
+            cm_md: None,
+            gh_md: MdRepository {
+                default_branch_ref: Some(default_branch_ref),
+                ..MdRepository::default()
+            },
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn not_evaluated_forge_not_github() {
+        assert_eq!(
+            check_with(
+                Forge::GitLab,
+                MdRepositoryDefaultBranchRef::new("main".to_string())
+            ),
+            CheckOutput::not_evaluated().details(Some(
+                "Branch protection is only checked in GitHub repositories".to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_no_rule_found() {
+        assert_eq!(
+            check_with(
+                Forge::GitHub,
+                MdRepositoryDefaultBranchRef::new("main".to_string())
+            ),
+            CheckOutput::not_passed().details(Some(
+                "No branch protection rule found for the default branch (main)".to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_reviews_not_required() {
+        assert_eq!(
+            check_with(
+                Forge::GitHub,
+                MdRepositoryDefaultBranchRef {
+                    branch_protection_rule: Some(
+                        MdRepositoryDefaultBranchRefBranchProtectionRule {
+                            requires_approving_reviews: false,
+                            required_approving_review_count: None,
+                            requires_status_checks: true,
+                        }
+                    ),
+                    ..MdRepositoryDefaultBranchRef::new("main".to_string())
+                }
+            ),
+            CheckOutput::not_passed().details(Some(
+                "Branch protection rules missing in the default branch (main): required reviews"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_no_rules_enforced_ref_update_rule() {
+        assert_eq!(
+            check_with(
+                Forge::GitHub,
+                MdRepositoryDefaultBranchRef {
+                    ref_update_rule: Some(MdRepositoryDefaultBranchRefRefUpdateRule {
+                        required_approving_review_count: Some(0),
+                        required_status_check_contexts: Some(vec![]),
+                    }),
+                    ..MdRepositoryDefaultBranchRef::new("main".to_string())
+                }
+            ),
+            CheckOutput::not_passed().details(Some(
+                "Branch protection rules missing in the default branch (main): required reviews, required status checks"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn passed_branch_protection_rule() {
+        assert_eq!(
+            check_with(
+                Forge::GitHub,
+                MdRepositoryDefaultBranchRef {
+                    branch_protection_rule: Some(
+                        MdRepositoryDefaultBranchRefBranchProtectionRule {
+                            requires_approving_reviews: true,
+                            required_approving_review_count: Some(1),
+                            requires_status_checks: true,
+                        }
+                    ),
+                    ..MdRepositoryDefaultBranchRef::new("main".to_string())
+                }
+            ),
+            CheckOutput::passed(),
+        );
+    }
+
+    #[test]
+    fn passed_ref_update_rule() {
+        assert_eq!(
+            check_with(
+                Forge::GitHub,
+                MdRepositoryDefaultBranchRef {
+                    ref_update_rule: Some(MdRepositoryDefaultBranchRefRefUpdateRule {
+                        required_approving_review_count: Some(2),
+                        required_status_check_contexts: Some(vec![Some("ci".to_string())]),
+                    }),
+                    ..MdRepositoryDefaultBranchRef::new("main".to_string())
+                }
+            ),
+            CheckOutput::passed(),
+        );
+    }
+}
@@ -29,9 +29,9 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    if readme_matches(&input.li.root, &README_REF)? {
-        return Ok(CheckOutput::passed());
-    };
+    if let Some(evidence) = readme_matches(&input.li.root, &README_REF)? {
+        return Ok(CheckOutput::passed().evidence(Some(evidence)));
+    }
 
     Ok(CheckOutput::not_passed())
 }
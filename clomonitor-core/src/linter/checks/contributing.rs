@@ -1,4 +1,4 @@
-use super::{datasource::github, util::helpers::find_file_or_readme_ref};
+use super::util::helpers::find_file_or_readme_ref;
 use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
     CheckSet,
@@ -42,8 +42,14 @@ pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
     }
 
     // File in .github repo
-    if let Some(url) = github::has_community_health_file("CONTRIBUTING.md", &input.gh_md).await? {
-        return Ok(CheckOutput::passed().url(Some(url)));
+    if !input.li.offline {
+        if let Some(url) = input
+            .forge
+            .has_community_health_file("CONTRIBUTING.md", &input.gh_md)
+            .await?
+        {
+            return Ok(CheckOutput::passed().url(Some(url)));
+        }
     }
 
     Ok(CheckOutput::not_passed())
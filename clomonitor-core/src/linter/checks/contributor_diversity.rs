@@ -0,0 +1,288 @@
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput},
+    CheckSet,
+};
+use anyhow::Result;
+use std::{collections::HashMap, path::Path};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "contributor_diversity";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
+
+/// Maximum number of commits analysed.
+const MAX_COMMITS: usize = 100;
+
+/// Minimum number of authors (and organizations) that should be needed to
+/// cover half of the commits analysed.
+const MIN_BUS_FACTOR: usize = 2;
+
+/// Email domains used by individuals, which don't identify an organization.
+const PERSONAL_EMAIL_DOMAINS: [&str; 11] = [
+    "163.com",
+    "gmail.com",
+    "googlemail.com",
+    "hotmail.com",
+    "icloud.com",
+    "outlook.com",
+    "proton.me",
+    "protonmail.com",
+    "qq.com",
+    "users.noreply.github.com",
+    "yahoo.com",
+];
+
+/// Check main function.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    let Ok(authors) = commits_authors(&input.li.root) else {
+        return Ok(
+            CheckOutput::not_evaluated().details(Some("Git history not available".to_string()))
+        );
+    };
+    if authors.is_empty() {
+        return Ok(CheckOutput::not_evaluated()
+            .details(Some("No commits found in the repository".to_string())));
+    }
+
+    // Commits per author and per organization (email domain)
+    let mut commits_per_author: HashMap<&str, usize> = HashMap::new();
+    let mut commits_per_org: HashMap<&str, usize> = HashMap::new();
+    for email in &authors {
+        *commits_per_author.entry(email).or_default() += 1;
+        *commits_per_org.entry(organization(email)).or_default() += 1;
+    }
+    let authors_bus_factor = bus_factor(&commits_per_author);
+    let orgs_bus_factor = bus_factor(&commits_per_org);
+
+    let details = format!(
+        "Authors covering 50% of the last {} commits (bus factor): {authors_bus_factor} of {}. Organizations covering 50% of the commits: {orgs_bus_factor} of {}",
+        authors.len(),
+        commits_per_author.len(),
+        commits_per_org.len(),
+    );
+    if authors_bus_factor >= MIN_BUS_FACTOR && orgs_bus_factor >= MIN_BUS_FACTOR {
+        return Ok(CheckOutput::passed().details(Some(details)));
+    }
+    Ok(CheckOutput::not_passed().details(Some(details)))
+}
+
+/// Get the email of the authors of the last commits on the git repository
+/// containing the path provided (one entry per commit). Merge commits and
+/// commits authored by bots are ignored.
+fn commits_authors(path: &Path) -> Result<Vec<String>, git2::Error> {
+    let repo = git2::Repository::discover(path)?;
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+
+    let mut authors = Vec::new();
+    for oid in revwalk {
+        if authors.len() == MAX_COMMITS {
+            break;
+        }
+        let Ok(oid) = oid else { continue };
+        let commit = repo.find_commit(oid)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+        let author = commit.author();
+        let (name, email) = (
+            author.name().unwrap_or_default(),
+            author.email().unwrap_or_default(),
+        );
+        if name.ends_with("[bot]") || email.contains("[bot]") {
+            continue;
+        }
+        authors.push(email.to_lowercase());
+    }
+
+    Ok(authors)
+}
+
+/// Get the organization the email provided belongs to (its domain). Emails
+/// from personal domains are considered an organization on their own.
+fn organization(email: &str) -> &str {
+    match email.rsplit_once('@') {
+        Some((_, domain))
+            if !PERSONAL_EMAIL_DOMAINS.contains(&domain)
+                && !domain.ends_with(".users.noreply.github.com") =>
+        {
+            domain
+        }
+        _ => email,
+    }
+}
+
+/// Compute the minimum number of entries needed to cover half of the commits.
+fn bus_factor(commits_per_entry: &HashMap<&str, usize>) -> usize {
+    let total: usize = commits_per_entry.values().sum();
+    let mut commits: Vec<usize> = commits_per_entry.values().copied().collect();
+    commits.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut covered = 0;
+    for (i, count) in commits.into_iter().enumerate() {
+        covered += count;
+        if covered * 2 >= total {
+            return i + 1;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        datasource::forge::Forge, datasource::github::md::MdRepository, LinterInput,
+    };
+    use anyhow::format_err;
+    use tempfile::TempDir;
+
+    fn setup_repository(authors: &[(&str, &str)]) -> TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let mut parent = None;
+        for (name, email) in authors {
+            let sig = git2::Signature::now(name, email).unwrap();
+            let parents: Vec<git2::Commit> = parent
+                .map(|oid| repo.find_commit(oid).unwrap())
+                .into_iter()
+                .collect();
+            let parents: Vec<&git2::Commit> = parents.iter().collect();
+            parent = Some(
+                repo.commit(Some("HEAD"), &sig, &sig, "commit", &tree, &parents)
+                    .unwrap(),
+            );
+        }
+        dir
+    }
+
+    fn check_with(root: &Path) -> CheckOutput {
+        check(&CheckInput {
+            li: &LinterInput {
+                root: root.to_path_buf(),
+                ..LinterInput::default()
+            },
+            forge: Forge::GitHub,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn not_evaluated_git_history_not_available() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert_eq!(
+            check_with(dir.path()),
+            CheckOutput::not_evaluated().details(Some("Git history not available".to_string())),
+        );
+    }
+
+    #[test]
+    fn not_passed_single_author() {
+        let dir = setup_repository(&[
+            ("user1", "user1@example.com"),
+            ("user1", "User1@example.com"),
+            ("user1", "user1@example.com"),
+        ]);
+
+        assert_eq!(
+            check_with(dir.path()),
+            CheckOutput::not_passed().details(Some(
+                "Authors covering 50% of the last 3 commits (bus factor): 1 of 1. Organizations covering 50% of the commits: 1 of 1"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_single_author_from_subdirectory() {
+        let dir = setup_repository(&[("user1", "user1@example.com")]);
+        let subdir = dir.path().join("component");
+        std::fs::create_dir(&subdir).unwrap();
+
+        assert_eq!(
+            check_with(&subdir),
+            CheckOutput::not_passed().details(Some(
+                "Authors covering 50% of the last 1 commits (bus factor): 1 of 1. Organizations covering 50% of the commits: 1 of 1"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_single_organization() {
+        let dir = setup_repository(&[
+            ("user1", "user1@company.com"),
+            ("user2", "user2@company.com"),
+            ("user1", "user1@company.com"),
+            ("user2", "user2@company.com"),
+        ]);
+
+        assert_eq!(
+            check_with(dir.path()),
+            CheckOutput::not_passed().details(Some(
+                "Authors covering 50% of the last 4 commits (bus factor): 1 of 2. Organizations covering 50% of the commits: 1 of 1"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn passed_diverse_contributors() {
+        let dir = setup_repository(&[
+            ("user1", "user1@company.com"),
+            (
+                "dependabot[bot]",
+                "49699333+dependabot[bot]@users.noreply.github.com",
+            ),
+            ("user2", "user2@gmail.com"),
+            (
+                "dependabot[bot]",
+                "49699333+dependabot[bot]@users.noreply.github.com",
+            ),
+            ("user3", "user3@example.org"),
+        ]);
+
+        assert_eq!(
+            check_with(dir.path()),
+            CheckOutput::passed().details(Some(
+                "Authors covering 50% of the last 3 commits (bus factor): 2 of 3. Organizations covering 50% of the commits: 2 of 3"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn organization_from_email() {
+        assert_eq!(organization("user1@company.com"), "company.com");
+        assert_eq!(organization("user1@gmail.com"), "user1@gmail.com");
+        assert_eq!(
+            organization("123+user1@users.noreply.github.com"),
+            "123+user1@users.noreply.github.com"
+        );
+        assert_eq!(organization("user1"), "user1");
+    }
+
+    #[test]
+    fn bus_factor_works() {
+        assert_eq!(bus_factor(&HashMap::new()), 0);
+        assert_eq!(bus_factor(&HashMap::from([("a", 10), ("b", 1)])), 1);
+        assert_eq!(bus_factor(&HashMap::from([("a", 5), ("b", 5)])), 1);
+        assert_eq!(
+            bus_factor(&HashMap::from([("a", 4), ("b", 3), ("c", 3)])),
+            2
+        );
+    }
+}
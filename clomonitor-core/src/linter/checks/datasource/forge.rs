@@ -0,0 +1,239 @@
+use super::{
+    gitea,
+    github::{
+        self,
+        md::{MdRepository, MdRepositoryDefaultBranchRef, MdRepositoryOwner, MdRepositoryOwnerOn},
+    },
+    gitlab,
+};
+use crate::linter::LinterInput;
+use anyhow::{format_err, Result};
+use clap::ValueEnum;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+lazy_static! {
+    static ref REPO_URL_HOST: Regex =
+        Regex::new("^https?://(?P<host>[^/]+)/").expect("exprs in REPO_URL_HOST to be valid");
+}
+
+/// Code forge hosting a repository. The forge determines where the
+/// repository's metadata is fetched from and how urls to files in the
+/// repository are built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    /// Gitea compatible forges (i.e. Gitea or Forgejo).
+    Gitea,
+}
+
+impl Forge {
+    /// Detect the forge hosting the repository from its url. Self-hosted
+    /// instances are only detected when the host name starts with the forge
+    /// name (i.e. gitlab.example.org), otherwise the forge must be provided
+    /// explicitly in the linter input.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_url(repo_url: &str) -> Result<Self> {
+        let host = REPO_URL_HOST
+            .captures(repo_url)
+            .map(|c| c["host"].to_lowercase())
+            .ok_or_else(|| format_err!("invalid repository url"))?;
+        match host.as_str() {
+            "github.com" => Ok(Self::GitHub),
+            "gitlab.com" => Ok(Self::GitLab),
+            "codeberg.org" | "gitea.com" => Ok(Self::Gitea),
+            _ if host.starts_with("gitlab.") => Ok(Self::GitLab),
+            _ if host.starts_with("gitea.") || host.starts_with("forgejo.") => Ok(Self::Gitea),
+            _ => Err(format_err!("unsupported repository host: {host}")),
+        }
+    }
+
+    /// Get repository's metadata from the forge's API, using the token
+    /// configured for the forge in the linter input provided. Recent issues
+    /// and pull requests are only fetched when requested (GitHub only).
+    pub(crate) async fn metadata(self, li: &LinterInput, with_items: bool) -> Result<MdRepository> {
+        match self {
+            Self::GitHub => github::metadata(&li.url, &li.github_token, with_items).await,
+            Self::GitLab => gitlab::metadata(&li.url, li.gitlab_token.as_deref()).await,
+            Self::Gitea => gitea::metadata(&li.url, li.gitea_token.as_deref()).await,
+        }
+    }
+
+    /// Build the repository's metadata using only the information available
+    /// locally (used in offline mode). The owner and repository names are
+    /// extracted from the repository url, and the default branch is the one
+    /// checked out in the local repository (if available).
+    pub(crate) fn local_metadata(self, repo_url: &str, root: &Path) -> Result<MdRepository> {
+        let (owner, repo) = self.owner_and_repo(repo_url)?;
+        let default_branch_ref = git2::Repository::open(root)
+            .ok()
+            .and_then(|r| r.head().ok()?.shorthand().map(ToString::to_string))
+            .map(MdRepositoryDefaultBranchRef::new);
+
+        Ok(MdRepository {
+            default_branch_ref,
+            name: repo,
+            owner: MdRepositoryOwner {
+                login: owner,
+                on: MdRepositoryOwnerOn::Organization,
+            },
+            ..MdRepository::default()
+        })
+    }
+
+    /// Build a url to the file at the path provided in the repository.
+    pub(crate) fn build_url(self, repo_url: &str, md: &MdRepository, path: &Path) -> String {
+        let branch = github::default_branch(md.default_branch_ref.as_ref());
+        match self {
+            Self::GitHub => github::build_url(path, &md.owner.login, &md.name, &branch),
+            Self::GitLab => gitlab::build_url(repo_url, path, &branch),
+            Self::Gitea => gitea::build_url(repo_url, path, &branch),
+        }
+    }
+
+    /// Check if the given default community health file is available for the
+    /// repository's owner, returning the url to the file when found. This is
+    /// only supported by GitHub.
+    pub(crate) async fn has_community_health_file(
+        self,
+        file: &str,
+        md: &MdRepository,
+    ) -> Result<Option<String>> {
+        match self {
+            Self::GitHub => github::has_community_health_file(file, md).await,
+            Self::GitLab | Self::Gitea => Ok(None),
+        }
+    }
+
+    /// Extract the owner and repository from the repository url provided.
+    fn owner_and_repo(self, repo_url: &str) -> Result<(String, String)> {
+        match self {
+            Self::GitHub => github::get_owner_and_repo(repo_url),
+            Self::GitLab => gitlab::get_owner_and_repo(repo_url),
+            Self::Gitea => gitea::get_owner_and_repo(repo_url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_url_github() {
+        assert_eq!(
+            Forge::from_url("https://github.com/owner/repo").unwrap(),
+            Forge::GitHub
+        );
+    }
+
+    #[test]
+    fn from_url_gitlab() {
+        assert_eq!(
+            Forge::from_url("https://gitlab.com/group/subgroup/repo").unwrap(),
+            Forge::GitLab
+        );
+        assert_eq!(
+            Forge::from_url("https://gitlab.example.org/group/repo").unwrap(),
+            Forge::GitLab
+        );
+    }
+
+    #[test]
+    fn from_url_gitea() {
+        assert_eq!(
+            Forge::from_url("https://codeberg.org/owner/repo").unwrap(),
+            Forge::Gitea
+        );
+        assert_eq!(
+            Forge::from_url("https://forgejo.example.org/owner/repo").unwrap(),
+            Forge::Gitea
+        );
+    }
+
+    #[test]
+    fn from_url_unsupported_host() {
+        assert_eq!(
+            Forge::from_url("https://example.com/owner/repo")
+                .unwrap_err()
+                .to_string(),
+            "unsupported repository host: example.com"
+        );
+    }
+
+    #[test]
+    fn local_metadata_works() {
+        let md = Forge::GitHub
+            .local_metadata("https://github.com/owner/repo", Path::new("src/testdata"))
+            .unwrap();
+
+        assert_eq!(md.owner.login, "owner");
+        assert_eq!(md.name, "repo");
+        assert!(md.releases.nodes.is_none());
+    }
+
+    #[test]
+    fn local_metadata_gitlab_nested_groups() {
+        let md = Forge::GitLab
+            .local_metadata(
+                "https://gitlab.com/group/subgroup/repo",
+                Path::new("src/testdata"),
+            )
+            .unwrap();
+
+        assert_eq!(md.owner.login, "group/subgroup");
+        assert_eq!(md.name, "repo");
+    }
+
+    #[test]
+    fn local_metadata_invalid_url() {
+        assert!(Forge::GitHub
+            .local_metadata("https://github.com/repo", Path::new("src/testdata"))
+            .is_err());
+    }
+
+    #[test]
+    fn build_url_github() {
+        let md = MdRepository {
+            name: "repo".to_string(),
+            owner: MdRepositoryOwner {
+                login: "owner".to_string(),
+                on: MdRepositoryOwnerOn::Organization,
+            },
+            ..MdRepository::default()
+        };
+
+        assert_eq!(
+            Forge::GitHub.build_url("https://github.com/owner/repo", &md, Path::new("README.md")),
+            "https://github.com/owner/repo/blob/master/README.md"
+        );
+    }
+
+    #[test]
+    fn build_url_gitlab() {
+        assert_eq!(
+            Forge::GitLab.build_url(
+                "https://gitlab.com/group/repo",
+                &MdRepository::default(),
+                Path::new("README.md")
+            ),
+            "https://gitlab.com/group/repo/-/blob/master/README.md"
+        );
+    }
+
+    #[test]
+    fn build_url_gitea() {
+        assert_eq!(
+            Forge::Gitea.build_url(
+                "https://codeberg.org/owner/repo",
+                &MdRepository::default(),
+                Path::new("README.md")
+            ),
+            "https://codeberg.org/owner/repo/src/branch/master/README.md"
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use super::{github::md::*, rest};
+use anyhow::{format_err, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{header::AUTHORIZATION, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::path::Path;
+
+/// Name used to refer to the Gitea API in errors.
+const API: &str = "gitea";
+
+/// Number of releases to fetch from the Gitea API.
+const RELEASES_LIMIT: usize = 30;
+
+/// Number of closed pull requests to fetch from the Gitea API when looking
+/// for the latest merged one.
+const PULL_REQUESTS_LIMIT: usize = 10;
+
+lazy_static! {
+    static ref GITEA_REPO_URL: Regex =
+        Regex::new("^(?P<base>https?://[^/]+)/(?P<owner>[^/]+)/(?P<repo>[^/]+?)/?$")
+            .expect("exprs in GITEA_REPO_URL to be valid");
+}
+
+/// Gitea repository information.
+#[derive(Debug, Deserialize)]
+struct Repository {
+    name: String,
+    owner: RepositoryOwner,
+    default_branch: Option<String>,
+    website: Option<String>,
+    #[serde(default)]
+    licenses: Vec<String>,
+}
+
+/// Gitea repository owner information.
+#[derive(Debug, Deserialize)]
+struct RepositoryOwner {
+    login: String,
+}
+
+/// Gitea release information.
+#[derive(Debug, Deserialize)]
+struct Release {
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    prerelease: bool,
+    created_at: String,
+    published_at: Option<String>,
+    #[serde(default)]
+    tag_name: String,
+    html_url: String,
+    #[serde(default)]
+    assets: Vec<ReleaseAsset>,
+}
+
+/// Gitea release asset information.
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+}
+
+/// Gitea pull request information.
+#[derive(Debug, Deserialize)]
+struct PullRequest {
+    #[serde(default)]
+    merged: bool,
+    head: PullRequestHead,
+}
+
+/// Gitea pull request head information.
+#[derive(Debug, Deserialize)]
+struct PullRequestHead {
+    sha: String,
+}
+
+/// Gitea commit status information.
+#[derive(Debug, Deserialize)]
+struct CommitStatus {
+    context: String,
+}
+
+/// Get repository's metadata from the Gitea (or Forgejo) REST API. The
+/// metadata returned uses the same format as the one obtained from GitHub, so
+/// that checks can process it regardless of the forge hosting the repository.
+/// The token provided (if any) is used to authenticate the API requests.
+pub(crate) async fn metadata(repo_url: &str, token: Option<&str>) -> Result<MdRepository> {
+    let (base_url, owner, repo) = get_base_owner_and_repo(repo_url)?;
+    let repo_api_url = format!("{base_url}/api/v1/repos/{owner}/{repo}");
+    let auth = token.map(|token| format!("token {token}"));
+    let http_client = rest::setup_http_client(auth.as_deref().map(|auth| (AUTHORIZATION, auth)))?;
+
+    // Repository
+    let repository: Repository = get(&http_client, &repo_api_url).await?;
+    let owner_kind = owner_kind(&http_client, &base_url, &repository.owner.login).await?;
+
+    // Releases
+    let releases: Vec<Release> = get(
+        &http_client,
+        &format!("{repo_api_url}/releases?limit={RELEASES_LIMIT}"),
+    )
+    .await?;
+
+    // Latest merged pull request commit statuses
+    let statuses = latest_merged_pr_statuses(&http_client, &repo_api_url).await?;
+
+    Ok(MdRepository {
+        code_of_conduct: None,
+        default_branch_ref: repository
+            .default_branch
+            .map(MdRepositoryDefaultBranchRef::new),
+        discussions: MdRepositoryDiscussions { nodes: None },
+        homepage_url: repository.website.filter(|w| !w.is_empty()),
+        issues: MdRepositoryIssues { nodes: None },
+        license_info: repository.licenses.into_iter().next().map(|spdx_id| {
+            MdRepositoryLicenseInfo {
+                spdx_id: Some(spdx_id),
+            }
+        }),
+        name: repository.name,
+        owner: MdRepositoryOwner {
+            login: repository.owner.login,
+            on: owner_kind,
+        },
+        pull_requests: pull_requests_from_statuses(statuses),
+        recent_pull_requests: MdRepositoryRecentPullRequests { nodes: None },
+        releases: MdRepositoryReleases {
+            nodes: Some(
+                releases
+                    .into_iter()
+                    .filter(|r| !r.draft)
+                    .map(|r| Some(r.into()))
+                    .collect(),
+            ),
+        },
+        security_policy_url: None,
+    })
+}
+
+/// Build a url from the repository url, path and branch provided.
+pub(crate) fn build_url(repo_url: &str, path: &Path, branch: &str) -> String {
+    rest::build_url(repo_url, "src/branch", path, branch)
+}
+
+/// Extract the owner and repository from the repository url provided.
+pub(crate) fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
+    let (_, owner, repo) = get_base_owner_and_repo(repo_url)?;
+    Ok((owner, repo))
+}
+
+/// Extract the base url, owner and repository from the repository url
+/// provided.
+fn get_base_owner_and_repo(repo_url: &str) -> Result<(String, String, String)> {
+    rest::get_base_owner_and_repo(&GITEA_REPO_URL, repo_url)
+}
+
+/// Get the kind of the repository owner provided. The Gitea API doesn't
+/// include it in the repository owner information, so the owner is looked
+/// up in the organizations endpoint.
+async fn owner_kind(
+    http_client: &reqwest::Client,
+    base_url: &str,
+    owner: &str,
+) -> Result<MdRepositoryOwnerOn> {
+    let resp = http_client
+        .get(format!("{base_url}/api/v1/orgs/{owner}"))
+        .send()
+        .await
+        .context("error querying gitea api")?;
+    match resp.status() {
+        StatusCode::OK => Ok(MdRepositoryOwnerOn::Organization),
+        StatusCode::NOT_FOUND => Ok(MdRepositoryOwnerOn::User),
+        status => Err(format_err!(
+            "unexpected status code querying gitea api: {status} - {}",
+            resp.text().await?,
+        )),
+    }
+}
+
+/// Get the commit statuses of the head commit of the most recent merged pull
+/// request.
+async fn latest_merged_pr_statuses(
+    http_client: &reqwest::Client,
+    repo_api_url: &str,
+) -> Result<Option<Vec<CommitStatus>>> {
+    let prs: Vec<PullRequest> = get(
+        http_client,
+        &format!("{repo_api_url}/pulls?state=closed&sort=recentupdate&limit={PULL_REQUESTS_LIMIT}"),
+    )
+    .await?;
+    let Some(pr) = prs.into_iter().find(|pr| pr.merged) else {
+        return Ok(None);
+    };
+
+    let statuses = get(
+        http_client,
+        &format!("{repo_api_url}/commits/{}/statuses", pr.head.sha),
+    )
+    .await?;
+    Ok(Some(statuses))
+}
+
+/// Build the pull requests metadata from the commit statuses provided.
+fn pull_requests_from_statuses(statuses: Option<Vec<CommitStatus>>) -> MdRepositoryPullRequests {
+    let Some(statuses) = statuses else {
+        return MdRepositoryPullRequests { nodes: None };
+    };
+
+    let commit = MdRepositoryPullRequestsNodesCommitsNodesCommit {
+        check_suites: None,
+        status: Some(MdRepositoryPullRequestsNodesCommitsNodesCommitStatus {
+            contexts: statuses
+                .into_iter()
+                .map(
+                    |s| MdRepositoryPullRequestsNodesCommitsNodesCommitStatusContexts {
+                        context: s.context,
+                    },
+                )
+                .collect(),
+        }),
+    };
+    MdRepositoryPullRequests {
+        nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
+            commits: MdRepositoryPullRequestsNodesCommits {
+                nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodes {
+                    commit,
+                })]),
+            },
+        })]),
+    }
+}
+
+impl From<Release> for MdRepositoryReleasesNodes {
+    fn from(r: Release) -> Self {
+        Self {
+            created_at: r.published_at.unwrap_or(r.created_at),
+            description: r.body.filter(|b| !b.is_empty()),
+            is_prerelease: r.prerelease,
+            release_assets: MdRepositoryReleasesNodesReleaseAssets {
+                nodes: Some(
+                    r.assets
+                        .into_iter()
+                        .map(|a| Some(MdRepositoryReleasesNodesReleaseAssetsNodes { name: a.name }))
+                        .collect(),
+                ),
+            },
+            tag_name: r.tag_name,
+            url: r.html_url,
+        }
+    }
+}
+
+/// Do a GET request to the Gitea API url provided, deserializing the
+/// response body.
+async fn get<T: DeserializeOwned>(http_client: &reqwest::Client, url: &str) -> Result<T> {
+    rest::get(http_client, API, url).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::datasource::{github, rest::tests::mock_get};
+    use mockito::Server;
+    use regex::RegexSet;
+
+    const REPO_PATH: &str = "/api/v1/repos/owner/repo";
+
+    #[test]
+    fn get_owner_and_repo_valid_url() {
+        assert_eq!(
+            get_owner_and_repo("https://codeberg.org/owner/repo/").unwrap(),
+            ("owner".to_string(), "repo".to_string())
+        );
+    }
+
+    #[test]
+    fn get_owner_and_repo_invalid_url() {
+        assert!(get_owner_and_repo("https://codeberg.org/owner").is_err());
+    }
+
+    #[test]
+    fn build_url_works() {
+        assert_eq!(
+            build_url(
+                "https://codeberg.org/owner/repo",
+                Path::new("path/test.md"),
+                "main"
+            ),
+            "https://codeberg.org/owner/repo/src/branch/main/path/test.md".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata_works() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/owner/repo", server.url());
+        let mocks = vec![
+            mock_get(
+                &mut server,
+                REPO_PATH,
+                r#"{
+                    "name": "repo",
+                    "owner": {"login": "owner"},
+                    "default_branch": "main",
+                    "website": "https://project.example",
+                    "licenses": ["Apache-2.0"]
+                }"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                "/api/v1/orgs/owner",
+                r#"{"username": "owner"}"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                &format!("{REPO_PATH}/releases"),
+                r#"[
+                    {
+                        "body": "",
+                        "draft": true,
+                        "prerelease": false,
+                        "created_at": "2022-04-01T10:00:00Z",
+                        "published_at": null,
+                        "html_url": "https://project.example/releases/draft",
+                        "assets": []
+                    },
+                    {
+                        "body": "Release notes",
+                        "draft": false,
+                        "prerelease": false,
+                        "created_at": "2022-02-01T10:00:00Z",
+                        "published_at": "2022-02-02T10:00:00Z",
+                        "html_url": "https://project.example/releases/v1.0.0",
+                        "assets": [{"name": "sbom.spdx.json"}]
+                    }
+                ]"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                &format!("{REPO_PATH}/pulls"),
+                r#"[
+                    {"merged": false, "head": {"sha": "aaa"}},
+                    {"merged": true, "head": {"sha": "bbb"}}
+                ]"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                &format!("{REPO_PATH}/commits/bbb/statuses"),
+                r#"[{"context": "ci/build"}, {"context": "dco"}]"#,
+            )
+            .await,
+        ];
+
+        let md = metadata(&repo_url, None).await.unwrap();
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+
+        assert_eq!(md.name, "repo");
+        assert_eq!(md.owner.login, "owner");
+        assert_eq!(md.owner.on, MdRepositoryOwnerOn::Organization);
+        assert_eq!(md.default_branch_ref.as_ref().unwrap().name, "main");
+        assert_eq!(md.homepage_url.as_deref(), Some("https://project.example"));
+        assert_eq!(
+            md.license_info.as_ref().unwrap().spdx_id.as_deref(),
+            Some("Apache-2.0")
+        );
+
+        let release = github::latest_release(&md).unwrap();
+        assert_eq!(release.created_at, "2022-02-02T10:00:00Z");
+        assert_eq!(release.url, "https://project.example/releases/v1.0.0");
+        assert_eq!(release.description.as_deref(), Some("Release notes"));
+        assert_eq!(
+            release.release_assets.nodes.as_ref().unwrap()[0]
+                .as_ref()
+                .unwrap()
+                .name,
+            "sbom.spdx.json"
+        );
+        assert_eq!(md.releases.nodes.as_ref().unwrap().len(), 1);
+
+        assert!(github::has_check(&md, &RegexSet::new(["dco"]).unwrap()));
+    }
+
+    #[tokio::test]
+    async fn metadata_no_merged_pull_requests() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/owner/repo", server.url());
+        mock_get(
+            &mut server,
+            REPO_PATH,
+            r#"{
+                "name": "repo",
+                "owner": {"login": "owner"},
+                "default_branch": "main",
+                "website": ""
+            }"#,
+        )
+        .await;
+        server
+            .mock("GET", "/api/v1/orgs/owner")
+            .with_status(404)
+            .create_async()
+            .await;
+        mock_get(&mut server, &format!("{REPO_PATH}/releases"), "[]").await;
+        mock_get(
+            &mut server,
+            &format!("{REPO_PATH}/pulls"),
+            r#"[{"merged": false, "head": {"sha": "aaa"}}]"#,
+        )
+        .await;
+
+        let md = metadata(&repo_url, None).await.unwrap();
+
+        assert_eq!(md.owner.on, MdRepositoryOwnerOn::User);
+        assert!(md.homepage_url.is_none());
+        assert!(md.license_info.is_none());
+        assert!(md.pull_requests.nodes.is_none());
+        assert!(github::latest_release(&md).is_none());
+    }
+
+    #[tokio::test]
+    async fn metadata_repository_not_found() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/owner/repo", server.url());
+        server
+            .mock("GET", REPO_PATH)
+            .with_status(404)
+            .with_body("not found")
+            .create_async()
+            .await;
+
+        assert_eq!(
+            metadata(&repo_url, None).await.unwrap_err().to_string(),
+            "unexpected status code querying gitea api: 404 Not Found - not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata_token_provided() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/owner/repo", server.url());
+        server
+            .mock("GET", REPO_PATH)
+            .match_header("authorization", "token secret")
+            .with_status(403)
+            .with_body("forbidden")
+            .create_async()
+            .await;
+
+        assert_eq!(
+            metadata(&repo_url, Some("secret"))
+                .await
+                .unwrap_err()
+                .to_string(),
+            "unexpected status code querying gitea api: 403 Forbidden - forbidden"
+        );
+    }
+}
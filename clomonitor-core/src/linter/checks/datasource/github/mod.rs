@@ -9,6 +9,9 @@ use std::path::Path;
 /// GitHub GraphQL API URL.
 const GITHUB_GRAPHQL_API: &str = "https://api.github.com/graphql";
 
+/// Number of recent issues and pull requests to fetch.
+const RECENT_ITEMS: i64 = 50;
+
 lazy_static! {
     static ref GITHUB_REPO_URL: Regex =
         Regex::new("^https://github.com/(?P<org>[^/]+)/(?P<repo>[^/]+)/?$")
@@ -27,23 +30,22 @@ type DateTime = String;
 #[graphql(
     schema_path = "src/linter/checks/datasource/github/github_schema.graphql",
     query_path = "src/linter/checks/datasource/github/md.graphql",
-    response_derives = "Debug, PartialEq, Eq"
+    response_derives = "Debug, Clone, PartialEq, Eq, Serialize"
 )]
 pub struct Md;
 
 impl MdRepository {
-    #[cfg(test)]
     pub(crate) fn default() -> Self {
         Self {
             code_of_conduct: None,
-            default_branch_ref: Some(MdRepositoryDefaultBranchRef {
-                name: "master".to_string(),
-            }),
+            default_branch_ref: Some(MdRepositoryDefaultBranchRef::new("master".to_string())),
             discussions: MdRepositoryDiscussions { nodes: None },
             homepage_url: None,
+            issues: MdRepositoryIssues { nodes: None },
             license_info: None,
             name: String::new(),
             pull_requests: MdRepositoryPullRequests { nodes: None },
+            recent_pull_requests: MdRepositoryRecentPullRequests { nodes: None },
             owner: MdRepositoryOwner {
                 login: String::new(),
                 on: MdRepositoryOwnerOn::Organization,
@@ -54,13 +56,34 @@ impl MdRepository {
     }
 }
 
-/// Get repository's metadata from the Github GraphQL API.
-pub(crate) async fn metadata(repo_url: &str, token: &str) -> Result<MdRepository> {
+impl MdRepositoryDefaultBranchRef {
+    /// Create a new default branch reference with the name provided (no
+    /// branch protection information available).
+    pub(crate) fn new(name: String) -> Self {
+        Self {
+            name,
+            branch_protection_rule: None,
+            ref_update_rule: None,
+        }
+    }
+}
+
+/// Get repository's metadata from the Github GraphQL API. Recent issues and
+/// pull requests are only fetched when requested.
+pub(crate) async fn metadata(
+    repo_url: &str,
+    token: &str,
+    with_items: bool,
+) -> Result<MdRepository> {
     let (owner, repo) = get_owner_and_repo(repo_url)?;
 
     // Do request to GraphQL API
     let http_client = setup_http_client(token)?;
-    let vars = md::Variables { repo, owner };
+    let vars = md::Variables {
+        repo,
+        owner,
+        items: if with_items { RECENT_ITEMS } else { 0 },
+    };
     let req_body = &Md::build_query(vars);
     let resp = http_client
         .post(GITHUB_GRAPHQL_API)
@@ -239,7 +262,7 @@ pub fn setup_http_client(token: &str) -> Result<reqwest::Client, reqwest::Error>
 }
 
 /// Extract the owner and repository from the repository url provided.
-fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
+pub(crate) fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
     let c = GITHUB_REPO_URL
         .captures(repo_url)
         .ok_or_else(|| format_err!("invalid repository url"))?;
@@ -266,9 +289,7 @@ mod tests {
 
     #[test]
     fn default_branch_some() {
-        let r = MdRepositoryDefaultBranchRef {
-            name: "main".to_string(),
-        };
+        let r = MdRepositoryDefaultBranchRef::new("main".to_string());
 
         assert_eq!(default_branch(Some(&r)), "main".to_string());
     }
@@ -376,6 +397,7 @@ mod tests {
                     description: None,
                     is_prerelease: false,
                     release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    tag_name: "v1.0.0".to_string(),
                     url: "release_url".to_string(),
                 })]),
             },
@@ -402,6 +424,7 @@ mod tests {
                     description: Some("description".to_string()),
                     is_prerelease: false,
                     release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    tag_name: "v1.0.0".to_string(),
                     url: "release_url".to_string(),
                 })]),
             },
@@ -0,0 +1,606 @@
+use super::{github::md::*, rest};
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{header::HeaderName, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::path::Path;
+
+/// Name used to refer to the GitLab API in errors.
+const API: &str = "gitlab";
+
+/// Number of releases to fetch from the GitLab API.
+const RELEASES_PER_PAGE: usize = 30;
+
+/// Files checked in the repository (in order) to locate the security policy.
+const SECURITY_POLICY_FILES: [&str; 3] = ["SECURITY.md", ".gitlab/SECURITY.md", "docs/SECURITY.md"];
+
+lazy_static! {
+    static ref GITLAB_REPO_URL: Regex =
+        Regex::new("^(?P<base>https?://[^/]+)/(?P<owner>.+)/(?P<repo>[^/]+?)/?$")
+            .expect("exprs in GITLAB_REPO_URL to be valid");
+    static ref PRERELEASE_TAG: Regex =
+        Regex::new(r"^v?\d+(\.\d+)*-").expect("exprs in PRERELEASE_TAG to be valid");
+}
+
+/// GitLab project information.
+#[derive(Debug, Deserialize)]
+struct Project {
+    path: String,
+    default_branch: Option<String>,
+    license: Option<ProjectLicense>,
+    namespace: ProjectNamespace,
+}
+
+/// GitLab project license information.
+#[derive(Debug, Deserialize)]
+struct ProjectLicense {
+    key: String,
+}
+
+/// GitLab project namespace information.
+#[derive(Debug, Deserialize)]
+struct ProjectNamespace {
+    full_path: String,
+    kind: String,
+}
+
+/// GitLab release information.
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    description: Option<String>,
+    released_at: String,
+    #[serde(default)]
+    upcoming_release: bool,
+    assets: ReleaseAssets,
+    #[serde(rename = "_links")]
+    links: ReleaseLinks,
+}
+
+/// GitLab release assets information.
+#[derive(Debug, Deserialize)]
+struct ReleaseAssets {
+    links: Vec<ReleaseAssetLink>,
+}
+
+/// GitLab release asset link information.
+#[derive(Debug, Deserialize)]
+struct ReleaseAssetLink {
+    name: String,
+}
+
+/// GitLab release links.
+#[derive(Debug, Deserialize)]
+struct ReleaseLinks {
+    #[serde(rename = "self")]
+    self_: String,
+}
+
+/// GitLab merge request information.
+#[derive(Debug, Deserialize)]
+struct MergeRequest {
+    iid: u64,
+}
+
+/// GitLab pipeline information.
+#[derive(Debug, Deserialize)]
+struct Pipeline {
+    id: u64,
+}
+
+/// GitLab pipeline job information.
+#[derive(Debug, Deserialize)]
+struct Job {
+    name: String,
+}
+
+/// Get repository's metadata from the GitLab REST API. The metadata returned
+/// uses the same format as the one obtained from GitHub, so that checks can
+/// process it regardless of the forge hosting the repository. The token
+/// provided (if any) is used to authenticate the API requests.
+pub(crate) async fn metadata(repo_url: &str, token: Option<&str>) -> Result<MdRepository> {
+    let (base_url, owner, repo) = get_base_owner_and_repo(repo_url)?;
+    let project_api_url = format!(
+        "{base_url}/api/v4/projects/{}",
+        encode_path(&format!("{owner}/{repo}"))
+    );
+    let http_client = rest::setup_http_client(
+        token.map(|token| (HeaderName::from_static("private-token"), token)),
+    )?;
+
+    // Project
+    let project: Project = get(&http_client, &format!("{project_api_url}?license=true")).await?;
+    let default_branch = project.default_branch.clone();
+
+    // Releases
+    let releases: Vec<Release> = get(
+        &http_client,
+        &format!("{project_api_url}/releases?per_page={RELEASES_PER_PAGE}"),
+    )
+    .await?;
+
+    // Latest merged merge request pipeline jobs
+    let jobs = latest_merged_mr_jobs(&http_client, &project_api_url).await?;
+
+    // Security policy
+    let mut security_policy_url = None;
+    if let Some(branch) = default_branch.as_ref() {
+        for file in SECURITY_POLICY_FILES {
+            if has_file(&http_client, &project_api_url, file, branch).await? {
+                security_policy_url = Some(build_url(repo_url, Path::new(file), branch.as_str()));
+                break;
+            }
+        }
+    }
+
+    Ok(MdRepository {
+        code_of_conduct: None,
+        default_branch_ref: default_branch.map(MdRepositoryDefaultBranchRef::new),
+        discussions: MdRepositoryDiscussions { nodes: None },
+        homepage_url: None, // GitLab projects don't have a homepage field
+        issues: MdRepositoryIssues { nodes: None },
+        license_info: project.license.map(|l| MdRepositoryLicenseInfo {
+            spdx_id: spdx_id(&l.key).map(ToString::to_string),
+        }),
+        name: project.path,
+        owner: MdRepositoryOwner {
+            login: project.namespace.full_path,
+            on: match project.namespace.kind.as_str() {
+                "user" => MdRepositoryOwnerOn::User,
+                _ => MdRepositoryOwnerOn::Organization,
+            },
+        },
+        pull_requests: pull_requests_from_jobs(jobs),
+        recent_pull_requests: MdRepositoryRecentPullRequests { nodes: None },
+        releases: MdRepositoryReleases {
+            nodes: Some(releases.into_iter().map(|r| Some(r.into())).collect()),
+        },
+        security_policy_url,
+    })
+}
+
+/// Get the SPDX id corresponding to the GitLab license key provided. GitLab
+/// detects licenses using licensee, so its keys are mapped to the same SPDX
+/// ids GitHub reports. None is returned for unknown licenses (i.e. other).
+fn spdx_id(key: &str) -> Option<&'static str> {
+    let spdx_id = match key {
+        "0bsd" => "0BSD",
+        "afl-3.0" => "AFL-3.0",
+        "agpl-3.0" => "AGPL-3.0",
+        "apache-2.0" => "Apache-2.0",
+        "artistic-2.0" => "Artistic-2.0",
+        "bsd-2-clause" => "BSD-2-Clause",
+        "bsd-3-clause" => "BSD-3-Clause",
+        "bsd-3-clause-clear" => "BSD-3-Clause-Clear",
+        "bsd-4-clause" => "BSD-4-Clause",
+        "bsl-1.0" => "BSL-1.0",
+        "cc-by-4.0" => "CC-BY-4.0",
+        "cc-by-sa-4.0" => "CC-BY-SA-4.0",
+        "cc0-1.0" => "CC0-1.0",
+        "ecl-2.0" => "ECL-2.0",
+        "epl-1.0" => "EPL-1.0",
+        "epl-2.0" => "EPL-2.0",
+        "eupl-1.1" => "EUPL-1.1",
+        "eupl-1.2" => "EUPL-1.2",
+        "gpl-2.0" => "GPL-2.0",
+        "gpl-3.0" => "GPL-3.0",
+        "isc" => "ISC",
+        "lgpl-2.1" => "LGPL-2.1",
+        "lgpl-3.0" => "LGPL-3.0",
+        "lppl-1.3c" => "LPPL-1.3c",
+        "mit" => "MIT",
+        "mit-0" => "MIT-0",
+        "mpl-2.0" => "MPL-2.0",
+        "ms-pl" => "MS-PL",
+        "ms-rl" => "MS-RL",
+        "ncsa" => "NCSA",
+        "ofl-1.1" => "OFL-1.1",
+        "osl-3.0" => "OSL-3.0",
+        "postgresql" => "PostgreSQL",
+        "unlicense" => "Unlicense",
+        "upl-1.0" => "UPL-1.0",
+        "vim" => "Vim",
+        "wtfpl" => "WTFPL",
+        "zlib" => "Zlib",
+        _ => return None,
+    };
+    Some(spdx_id)
+}
+
+/// Build a url from the repository url, path and branch provided.
+pub(crate) fn build_url(repo_url: &str, path: &Path, branch: &str) -> String {
+    rest::build_url(repo_url, "-/blob", path, branch)
+}
+
+/// Extract the owner and repository from the repository url provided. The
+/// owner can be a nested group (i.e. group/subgroup).
+pub(crate) fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
+    let (_, owner, repo) = get_base_owner_and_repo(repo_url)?;
+    Ok((owner, repo))
+}
+
+/// Extract the base url, owner and repository from the repository url
+/// provided.
+fn get_base_owner_and_repo(repo_url: &str) -> Result<(String, String, String)> {
+    rest::get_base_owner_and_repo(&GITLAB_REPO_URL, repo_url)
+}
+
+/// Get the names of the jobs run in the latest pipeline of the most recently
+/// merged merge request (sorting merge requests by their merge date requires
+/// GitLab 16 or later).
+async fn latest_merged_mr_jobs(
+    http_client: &reqwest::Client,
+    project_api_url: &str,
+) -> Result<Option<Vec<Job>>> {
+    let mrs: Vec<MergeRequest> = get(
+        http_client,
+        &format!(
+            "{project_api_url}/merge_requests?state=merged&order_by=merged_at&sort=desc&per_page=1"
+        ),
+    )
+    .await?;
+    let Some(mr) = mrs.first() else {
+        return Ok(None);
+    };
+
+    let pipelines: Vec<Pipeline> = get(
+        http_client,
+        &format!(
+            "{project_api_url}/merge_requests/{}/pipelines?per_page=1",
+            mr.iid
+        ),
+    )
+    .await?;
+    let Some(pipeline) = pipelines.first() else {
+        return Ok(None);
+    };
+
+    let jobs = get(
+        http_client,
+        &format!(
+            "{project_api_url}/pipelines/{}/jobs?per_page=100",
+            pipeline.id
+        ),
+    )
+    .await?;
+    Ok(Some(jobs))
+}
+
+/// Build the pull requests metadata from the pipeline jobs provided. Each job
+/// is represented as a check run of the latest merged pull request.
+fn pull_requests_from_jobs(jobs: Option<Vec<Job>>) -> MdRepositoryPullRequests {
+    let Some(jobs) = jobs else {
+        return MdRepositoryPullRequests { nodes: None };
+    };
+
+    let check_runs = jobs
+        .into_iter()
+        .map(|job| {
+            Some(
+                MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuitesNodesCheckRunsNodes {
+                    name: job.name,
+                },
+            )
+        })
+        .collect();
+    let commit = MdRepositoryPullRequestsNodesCommitsNodesCommit {
+        check_suites: Some(MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuites {
+            nodes: Some(vec![Some(
+                MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuitesNodes {
+                    app: None,
+                    check_runs: Some(
+                        MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuitesNodesCheckRuns {
+                            nodes: Some(check_runs),
+                        },
+                    ),
+                },
+            )]),
+        }),
+        status: None,
+    };
+    MdRepositoryPullRequests {
+        nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
+            commits: MdRepositoryPullRequestsNodesCommits {
+                nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodes {
+                    commit,
+                })]),
+            },
+        })]),
+    }
+}
+
+impl From<Release> for MdRepositoryReleasesNodes {
+    fn from(r: Release) -> Self {
+        Self {
+            created_at: r.released_at,
+            description: r.description,
+            is_prerelease: r.upcoming_release || PRERELEASE_TAG.is_match(&r.tag_name),
+            release_assets: MdRepositoryReleasesNodesReleaseAssets {
+                nodes: Some(
+                    r.assets
+                        .links
+                        .into_iter()
+                        .map(|l| Some(MdRepositoryReleasesNodesReleaseAssetsNodes { name: l.name }))
+                        .collect(),
+                ),
+            },
+            tag_name: r.tag_name,
+            url: r.links.self_,
+        }
+    }
+}
+
+/// Check if the file provided exists in the repository's branch given.
+async fn has_file(
+    http_client: &reqwest::Client,
+    project_api_url: &str,
+    file: &str,
+    branch: &str,
+) -> Result<bool> {
+    let url = format!(
+        "{project_api_url}/repository/files/{}?ref={branch}",
+        encode_path(file)
+    );
+    let resp = http_client
+        .head(&url)
+        .send()
+        .await
+        .context(format!("error checking file {file}"))?;
+    Ok(resp.status() == StatusCode::OK)
+}
+
+/// Do a GET request to the GitLab API url provided, deserializing the
+/// response body.
+async fn get<T: DeserializeOwned>(http_client: &reqwest::Client, url: &str) -> Result<T> {
+    rest::get(http_client, API, url).await
+}
+
+/// Encode the path provided so that it can be used as a GitLab API path
+/// parameter (i.e. project id or file path).
+fn encode_path(path: &str) -> String {
+    path.replace('/', "%2F")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::datasource::rest::tests::mock_get;
+    use mockito::{Matcher, Server};
+
+    const PROJECT_PATH: &str = "/api/v4/projects/group%2Fsubgroup%2Frepo";
+
+    #[test]
+    fn get_owner_and_repo_valid_url() {
+        assert_eq!(
+            get_owner_and_repo("https://gitlab.com/org/repo").unwrap(),
+            ("org".to_string(), "repo".to_string())
+        );
+    }
+
+    #[test]
+    fn get_owner_and_repo_nested_groups_trailing_slash() {
+        assert_eq!(
+            get_owner_and_repo("https://gitlab.com/org/subgroup/repo/").unwrap(),
+            ("org/subgroup".to_string(), "repo".to_string())
+        );
+    }
+
+    #[test]
+    fn get_owner_and_repo_invalid_url() {
+        assert!(get_owner_and_repo("https://gitlab.com/org").is_err());
+    }
+
+    #[test]
+    fn spdx_id_known_keys() {
+        assert_eq!(spdx_id("apache-2.0"), Some("Apache-2.0"));
+        assert_eq!(spdx_id("bsd-3-clause"), Some("BSD-3-Clause"));
+        assert_eq!(spdx_id("mit"), Some("MIT"));
+    }
+
+    #[test]
+    fn spdx_id_unknown_keys() {
+        assert_eq!(spdx_id("other"), None);
+        assert_eq!(spdx_id("unknown"), None);
+    }
+
+    #[test]
+    fn build_url_works() {
+        assert_eq!(
+            build_url(
+                "https://gitlab.com/owner/repo/",
+                Path::new("path/test.md"),
+                "main"
+            ),
+            "https://gitlab.com/owner/repo/-/blob/main/path/test.md".to_string()
+        );
+    }
+
+    #[tokio::test]
+    #[allow(clippy::too_many_lines)]
+    async fn metadata_works() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/group/subgroup/repo", server.url());
+        let mocks = vec![
+            mock_get(
+                &mut server,
+                PROJECT_PATH,
+                r#"{
+                    "path": "repo",
+                    "default_branch": "main",
+                    "license": {"key": "apache-2.0"},
+                    "namespace": {"full_path": "group/subgroup", "kind": "group"}
+                }"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                &format!("{PROJECT_PATH}/releases"),
+                r#"[
+                    {
+                        "tag_name": "v1.1.0-rc.1",
+                        "description": "Release candidate",
+                        "released_at": "2022-03-01T10:00:00.000Z",
+                        "assets": {"links": []},
+                        "_links": {"self": "https://gitlab.example/releases/v1.1.0-rc.1"}
+                    },
+                    {
+                        "tag_name": "v1.0.0",
+                        "description": null,
+                        "released_at": "2022-02-01T10:00:00.000Z",
+                        "upcoming_release": false,
+                        "assets": {"links": [{"name": "sbom.spdx.json"}]},
+                        "_links": {"self": "https://gitlab.example/releases/v1.0.0"}
+                    }
+                ]"#,
+            )
+            .await,
+            server
+                .mock("GET", format!("{PROJECT_PATH}/merge_requests").as_str())
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("state".into(), "merged".into()),
+                    Matcher::UrlEncoded("order_by".into(), "merged_at".into()),
+                    Matcher::UrlEncoded("sort".into(), "desc".into()),
+                ]))
+                .with_status(200)
+                .with_body(r#"[{"iid": 7}]"#)
+                .create_async()
+                .await,
+            mock_get(
+                &mut server,
+                &format!("{PROJECT_PATH}/merge_requests/7/pipelines"),
+                r#"[{"id": 42}]"#,
+            )
+            .await,
+            mock_get(
+                &mut server,
+                &format!("{PROJECT_PATH}/pipelines/42/jobs"),
+                r#"[{"name": "build"}, {"name": "dco"}]"#,
+            )
+            .await,
+            server
+                .mock(
+                    "HEAD",
+                    format!("{PROJECT_PATH}/repository/files/SECURITY.md").as_str(),
+                )
+                .match_query(Matcher::UrlEncoded("ref".into(), "main".into()))
+                .with_status(200)
+                .create_async()
+                .await,
+        ];
+
+        let md = metadata(&repo_url, None).await.unwrap();
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+
+        assert_eq!(md.name, "repo");
+        assert_eq!(md.owner.login, "group/subgroup");
+        assert_eq!(md.owner.on, MdRepositoryOwnerOn::Organization);
+        assert_eq!(md.default_branch_ref.unwrap().name, "main");
+        assert_eq!(md.license_info.unwrap().spdx_id.unwrap(), "Apache-2.0");
+        assert!(md.homepage_url.is_none());
+        assert_eq!(
+            md.security_policy_url.unwrap(),
+            format!("{repo_url}/-/blob/main/SECURITY.md")
+        );
+
+        let releases = md.releases.nodes.unwrap();
+        let rc = releases[0].as_ref().unwrap();
+        assert!(rc.is_prerelease);
+        let release = releases[1].as_ref().unwrap();
+        assert!(!release.is_prerelease);
+        assert_eq!(release.created_at, "2022-02-01T10:00:00.000Z");
+        assert_eq!(release.url, "https://gitlab.example/releases/v1.0.0");
+        assert_eq!(
+            release.release_assets.nodes.as_ref().unwrap()[0]
+                .as_ref()
+                .unwrap()
+                .name,
+            "sbom.spdx.json"
+        );
+
+        let gh_md = MdRepository {
+            pull_requests: md.pull_requests,
+            ..MdRepository::default()
+        };
+        assert!(super::super::github::has_check(
+            &gh_md,
+            &regex::RegexSet::new(["dco"]).unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn metadata_no_merge_requests_nor_security_policy() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/group/subgroup/repo", server.url());
+        mock_get(
+            &mut server,
+            PROJECT_PATH,
+            r#"{
+                "path": "repo",
+                "default_branch": "main",
+                "license": null,
+                "namespace": {"full_path": "group/subgroup", "kind": "user"}
+            }"#,
+        )
+        .await;
+        mock_get(&mut server, &format!("{PROJECT_PATH}/releases"), "[]").await;
+        mock_get(&mut server, &format!("{PROJECT_PATH}/merge_requests"), "[]").await;
+        server
+            .mock("HEAD", Matcher::Any)
+            .with_status(404)
+            .expect(SECURITY_POLICY_FILES.len())
+            .create_async()
+            .await;
+
+        let md = metadata(&repo_url, None).await.unwrap();
+
+        assert_eq!(md.owner.on, MdRepositoryOwnerOn::User);
+        assert!(md.license_info.is_none());
+        assert!(md.pull_requests.nodes.is_none());
+        assert!(md.releases.nodes.unwrap().is_empty());
+        assert!(md.security_policy_url.is_none());
+    }
+
+    #[tokio::test]
+    async fn metadata_project_not_found() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/group/subgroup/repo", server.url());
+        server
+            .mock("GET", PROJECT_PATH)
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .with_body("not found")
+            .create_async()
+            .await;
+
+        assert_eq!(
+            metadata(&repo_url, None).await.unwrap_err().to_string(),
+            "unexpected status code querying gitlab api: 404 Not Found - not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata_token_provided() {
+        let mut server = Server::new_async().await;
+        let repo_url = format!("{}/group/subgroup/repo", server.url());
+        server
+            .mock("GET", PROJECT_PATH)
+            .match_query(Matcher::Any)
+            .match_header("private-token", "secret")
+            .with_status(403)
+            .with_body("forbidden")
+            .create_async()
+            .await;
+
+        assert_eq!(
+            metadata(&repo_url, Some("secret"))
+                .await
+                .unwrap_err()
+                .to_string(),
+            "unexpected status code querying gitlab api: 403 Forbidden - forbidden"
+        );
+    }
+}
@@ -1,4 +1,8 @@
+pub(crate) mod forge;
+pub(crate) mod gitea;
 pub(crate) mod github;
+pub(crate) mod gitlab;
 pub(crate) mod landscape;
+pub(crate) mod rest;
 pub(crate) mod scorecard;
 pub(crate) mod security_insights;
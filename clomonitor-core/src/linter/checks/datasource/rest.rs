@@ -0,0 +1,132 @@
+use anyhow::{format_err, Context, Result};
+use regex::Regex;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::de::DeserializeOwned;
+use std::{path::Path, time::Duration};
+
+/// Forges REST API requests timeout (in seconds).
+const API_TIMEOUT: u64 = 30;
+
+/// Setup a new HTTP client to query a forge's REST API. When provided, the
+/// authentication header is sent on every request.
+pub(crate) fn setup_http_client(auth: Option<(HeaderName, &str)>) -> Result<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+    if let Some((name, value)) = auth {
+        let mut value = HeaderValue::from_str(value)?;
+        value.set_sensitive(true);
+        headers.insert(name, value);
+    }
+    let http_client = reqwest::Client::builder()
+        .user_agent("clomonitor")
+        .default_headers(headers)
+        .timeout(Duration::from_secs(API_TIMEOUT))
+        .build()?;
+    Ok(http_client)
+}
+
+/// Do a GET request to the url provided of the forge's API given (used in
+/// error messages), deserializing the response body.
+pub(crate) async fn get<T: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    api: &str,
+    url: &str,
+) -> Result<T> {
+    let resp = http_client
+        .get(url)
+        .send()
+        .await
+        .context(format!("error querying {api} api"))?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying {api} api: {} - {}",
+            resp.status(),
+            resp.text().await?,
+        ));
+    }
+    let resp_body = resp.text().await?;
+    serde_json::from_str(&resp_body).context(format!("error deserializing response: {resp_body}"))
+}
+
+/// Extract the base url, owner and repository from the repository url
+/// provided, using the regular expression given (which must define the
+/// `base`, `owner` and `repo` capture groups).
+pub(crate) fn get_base_owner_and_repo(
+    repo_url_re: &Regex,
+    repo_url: &str,
+) -> Result<(String, String, String)> {
+    let c = repo_url_re
+        .captures(repo_url)
+        .ok_or_else(|| format_err!("invalid repository url"))?;
+    Ok((
+        c["base"].to_string(),
+        c["owner"].to_string(),
+        c["repo"].to_string(),
+    ))
+}
+
+/// Build a url from the repository url, path and branch provided. The files
+/// prefix is the forge specific path segment used to browse the repository's
+/// files (i.e. `-/blob`).
+pub(crate) fn build_url(repo_url: &str, files_prefix: &str, path: &Path, branch: &str) -> String {
+    format!(
+        "{}/{files_prefix}/{branch}/{}",
+        repo_url.trim_end_matches('/'),
+        path.to_string_lossy(),
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use mockito::{Matcher, Server, ServerGuard};
+
+    #[tokio::test]
+    async fn get_works() {
+        let mut server = Server::new_async().await;
+        mock_get(&mut server, "/api", r#"{"name": "repo"}"#).await;
+
+        let value: serde_json::Value = get(
+            &setup_http_client(None).unwrap(),
+            "forge",
+            &format!("{}/api", server.url()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(value["name"], "repo");
+    }
+
+    #[tokio::test]
+    async fn get_invalid_response() {
+        let mut server = Server::new_async().await;
+        mock_get(&mut server, "/api", "invalid").await;
+
+        assert_eq!(
+            get::<serde_json::Value>(
+                &setup_http_client(None).unwrap(),
+                "forge",
+                &format!("{}/api", server.url()),
+            )
+            .await
+            .unwrap_err()
+            .to_string(),
+            "error deserializing response: invalid"
+        );
+    }
+
+    pub(crate) async fn mock_get(
+        server: &mut ServerGuard,
+        path: &str,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("GET", path)
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await
+    }
+}
@@ -1,17 +1,90 @@
-use crate::linter::checks::CHECKS;
-use anyhow::{format_err, Error, Result};
-use serde::Deserialize;
+use crate::linter::{
+    check::{CheckInput, CheckOutput},
+    checks::{signed_releases, CHECKS},
+    LinterInput,
+};
+use anyhow::{format_err, Context, Error, Result};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::Path, time::Duration};
 use tokio::process::Command;
+use tracing::warn;
+use which::which;
+
+/// Default score a scorecard check must reach to pass.
+pub(crate) const DEFAULT_PASS_THRESHOLD: f64 = 5.0;
+
+/// Timeout used for the requests to the Scorecard API (in seconds).
+const API_TIMEOUT: u64 = 30;
 
 /// Scorecard report (list of checks).
-#[derive(Debug, Clone, Deserialize)]
-pub(crate) struct Scorecard {
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Scorecard {
     checks: Vec<ScorecardCheck>,
+
+    /// Repository the scorecard was produced for.
+    #[serde(default)]
+    repo: Option<ScorecardRepo>,
+
+    /// Source the scorecard was obtained from.
+    #[serde(skip)]
+    pub source: ScorecardSource,
+}
+
+impl Scorecard {
+    /// Checks included in the scorecard.
+    #[must_use]
+    pub fn checks(&self) -> &[ScorecardCheck] {
+        &self.checks
+    }
+
+    /// Check that the scorecard was produced for the repository provided.
+    fn verify_repository(&self, repo_url: &str) -> Result<()> {
+        let expected = project(repo_url)?;
+        match &self.repo {
+            Some(repo) if repo.name.eq_ignore_ascii_case(expected) => Ok(()),
+            Some(repo) => Err(format_err!(
+                "scorecard repository ({}) does not match {expected}",
+                repo.name
+            )),
+            None => Err(format_err!("scorecard repository not provided")),
+        }
+    }
+}
+
+/// Scorecard repository details.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ScorecardRepo {
+    name: String,
+}
+
+/// Source an OpenSSF Scorecard can be obtained from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScorecardSource {
+    /// Scorecard CLI run by the linter.
+    #[default]
+    Cli,
+    /// Precomputed scorecard read from a JSON file.
+    File,
+    /// Precomputed scorecard fetched from a Scorecard API endpoint.
+    Api,
+}
+
+impl fmt::Display for ScorecardSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = match self {
+            Self::Cli => "CLI",
+            Self::File => "File",
+            Self::Api => "API",
+        };
+        write!(f, "{output}")
+    }
 }
 
 /// Scorecard check details.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub(crate) struct ScorecardCheck {
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScorecardCheck {
     pub name: String,
     pub reason: String,
     pub details: Option<Vec<String>>,
@@ -20,13 +93,93 @@ pub(crate) struct ScorecardCheck {
 }
 
 /// Scorecard check documentation.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-pub(crate) struct ScorecardCheckDocs {
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScorecardCheckDocs {
     pub url: String,
 }
 
-/// Get repository's OpenSSF Scorecard.
-pub(crate) async fn scorecard(repo_url: &str, github_token: &str) -> Result<Scorecard> {
+/// Get repository's OpenSSF Scorecard from the source requested. When a
+/// precomputed source (JSON file or Scorecard API endpoint) is provided, it's
+/// used and any error getting the scorecard from it is returned. The only
+/// exception are repositories not available in the Scorecard API, which fall
+/// back to running the scorecard CLI, as it happens when no source is given.
+pub(crate) async fn scorecard(li: &LinterInput) -> Result<Scorecard> {
+    // Precomputed scorecard JSON file
+    if let Some(path) = &li.scorecard_json {
+        let scorecard = from_file(path)?;
+        scorecard.verify_repository(&li.url)?;
+        return Ok(scorecard);
+    }
+
+    // Scorecard API endpoint
+    if let Some(api_url) = &li.scorecard_api_url {
+        if let Some(scorecard) = from_api(api_url, &li.url).await? {
+            scorecard.verify_repository(&li.url)?;
+            return Ok(scorecard);
+        }
+        warn!("scorecard not available in api, falling back to cli");
+    }
+
+    // Scorecard CLI
+    from_cli(&li.url, &li.github_token).await
+}
+
+/// Read a precomputed scorecard from the JSON file provided. Both the output
+/// of the scorecard CLI and the Scorecard API format are supported.
+fn from_file(path: &Path) -> Result<Scorecard> {
+    let data =
+        fs::read_to_string(path).context(format!("error reading file {}", path.display()))?;
+    let mut scorecard: Scorecard = serde_json::from_str(&data)?;
+    scorecard.source = ScorecardSource::File;
+    Ok(scorecard)
+}
+
+/// Get the repository's scorecard from the Scorecard API endpoint provided
+/// (i.e. https://api.securityscorecards.dev). None is returned when the
+/// repository's scorecard is not available in the API.
+async fn from_api(api_url: &str, repo_url: &str) -> Result<Option<Scorecard>> {
+    let url = format!(
+        "{}/projects/{}",
+        api_url.trim_end_matches('/'),
+        project(repo_url)?
+    );
+    let resp = reqwest::Client::builder()
+        .timeout(Duration::from_secs(API_TIMEOUT))
+        .build()?
+        .get(&url)
+        .send()
+        .await
+        .context("error querying scorecard api")?;
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code querying scorecard api: {}",
+            resp.status()
+        ));
+    }
+    let mut scorecard: Scorecard = resp.json().await?;
+    scorecard.source = ScorecardSource::Api;
+    Ok(Some(scorecard))
+}
+
+/// Get the project name used by OpenSSF Scorecard to identify the repository
+/// provided (i.e. github.com/owner/repo).
+fn project(repo_url: &str) -> Result<&str> {
+    repo_url
+        .split_once("://")
+        .map(|(_, project)| project.trim_end_matches('/'))
+        .ok_or_else(|| format_err!("invalid repository url"))
+}
+
+/// Get the repository's scorecard running the scorecard CLI.
+async fn from_cli(repo_url: &str, github_token: &str) -> Result<Scorecard> {
+    if which("scorecard").is_err() {
+        return Err(format_err!(
+            "scorecard not found in PATH (https://github.com/ossf/scorecard#installation)"
+        ));
+    }
     let output = Command::new("scorecard")
         .env("GITHUB_TOKEN", github_token)
         .env_remove("GITHUB_REF")
@@ -44,6 +197,27 @@ pub(crate) async fn scorecard(repo_url: &str, github_token: &str) -> Result<Scor
     Ok(scorecard)
 }
 
+/// Get the output of the check provided from the corresponding scorecard
+/// check, using the pass threshold that applies to it.
+pub(crate) fn check_output<T>(input: &CheckInput, check_id: &str) -> CheckOutput<T> {
+    CheckOutput::from_scorecard_check(
+        get_check(&input.scorecard, check_id),
+        pass_threshold(input.li, check_id),
+    )
+}
+
+/// Get the pass threshold for the check provided. Thresholds provided in the
+/// linter input take precedence over the default ones.
+pub(crate) fn pass_threshold(li: &LinterInput, check_id: &str) -> f64 {
+    if let Some(threshold) = li.scorecard_thresholds.get(check_id) {
+        return *threshold;
+    }
+    match check_id {
+        signed_releases::ID => signed_releases::PASS_THRESHOLD,
+        _ => DEFAULT_PASS_THRESHOLD,
+    }
+}
+
 // Get a check from the scorecard provided if available.
 pub(crate) fn get_check<'a>(
     scorecard: &'a Result<Scorecard>,
@@ -53,7 +227,7 @@ pub(crate) fn get_check<'a>(
         Ok(scorecard) => Ok(scorecard
             .checks
             .iter()
-            .find(|c| &c.name == CHECKS[check_id].scorecard_name.as_ref().unwrap())),
+            .find(|c| Some(c.name.as_str()) == CHECKS[check_id].scorecard_name())),
         Err(err) => Err(err),
     }
 }
@@ -61,7 +235,11 @@ pub(crate) fn get_check<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::checks::code_review;
+    use crate::linter::checks::{code_review, token_permissions};
+    use mockito::Server;
+    use std::path::PathBuf;
+
+    const TESTDATA_PATH: &str = "src/testdata";
 
     #[test]
     fn get_check_found() {
@@ -75,6 +253,7 @@ mod tests {
                     url: "https://test.url".to_string(),
                 },
             }],
+            ..Scorecard::default()
         });
 
         assert_eq!(
@@ -85,8 +264,176 @@ mod tests {
 
     #[test]
     fn get_check_not_found() {
-        let scorecard = Ok(Scorecard { checks: vec![] });
+        let scorecard = Ok(Scorecard::default());
 
         assert!(get_check(&scorecard, code_review::ID).unwrap().is_none());
     }
+
+    #[test]
+    fn from_file_works() {
+        let scorecard = from_file(&Path::new(TESTDATA_PATH).join("scorecard.json")).unwrap();
+
+        assert_eq!(scorecard.source, ScorecardSource::File);
+        assert_eq!(scorecard.checks.len(), 1);
+        assert_eq!(scorecard.checks[0].name, "Code-Review");
+    }
+
+    #[test]
+    fn from_file_not_found() {
+        assert!(from_file(&Path::new(TESTDATA_PATH).join("not-found.json")).is_err());
+    }
+
+    #[tokio::test]
+    async fn from_api_works() {
+        let mut server = Server::new_async().await;
+        let req = server
+            .mock("GET", "/projects/github.com/owner/repo")
+            .with_status(200)
+            .with_body(fs::read(Path::new(TESTDATA_PATH).join("scorecard.json")).unwrap())
+            .create_async()
+            .await;
+
+        let scorecard = from_api(&server.url(), "https://github.com/owner/repo/")
+            .await
+            .unwrap()
+            .unwrap();
+        req.assert_async().await;
+
+        assert_eq!(scorecard.source, ScorecardSource::Api);
+        assert_eq!(scorecard.checks[0].name, "Code-Review");
+    }
+
+    #[tokio::test]
+    async fn from_api_not_found() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/projects/github.com/owner/repo")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        assert!(from_api(&server.url(), "https://github.com/owner/repo")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn from_api_error() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/projects/github.com/owner/repo")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        assert_eq!(
+            from_api(&server.url(), "https://github.com/owner/repo")
+                .await
+                .unwrap_err()
+                .to_string(),
+            "unexpected status code querying scorecard api: 500 Internal Server Error"
+        );
+    }
+
+    #[tokio::test]
+    async fn scorecard_from_file() {
+        let li = LinterInput {
+            url: "https://github.com/Owner/Repo/".to_string(),
+            scorecard_json: Some(PathBuf::from(TESTDATA_PATH).join("scorecard.json")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(scorecard(&li).await.unwrap().source, ScorecardSource::File);
+    }
+
+    #[tokio::test]
+    async fn scorecard_file_error_is_returned() {
+        let mut server = Server::new_async().await;
+        let req = server
+            .mock("GET", "/projects/github.com/owner/repo")
+            .expect(0)
+            .create_async()
+            .await;
+        let li = LinterInput {
+            url: "https://github.com/owner/repo".to_string(),
+            scorecard_json: Some(PathBuf::from(TESTDATA_PATH).join("not-found.json")),
+            scorecard_api_url: Some(server.url()),
+            ..LinterInput::default()
+        };
+
+        assert!(scorecard(&li)
+            .await
+            .unwrap_err()
+            .to_string()
+            .starts_with("error reading file"));
+        req.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn scorecard_file_repository_mismatch() {
+        let li = LinterInput {
+            url: "https://github.com/owner/other-repo".to_string(),
+            scorecard_json: Some(PathBuf::from(TESTDATA_PATH).join("scorecard.json")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            scorecard(&li).await.unwrap_err().to_string(),
+            "scorecard repository (github.com/owner/repo) does not match github.com/owner/other-repo"
+        );
+    }
+
+    #[tokio::test]
+    async fn scorecard_api_error_is_returned() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/projects/github.com/owner/repo")
+            .with_status(500)
+            .create_async()
+            .await;
+        let li = LinterInput {
+            url: "https://github.com/owner/repo".to_string(),
+            scorecard_api_url: Some(server.url()),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            scorecard(&li).await.unwrap_err().to_string(),
+            "unexpected status code querying scorecard api: 500 Internal Server Error"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn pass_threshold_default() {
+        let li = LinterInput::default();
+
+        assert_eq!(pass_threshold(&li, code_review::ID), DEFAULT_PASS_THRESHOLD);
+        assert_eq!(
+            pass_threshold(&li, signed_releases::ID),
+            signed_releases::PASS_THRESHOLD
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn pass_threshold_provided_in_input() {
+        let li = LinterInput {
+            scorecard_thresholds: [
+                (code_review::ID.to_string(), 8.0),
+                (signed_releases::ID.to_string(), 5.0),
+            ]
+            .into_iter()
+            .collect(),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(pass_threshold(&li, code_review::ID), 8.0);
+        assert_eq!(pass_threshold(&li, signed_releases::ID), 5.0);
+        assert_eq!(
+            pass_threshold(&li, token_permissions::ID),
+            DEFAULT_PASS_THRESHOLD
+        );
+    }
 }
@@ -15,7 +15,7 @@ pub(crate) const SECURITY_INSIGHTS_MANIFEST_FILE: &str = "SECURITY-INSIGHTS.yml"
 /// https://github.com/ossf/security-insights-spec/blob/v1.0.0/specification.md
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct SecurityInsights {
+pub struct SecurityInsights {
     pub contribution_policy: ContributionPolicy,
     pub dependencies: Option<Dependencies>,
     pub distribution_points: Vec<String>,
@@ -43,7 +43,7 @@ impl SecurityInsights {
 /// Project's contribution rules, requirements, and policies.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct ContributionPolicy {
+pub struct ContributionPolicy {
     pub accepts_automated_pull_requests: bool,
     pub accepts_pull_requests: bool,
 }
@@ -51,7 +51,7 @@ pub(crate) struct ContributionPolicy {
 /// Overview of the project's supply chain.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct Dependencies {
+pub struct Dependencies {
     pub env_dependencies_policy: Option<EnvDependenciesPolicy>,
     pub sbom: Option<Sbom>,
 }
@@ -59,7 +59,7 @@ pub(crate) struct Dependencies {
 /// Dependencies policy information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct EnvDependenciesPolicy {
+pub struct EnvDependenciesPolicy {
     pub comment: Option<String>,
     pub policy_url: Option<String>,
 }
@@ -67,7 +67,7 @@ pub(crate) struct EnvDependenciesPolicy {
 /// High-level information about the project.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct Header {
+pub struct Header {
     pub expiration_date: String,
     pub project_url: String,
     pub schema_version: String,
@@ -76,7 +76,7 @@ pub(crate) struct Header {
 /// Status of the project.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct ProjectLifecycle {
+pub struct ProjectLifecycle {
     pub bug_fixes_only: bool,
     pub core_maintainers: Option<Vec<String>>,
     pub status: String,
@@ -85,7 +85,7 @@ pub(crate) struct ProjectLifecycle {
 /// SBOM information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case", transparent)]
-pub(crate) struct Sbom {
+pub struct Sbom {
     pub entries: Vec<SbomEntry>,
 }
 
@@ -93,7 +93,7 @@ pub(crate) struct Sbom {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[allow(clippy::struct_field_names)]
-pub(crate) struct SbomEntry {
+pub struct SbomEntry {
     pub sbom_creation: Option<String>,
     pub sbom_file: Option<String>,
     pub sbom_format: Option<String>,
@@ -103,7 +103,7 @@ pub(crate) struct SbomEntry {
 /// Security-focused documentation.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct SecurityArtifacts {
+pub struct SecurityArtifacts {
     pub self_assessment: Option<SelfAssessment>,
 }
 
@@ -111,7 +111,7 @@ pub(crate) struct SecurityArtifacts {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[allow(clippy::struct_field_names)]
-pub(crate) struct SelfAssessment {
+pub struct SelfAssessment {
     pub comment: Option<String>,
     pub evidence_url: Option<Vec<String>>,
     pub self_assessment_created: bool,
@@ -120,7 +120,7 @@ pub(crate) struct SelfAssessment {
 /// Security contact information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct SecurityContact {
+pub struct SecurityContact {
     #[serde(alias = "type")]
     pub kind: String,
     pub value: String,
@@ -129,6 +129,6 @@ pub(crate) struct SecurityContact {
 /// Policies and procedures about how to report properly a security issue.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
-pub(crate) struct VulnerabilityReporting {
+pub struct VulnerabilityReporting {
     accepts_vulnerability_reports: bool,
 }
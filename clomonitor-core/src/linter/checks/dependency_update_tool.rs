@@ -17,5 +17,5 @@ pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 /// Check main function.
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
-    Ok(scorecard::get_check(&input.scorecard, ID).into())
+    Ok(scorecard::check_output(input, ID))
 }
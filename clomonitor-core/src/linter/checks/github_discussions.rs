@@ -35,6 +35,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::datasource::forge::Forge;
     use crate::linter::{
         datasource::github::md::{
             MdRepository, MdRepositoryDiscussions, MdRepositoryDiscussionsNodes,
@@ -48,6 +49,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     discussions: MdRepositoryDiscussions { nodes: None },
@@ -55,6 +57,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -70,6 +73,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     discussions: MdRepositoryDiscussions {
@@ -82,6 +86,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -97,6 +102,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     discussions: MdRepositoryDiscussions {
@@ -109,6 +115,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::passed().url(Some("discussion_url".to_string())),
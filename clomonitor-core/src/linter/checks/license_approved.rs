@@ -1,8 +1,10 @@
-use super::util::helpers::{find_exemption, should_skip_check};
+use super::{license_spdx_id, util::helpers::find_exemption};
 use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
     CheckSet,
 };
+use anyhow::Result;
+use time::OffsetDateTime;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_approved";
@@ -30,23 +32,25 @@ static APPROVED_LICENSES: [&str; 11] = [
 ];
 
 /// Check main function.
-pub(crate) fn check(input: &CheckInput, spdx_id: Option<String>) -> Option<CheckOutput> {
-    // Check if this check should be skipped
-    if should_skip_check(ID, &input.li.check_sets) {
-        return None;
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    // No SPDX id is available when the license SPDX id check is exempt
+    if let Some(exemption) = find_exemption(license_spdx_id::ID, input.cm_md.as_ref()) {
+        if !exemption.is_expired(OffsetDateTime::now_utc().date()) {
+            return Ok(CheckOutput::not_passed());
+        }
     }
 
-    // Check if an exemption has been declared for this check
-    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
-        return Some(CheckOutput::from(exemption));
-    }
-
-    // SPDX id in list of approved licenses
+    // SPDX id in list of approved licenses (the license detection is shared
+    // with the license SPDX id check, which reports its errors)
+    let spdx_id = license_spdx_id::check(input)
+        .ok()
+        .and_then(|output| output.value);
     if spdx_id.map_or(false, |spdx_id| is_approved(&spdx_id)) {
-        return Some(CheckOutput::passed());
+        return Ok(CheckOutput::passed());
     }
 
-    Some(CheckOutput::not_passed())
+    Ok(CheckOutput::not_passed())
 }
 
 /// Check if the license provided is an approved one.
@@ -57,6 +61,46 @@ fn is_approved(spdx_id: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::{
+        datasource::{forge::Forge, github::md::MdRepository},
+        metadata::{Exemption, Metadata},
+        LinterInput,
+    };
+    use anyhow::format_err;
+
+    fn check_with(cm_md: Option<Metadata>) -> CheckOutput {
+        check(&CheckInput {
+            li: &LinterInput::default(),
+            forge: Forge::GitHub,
+            cm_md,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: Some("Apache-2.0".to_string()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn passed_approved_license_detected() {
+        assert_eq!(check_with(None), CheckOutput::passed());
+    }
+
+    #[test]
+    fn not_passed_license_spdx_id_exempt() {
+        assert_eq!(
+            check_with(Some(Metadata {
+                exemptions: Some(vec![Exemption {
+                    check: license_spdx_id::ID.to_string(),
+                    reason: "sample reason".to_string(),
+                    ..Default::default()
+                }]),
+                license_scanning: None,
+                locations: None,
+            })),
+            CheckOutput::not_passed(),
+        );
+    }
 
     #[test]
     fn approved_license() {
@@ -41,9 +41,13 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     }
 
     // Reference in README file
-    if let Some(url) = content::find(&readme_globs(&input.li.root), &[&FOSSA_URL, &SNYK_URL])? {
-        return Ok(CheckOutput::passed().url(Some(url)));
-    };
+    if let Some((url, evidence)) =
+        content::find(&readme_globs(&input.li.root), &[&FOSSA_URL, &SNYK_URL])?
+    {
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(Some(evidence)));
+    }
 
     Ok(CheckOutput::not_passed())
 }
@@ -51,6 +55,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::datasource::forge::Forge;
     use crate::linter::{
         datasource::github::md::MdRepository,
         metadata::{LicenseScanning, Metadata},
@@ -63,10 +68,12 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -78,13 +85,16 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: Some(Metadata {
                     exemptions: None,
                     license_scanning: None,
+                    locations: None,
                 }),
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -96,15 +106,18 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: Some(Metadata {
                     exemptions: None,
                     license_scanning: Some(LicenseScanning {
                         url: Some("license_scanning_url".to_string()),
                     }),
+                    locations: None,
                 }),
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::passed().url(Some("license_scanning_url".to_string())),
@@ -5,6 +5,7 @@ use crate::linter::{util, CheckSet};
 use anyhow::Result;
 use askalono::*;
 use lazy_static::lazy_static;
+use std::path::Path;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_spdx_id";
@@ -22,14 +23,11 @@ const LICENSES_DATA: &[u8] = include_bytes!("licenses/licenses.bin.zstd");
 pub(crate) const FILE_PATTERNS: [&str; 2] = ["LICENSE*", "COPYING*"];
 
 /// Check main function.
+#[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<String>> {
     // File in repo
-    if let Some(spdx_id) = detect(&Globs {
-        root: &input.li.root,
-        patterns: &FILE_PATTERNS,
-        case_sensitive: true,
-    })? {
-        return Ok(CheckOutput::passed().value(Some(spdx_id)));
+    if let Some(spdx_id) = &input.license {
+        return Ok(CheckOutput::passed().value(Some(spdx_id.clone())));
     }
 
     // License detected by Github
@@ -47,6 +45,17 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<String>> {
     Ok(CheckOutput::not_passed())
 }
 
+/// Detect the license of the repository located at the root provided and
+/// return its SPDX id if possible. This is done once when preparing the check
+/// input, as the license approved check relies on it as well.
+pub(crate) fn detect_in_root(root: &Path) -> Result<Option<String>> {
+    detect(&Globs {
+        root,
+        patterns: &FILE_PATTERNS,
+        case_sensitive: true,
+    })
+}
+
 /// Detect repository's license and return its SPDX id if possible.
 pub(crate) fn detect(globs: &Globs) -> Result<Option<String>> {
     lazy_static! {
@@ -70,11 +79,33 @@ pub(crate) fn detect(globs: &Globs) -> Result<Option<String>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linter::checks::license_spdx_id;
+    use crate::linter::{
+        checks::license_spdx_id,
+        datasource::{forge::Forge, github::md::MdRepository},
+        LinterInput,
+    };
+    use anyhow::format_err;
     use std::path::Path;
 
     const TESTDATA_PATH: &str = "src/testdata";
 
+    #[test]
+    fn passed_license_detected() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                forge: Forge::GitHub,
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                license: Some("Apache-2.0".to_string()),
+            })
+            .unwrap(),
+            CheckOutput::passed().value(Some("Apache-2.0".to_string())),
+        );
+    }
+
     #[test]
     fn detect_identified() {
         assert_eq!(
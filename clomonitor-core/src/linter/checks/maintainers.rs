@@ -1,4 +1,4 @@
-use super::util::helpers::find_file_or_readme_ref;
+use super::util::helpers::{find_declared_location, find_file_or_readme_ref};
 use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
     CheckSet,
@@ -37,7 +37,12 @@ lazy_static! {
 }
 
 /// Check main function.
-pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Location declared in the metadata file
+    if let Some(output) = find_declared_location(input, ID).await? {
+        return Ok(output);
+    }
+
     // File in repo or reference in README file
     find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)
 }
@@ -1,16 +1,26 @@
-use crate::linter::check::{CheckConfig, CheckId};
+use crate::linter::{
+    cache::CacheInputs,
+    check::{Check, CheckId, CheckInput, CheckOutput, RemoteData},
+    CheckRegistry, CheckSet, Remediation, Section,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+use serde_json::Value;
+use std::sync::Arc;
 
 pub(crate) mod adopters;
 pub(crate) mod artifacthub_badge;
 pub(crate) mod binary_artifacts;
+pub(crate) mod branch_protection;
 pub(crate) mod changelog;
 pub(crate) mod cla;
 pub(crate) mod code_of_conduct;
 pub(crate) mod code_review;
 pub(crate) mod community_meeting;
 pub(crate) mod contributing;
+pub(crate) mod contributor_diversity;
 pub(crate) mod dangerous_workflow;
 pub(crate) mod datasource;
 pub(crate) mod dco;
@@ -27,6 +37,8 @@ pub(crate) mod openssf_badge;
 pub(crate) mod openssf_scorecard_badge;
 pub(crate) mod readme;
 pub(crate) mod recent_release;
+pub(crate) mod release_cadence;
+pub(crate) mod responsiveness;
 pub(crate) mod roadmap;
 pub(crate) mod sbom;
 pub(crate) mod security_insights;
@@ -40,67 +52,432 @@ pub(crate) mod util;
 pub(crate) mod website;
 
 lazy_static! {
-    pub(crate) static ref CHECKS: HashMap<CheckId, CheckConfig> = {
-        let mut checks = HashMap::new();
-
-        macro_rules! register_check {
-            ($check:ident) => {
-                checks.insert(
-                    $check::ID,
-                    CheckConfig {
-                        weight: $check::WEIGHT,
-                        check_sets: $check::CHECK_SETS.to_vec(),
-                        scorecard_name: None,
-                    },
-                );
-            };
-            ($check:ident, $scorecard_name:expr) => {
-                checks.insert(
-                    $check::ID,
-                    CheckConfig {
-                        weight: $check::WEIGHT,
-                        check_sets: $check::CHECK_SETS.to_vec(),
-                        scorecard_name: Some($scorecard_name.to_string()),
-                    },
-                );
-            };
+    /// Registry containing all the builtin checks.
+    pub(crate) static ref CHECKS: CheckRegistry = CheckRegistry::builtin();
+}
+
+/// Type alias to represent the function used to run a builtin check.
+type CheckFn = for<'a> fn(&'a CheckInput<'_>) -> BoxFuture<'a, Result<CheckOutput<Value>>>;
+
+/// Check implementation used by the builtin checks.
+struct BuiltinCheck {
+    id: CheckId,
+    weight: usize,
+    check_sets: &'static [CheckSet],
+    section: Section,
+    scorecard_name: Option<&'static str>,
+    remote_data: RemoteData,
+    is_async: bool,
+    cache_inputs: Option<CacheInputs>,
+    remediation: Option<Remediation>,
+    run: CheckFn,
+}
+
+#[async_trait]
+impl Check for BuiltinCheck {
+    fn id(&self) -> CheckId {
+        self.id
+    }
+
+    fn weight(&self) -> usize {
+        self.weight
+    }
+
+    fn check_sets(&self) -> &[CheckSet] {
+        self.check_sets
+    }
+
+    fn section(&self) -> Section {
+        self.section
+    }
+
+    fn scorecard_name(&self) -> Option<&str> {
+        self.scorecard_name
+    }
+
+    fn remote_data(&self) -> RemoteData {
+        self.remote_data
+    }
+
+    fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    fn cache_inputs(&self) -> Option<CacheInputs> {
+        self.cache_inputs.clone()
+    }
+
+    fn remediation(&self) -> Option<Remediation> {
+        self.remediation.clone()
+    }
+
+    async fn run(&self, input: &CheckInput<'_>) -> Result<CheckOutput<Value>> {
+        (self.run)(input).await
+    }
+}
+
+/// Register all the builtin checks in the registry provided.
+pub(crate) fn register_builtin(registry: &mut CheckRegistry) {
+    macro_rules! register_check {
+        (@ $check:ident, $section:ident, $remote_data:ident, $scorecard_name:expr, $is_async:expr, $run:expr) => {
+            registry.register(Arc::new(BuiltinCheck {
+                id: $check::ID,
+                weight: $check::WEIGHT,
+                check_sets: &$check::CHECK_SETS,
+                section: Section::$section,
+                scorecard_name: $scorecard_name,
+                remote_data: RemoteData::$remote_data,
+                is_async: $is_async,
+                cache_inputs: cache_inputs($check::ID),
+                remediation: remediation($check::ID),
+                run: $run,
+            }));
+        };
+        ($check:ident, $section:ident, $remote_data:ident, async) => {
+            register_check!(@ $check, $section, $remote_data, None, true, |input| Box::pin(async move {
+                $check::check(input).await.map(CheckOutput::into_json)
+            }));
+        };
+        ($check:ident, $section:ident, $remote_data:ident, $scorecard_name:expr) => {
+            register_check!(@ $check, $section, $remote_data, Some($scorecard_name), false, |input| Box::pin(async move {
+                $check::check(input).map(CheckOutput::into_json)
+            }));
+        };
+        ($check:ident, $section:ident, $remote_data:ident) => {
+            register_check!(@ $check, $section, $remote_data, None, false, |input| Box::pin(async move {
+                $check::check(input).map(CheckOutput::into_json)
+            }));
+        };
+    }
+
+    // Documentation
+    register_check!(adopters, Documentation, None);
+    register_check!(changelog, Documentation, Fallback);
+    register_check!(code_of_conduct, Documentation, Fallback);
+    register_check!(contributing, Documentation, Fallback, async);
+    register_check!(governance, Documentation, None, async);
+    register_check!(maintainers, Documentation, None, async);
+    register_check!(readme, Documentation, None);
+    register_check!(roadmap, Documentation, None, async);
+    register_check!(summary_table, Documentation, Required, async);
+    register_check!(website, Documentation, Required);
+
+    // License
+    register_check!(license_approved, License, Fallback);
+    register_check!(license_scanning, License, None);
+    register_check!(license_spdx_id, License, Fallback);
+
+    // Best practices
+    register_check!(artifacthub_badge, BestPractices, None);
+    register_check!(cla, BestPractices, Required);
+    register_check!(community_meeting, BestPractices, None);
+    register_check!(contributor_diversity, BestPractices, None);
+    register_check!(dco, BestPractices, Fallback);
+    register_check!(github_discussions, BestPractices, Required);
+    register_check!(openssf_badge, BestPractices, Fallback, async);
+    register_check!(openssf_scorecard_badge, BestPractices, None);
+    register_check!(recent_release, BestPractices, Required);
+    register_check!(release_cadence, BestPractices, Required);
+    register_check!(responsiveness, BestPractices, Required);
+    register_check!(slack_presence, BestPractices, None);
+
+    // Security
+    register_check!(binary_artifacts, Security, Required, "Binary-Artifacts");
+    register_check!(branch_protection, Security, Required);
+    register_check!(code_review, Security, Required, "Code-Review");
+    register_check!(dangerous_workflow, Security, Required, "Dangerous-Workflow");
+    register_check!(dependencies_policy, Security, None);
+    register_check!(
+        dependency_update_tool,
+        Security,
+        Required,
+        "Dependency-Update-Tool"
+    );
+    register_check!(maintained, Security, Required, "Maintained");
+    register_check!(sbom, Security, Fallback);
+    register_check!(security_insights, Security, None);
+    register_check!(security_policy, Security, Fallback);
+    register_check!(signed_releases, Security, Required, "Signed-Releases");
+    register_check!(token_permissions, Security, Required, "Token-Permissions");
+
+    // Legal
+    register_check!(trademark_disclaimer, Legal, Required, async);
+}
+
+/// Inputs the output of the builtin checks depends on. Checks that fetch
+/// remote content when they run, or whose output depends on the current time,
+/// are never cached.
+fn cache_inputs(check_id: CheckId) -> Option<CacheInputs> {
+    let files = |patterns: &[&[&'static str]]| patterns.concat();
+    let readme = &readme::FILE_PATTERNS[..];
+
+    let inputs = match check_id {
+        // Local content only
+        adopters::ID => CacheInputs {
+            files: files(&[&adopters::FILE_PATTERNS, readme]),
+            ..CacheInputs::default()
+        },
+        governance::ID => CacheInputs {
+            files: files(&[&governance::FILE_PATTERNS, readme]),
+            ..CacheInputs::default()
+        },
+        maintainers::ID => CacheInputs {
+            files: files(&[&maintainers::FILE_PATTERNS, readme]),
+            ..CacheInputs::default()
+        },
+        roadmap::ID => CacheInputs {
+            files: files(&[&roadmap::FILE_PATTERNS, readme]),
+            ..CacheInputs::default()
+        },
+        artifacthub_badge::ID
+        | community_meeting::ID
+        | license_scanning::ID
+        | openssf_scorecard_badge::ID
+        | readme::ID
+        | slack_presence::ID => CacheInputs {
+            files: readme.to_vec(),
+            ..CacheInputs::default()
+        },
+        dependencies_policy::ID | security_insights::ID => CacheInputs::default(),
+
+        // Local content and forge metadata
+        changelog::ID => CacheInputs {
+            files: files(&[&changelog::FILE_PATTERNS, readme]),
+            forge_metadata: vec!["releases"],
+            ..CacheInputs::default()
+        },
+        code_of_conduct::ID => CacheInputs {
+            files: files(&[&code_of_conduct::FILE_PATTERNS, readme]),
+            forge_metadata: vec!["codeOfConduct"],
+            ..CacheInputs::default()
+        },
+        license_approved::ID | license_spdx_id::ID => CacheInputs {
+            files: license_spdx_id::FILE_PATTERNS.to_vec(),
+            forge_metadata: vec!["licenseInfo"],
+            ..CacheInputs::default()
+        },
+        sbom::ID => CacheInputs {
+            files: readme.to_vec(),
+            forge_metadata: vec!["releases"],
+            ..CacheInputs::default()
+        },
+        security_policy::ID => CacheInputs {
+            files: files(&[&security_policy::FILE_PATTERNS, readme]),
+            forge_metadata: vec!["securityPolicyUrl"],
+            ..CacheInputs::default()
+        },
+
+        // Git history and forge metadata
+        dco::ID => CacheInputs {
+            forge_metadata: vec!["pullRequests"],
+            git_head: true,
+            ..CacheInputs::default()
+        },
+
+        // Forge metadata only
+        branch_protection::ID => CacheInputs {
+            forge_metadata: vec!["defaultBranchRef"],
+            ..CacheInputs::default()
+        },
+        cla::ID => CacheInputs {
+            forge_metadata: vec!["pullRequests"],
+            ..CacheInputs::default()
+        },
+        website::ID => CacheInputs {
+            forge_metadata: vec!["homepageUrl"],
+            ..CacheInputs::default()
+        },
+
+        // OpenSSF Scorecard
+        binary_artifacts::ID
+        | code_review::ID
+        | dangerous_workflow::ID
+        | dependency_update_tool::ID
+        | maintained::ID
+        | signed_releases::ID
+        | token_permissions::ID => CacheInputs {
+            scorecard: true,
+            ..CacheInputs::default()
+        },
+
+        _ => return None,
+    };
+    Some(inputs)
+}
+
+/// Guidance on how to get the builtin checks to pass.
+#[allow(clippy::too_many_lines)]
+fn remediation(check_id: CheckId) -> Option<Remediation> {
+    let remediation = match check_id {
+        // Documentation
+        adopters::ID => Remediation::new(
+            "Add an ADOPTERS.md file listing the organizations using the project",
+            "adopters",
+        )
+        .template("ADOPTERS.md"),
+        changelog::ID => Remediation::new(
+            "Add a CHANGELOG.md file or describe the changes in the releases notes",
+            "changelog",
+        )
+        .template("CHANGELOG.md"),
+        code_of_conduct::ID => Remediation::new(
+            "Add a CODE_OF_CONDUCT.md file adopting a code of conduct",
+            "code-of-conduct",
+        )
+        .template("CODE_OF_CONDUCT.md"),
+        contributing::ID => Remediation::new(
+            "Add a CONTRIBUTING.md file explaining how to contribute to the project",
+            "contributing",
+        )
+        .template("CONTRIBUTING.md"),
+        governance::ID => Remediation::new(
+            "Add a GOVERNANCE.md file describing how the project is governed",
+            "governance",
+        )
+        .template("GOVERNANCE.md"),
+        maintainers::ID => Remediation::new(
+            "Add a MAINTAINERS.md file listing the project maintainers",
+            "maintainers",
+        )
+        .template("MAINTAINERS.md"),
+        readme::ID => Remediation::new("Add a README.md file introducing the project", "readme")
+            .template("README.md"),
+        roadmap::ID => Remediation::new(
+            "Add a ROADMAP.md file describing the features planned for the project",
+            "roadmap",
+        )
+        .template("ROADMAP.md"),
+        summary_table::ID => Remediation::new(
+            "Fill in the project's summary table in the landscape",
+            "summary-table",
+        ),
+        website::ID => Remediation::new(
+            "Set the project's website url in the repository settings",
+            "website",
+        ),
+
+        // License
+        license_approved::ID => Remediation::new(
+            "Use a license approved by the CNCF (i.e. Apache-2.0)",
+            "approved-license",
+        ),
+        license_scanning::ID => Remediation::new(
+            "Add a license scanning badge (i.e. FOSSA or Snyk) to the README file",
+            "license-scanning",
+        ),
+        license_spdx_id::ID => Remediation::new(
+            "Add a LICENSE file with the text of the project's license",
+            "spdx-id",
+        ),
+
+        // Best practices
+        artifacthub_badge::ID => Remediation::new(
+            "Add an Artifact Hub badge to the README file",
+            "artifact-hub-badge",
+        ),
+        cla::ID => Remediation::new(
+            "Require contributors to sign a CLA using a CLA check",
+            "contributor-license-agreement",
+        ),
+        community_meeting::ID => Remediation::new(
+            "Reference the community meetings in the README file",
+            "community-meeting",
+        ),
+        contributor_diversity::ID => Remediation::new(
+            "Grow the maintainers and contributors base beyond a single person or organization",
+            "contributor-diversity",
+        ),
+        dco::ID => Remediation::new(
+            "Require commits to be signed off using a DCO check",
+            "developer-certificate-of-origin",
+        ),
+        github_discussions::ID => Remediation::new(
+            "Enable GitHub discussions in the repository settings",
+            "github-discussions",
+        ),
+        openssf_badge::ID => Remediation::new(
+            "Get an OpenSSF best practices badge and add it to the README file",
+            "openssf-badge",
+        ),
+        openssf_scorecard_badge::ID => Remediation::new(
+            "Add an OpenSSF Scorecard badge to the README file",
+            "openssf-scorecard-badge",
+        ),
+        recent_release::ID => {
+            Remediation::new("Publish a new release of the project", "recent-release")
         }
+        release_cadence::ID => Remediation::new(
+            "Publish releases regularly using SemVer compliant tags",
+            "release-cadence",
+        ),
+        responsiveness::ID => Remediation::new(
+            "Respond to new issues and pull requests promptly and triage the stale ones",
+            "responsiveness",
+        ),
+        slack_presence::ID => Remediation::new(
+            "Reference the project's Slack channel in the README file",
+            "slack-presence",
+        ),
+
+        // Security
+        binary_artifacts::ID => Remediation::new(
+            "Remove the binary artifacts checked into the repository",
+            "binary-artifacts-from-openssf-scorecard",
+        ),
+        branch_protection::ID => Remediation::new(
+            "Protect the default branch requiring reviews and status checks to pass before merging",
+            "branch-protection",
+        ),
+        code_review::ID => Remediation::new(
+            "Require changes to be reviewed before they are merged",
+            "code-review-from-openssf-scorecard",
+        ),
+        dangerous_workflow::ID => Remediation::new(
+            "Avoid dangerous coding patterns in the GitHub Actions workflows",
+            "dangerous-workflow-from-openssf-scorecard",
+        ),
+        dependencies_policy::ID => Remediation::new(
+            "Describe how dependencies are consumed and updated in the security insights manifest",
+            "dependencies-policy",
+        ),
+        dependency_update_tool::ID => Remediation::new(
+            "Set up a dependency update tool like Dependabot",
+            "dependency-update-tool-from-openssf-scorecard",
+        )
+        .template(".github/dependabot.yml"),
+        maintained::ID => Remediation::new(
+            "Keep the project active, responding to issues and merging changes regularly",
+            "maintained-from-openssf-scorecard",
+        ),
+        sbom::ID => Remediation::new(
+            "Publish an SBOM with the project releases and mention it in the README file",
+            "software-bill-of-materials-sbom",
+        ),
+        security_insights::ID => Remediation::new(
+            "Add an OpenSSF Security Insights manifest file (SECURITY-INSIGHTS.yml)",
+            "security-insights",
+        ),
+        security_policy::ID => Remediation::new(
+            "Add a SECURITY.md file with a contact address to report vulnerabilities",
+            "security-policy",
+        )
+        .template("SECURITY.md"),
+        signed_releases::ID => Remediation::new(
+            "Sign the release artifacts (i.e. using Sigstore)",
+            "signed-releases-from-openssf-scorecard",
+        ),
+        token_permissions::ID => Remediation::new(
+            "Set read-only permissions by default for the GitHub Actions workflows tokens",
+            "token-permissions-from-openssf-scorecard",
+        ),
+
+        // Legal
+        trademark_disclaimer::ID => Remediation::new(
+            "Add the Linux Foundation trademark disclaimer to the project's website footer",
+            "trademark-disclaimer",
+        ),
 
-        register_check!(adopters);
-        register_check!(artifacthub_badge);
-        register_check!(binary_artifacts, "Binary-Artifacts");
-        register_check!(changelog);
-        register_check!(cla);
-        register_check!(code_of_conduct);
-        register_check!(code_review, "Code-Review");
-        register_check!(community_meeting);
-        register_check!(contributing);
-        register_check!(dangerous_workflow, "Dangerous-Workflow");
-        register_check!(dco);
-        register_check!(dependencies_policy);
-        register_check!(dependency_update_tool, "Dependency-Update-Tool");
-        register_check!(github_discussions);
-        register_check!(governance);
-        register_check!(license_approved);
-        register_check!(license_scanning);
-        register_check!(license_spdx_id);
-        register_check!(maintained, "Maintained");
-        register_check!(maintainers);
-        register_check!(openssf_badge);
-        register_check!(openssf_scorecard_badge);
-        register_check!(readme);
-        register_check!(recent_release);
-        register_check!(roadmap);
-        register_check!(sbom);
-        register_check!(security_insights);
-        register_check!(security_policy);
-        register_check!(signed_releases, "Signed-Releases");
-        register_check!(slack_presence);
-        register_check!(summary_table);
-        register_check!(token_permissions, "Token-Permissions");
-        register_check!(trademark_disclaimer);
-        register_check!(website);
-
-        checks
+        _ => return None,
     };
+    Some(remediation)
 }
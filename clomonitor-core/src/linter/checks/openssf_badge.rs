@@ -3,9 +3,12 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
     CheckSet,
 };
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "openssf_badge";
@@ -16,6 +19,10 @@ pub(crate) const WEIGHT: usize = 5;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Timeout used for the requests to the OpenSSF Best Practices site (in
+/// seconds).
+const REQUEST_TIMEOUT: u64 = 10;
+
 lazy_static! {
     #[rustfmt::skip]
     static ref OPENSSF_URL: Regex = Regex::new(
@@ -28,19 +35,76 @@ lazy_static! {
     ).expect("exprs in OPENSSF_URL_LEGACY to be valid");
 }
 
+/// OpenSSF Best Practices project details.
+#[derive(Debug, Clone, Deserialize)]
+struct Project {
+    badge_level: String,
+}
+
 /// Check main function.
-pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
     // Reference in README file
-    if let Some(url) = readme_capture(&input.li.root, &[&OPENSSF_URL, &OPENSSF_URL_LEGACY])? {
-        return Ok(CheckOutput::passed().url(Some(url)));
+    if let Some((url, evidence)) =
+        readme_capture(&input.li.root, &[&OPENSSF_URL, &OPENSSF_URL_LEGACY])?
+    {
+        let mut output = CheckOutput::passed()
+            .url(Some(url.clone()))
+            .evidence(Some(evidence));
+
+        // Grade the output based on the badge level achieved by the project.
+        // When it cannot be fetched the full weight is awarded, as before.
+        if !input.li.offline {
+            if let Ok(badge_level) = badge_level(&url).await {
+                output = grade(output, &badge_level);
+            }
+        }
+
+        return Ok(output);
     }
 
     Ok(CheckOutput::not_passed())
 }
 
+/// Get the badge level achieved by the OpenSSF Best Practices project
+/// located at the url provided.
+async fn badge_level(project_url: &str) -> Result<String> {
+    let resp = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT))
+        .build()?
+        .get(format!("{project_url}.json"))
+        .send()
+        .await?;
+    if resp.status() != StatusCode::OK {
+        return Err(format_err!(
+            "unexpected status code getting openssf project: {}",
+            resp.status()
+        ));
+    }
+    let project: Project = resp.json().await?;
+    Ok(project.badge_level)
+}
+
+/// Grade the output provided based on the badge level given. Projects that
+/// have achieved the passing badge (or higher) get the full weight, whereas
+/// those still in progress only get partial credit.
+fn grade(output: CheckOutput, badge_level: &str) -> CheckOutput {
+    let output = match badge_level {
+        "gold" | "silver" | "passing" => output,
+        "in_progress" => output.score(Some(0.5)).warning(true),
+        _ => return output,
+    };
+    output.details(Some(format!(
+        "OpenSSF Best Practices badge level: {badge_level}"
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     #[test]
     fn openssf_url_extract() {
@@ -57,4 +121,77 @@ mod tests {
             "https://bestpractices.coreinfrastructure.org/projects/4106"
         );
     }
+
+    #[tokio::test]
+    async fn badge_level_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/projects/4106.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"id":4106,"badge_level":"silver"}"#),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            badge_level(&format!("{}/projects/4106", mock_server.uri()))
+                .await
+                .unwrap(),
+            "silver"
+        );
+    }
+
+    #[tokio::test]
+    async fn badge_level_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        assert!(badge_level(&format!("{}/projects/4106", mock_server.uri()))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn grade_gold() {
+        assert_eq!(
+            grade(CheckOutput::passed(), "gold"),
+            CheckOutput::passed()
+                .details(Some("OpenSSF Best Practices badge level: gold".to_string()))
+        );
+    }
+
+    #[test]
+    fn grade_passing() {
+        assert_eq!(
+            grade(CheckOutput::passed(), "passing"),
+            CheckOutput::passed().details(Some(
+                "OpenSSF Best Practices badge level: passing".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn grade_in_progress() {
+        assert_eq!(
+            grade(CheckOutput::passed(), "in_progress"),
+            CheckOutput::passed()
+                .score(Some(0.5))
+                .warning(true)
+                .details(Some(
+                    "OpenSSF Best Practices badge level: in_progress".to_string()
+                ))
+        );
+    }
+
+    #[test]
+    fn grade_unknown_level() {
+        assert_eq!(
+            grade(CheckOutput::passed(), "unknown"),
+            CheckOutput::passed()
+        );
+    }
 }
@@ -26,8 +26,10 @@ lazy_static! {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    if let Some(url) = readme_capture(&input.li.root, &[&OPENSSF_SCORECARD_URL])? {
-        return Ok(CheckOutput::passed().url(Some(url)));
+    if let Some((url, evidence)) = readme_capture(&input.li.root, &[&OPENSSF_SCORECARD_URL])? {
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(Some(evidence)));
     }
 
     Ok(CheckOutput::not_passed())
@@ -1,8 +1,5 @@
 /// Patterns used to locate a file in the repository.
-use super::{
-    datasource::github,
-    util::{helpers::readme_globs, path},
-};
+use super::util::{helpers::readme_globs, path};
 use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
     CheckSet,
@@ -30,12 +27,7 @@ pub(crate) static FILE_PATTERNS: [&str; 3] = ["README*", ".github/README*", "doc
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo
     if let Some(path) = path::find(&readme_globs(&input.li.root))? {
-        let url = github::build_url(
-            &path,
-            &input.gh_md.owner.login,
-            &input.gh_md.name,
-            &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
-        );
+        let url = input.file_url(&path);
         return Ok(CheckOutput::passed().url(Some(url)));
     }
 
@@ -15,15 +15,31 @@ pub(crate) const WEIGHT: usize = 3;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Score awarded to releases older than one year but not older than two.
+const STALE_RELEASE_SCORE: f64 = 0.5;
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
-    // Recent release (< 1 year old) in GitHub
     if let Some(latest_release) = github::latest_release(&input.gh_md) {
         let created_at = OffsetDateTime::parse(&latest_release.created_at, &Rfc3339)?;
-        let one_year_ago = (OffsetDateTime::now_utc() - Duration::days(365)).unix_timestamp();
-        if created_at.unix_timestamp() > one_year_ago {
+        let age = OffsetDateTime::now_utc() - created_at;
+
+        // Recent release (< 1 year old) in GitHub
+        if age < Duration::days(365) {
             return Ok(CheckOutput::passed().url(Some(latest_release.url.clone())));
         }
+
+        // Stale release (< 2 years old) in GitHub, partial credit awarded
+        if age < Duration::days(365 * 2) {
+            return Ok(CheckOutput::passed()
+                .url(Some(latest_release.url.clone()))
+                .score(Some(STALE_RELEASE_SCORE))
+                .warning(true)
+                .details(Some(format!(
+                    "Latest release is {} months old",
+                    age.whole_days() / 30
+                ))));
+        }
     }
 
     Ok(CheckOutput::not_passed())
@@ -32,6 +48,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::datasource::forge::Forge;
     use crate::linter::{
         datasource::github::md::{
             MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
@@ -46,12 +63,14 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -60,21 +79,23 @@ mod tests {
 
     #[test]
     fn not_passed_no_recent_release_found() {
-        let two_years_ago = (OffsetDateTime::now_utc() - Duration::days(365 * 2))
+        let three_years_ago = (OffsetDateTime::now_utc() - Duration::days(365 * 3))
             .format(&Rfc3339)
             .unwrap();
 
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     releases: MdRepositoryReleases {
                         nodes: Some(vec![Some(MdRepositoryReleasesNodes {
-                            created_at: two_years_ago,
+                            created_at: three_years_ago,
                             description: None,
                             is_prerelease: false,
                             release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
@@ -82,6 +103,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -97,6 +119,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     releases: MdRepositoryReleases {
@@ -105,6 +128,7 @@ mod tests {
                             description: None,
                             is_prerelease: false,
                             release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
@@ -112,9 +136,47 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::passed().url(Some("release_url".to_string())),
         );
     }
+
+    #[test]
+    fn passed_with_warning_stale_release_found() {
+        let thirteen_months_ago = (OffsetDateTime::now_utc() - Duration::days(395))
+            .format(&Rfc3339)
+            .unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                forge: Forge::GitHub,
+                cm_md: None,
+                gh_md: MdRepository {
+                    releases: MdRepositoryReleases {
+                        nodes: Some(vec![Some(MdRepositoryReleasesNodes {
+                            created_at: thirteen_months_ago,
+                            description: None,
+                            is_prerelease: false,
+                            release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                            tag_name: "v1.0.0".to_string(),
+                            url: "release_url".to_string(),
+                        })]),
+                    },
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                license: None,
+            })
+            .unwrap(),
+            CheckOutput::passed()
+                .url(Some("release_url".to_string()))
+                .score(Some(STALE_RELEASE_SCORE))
+                .warning(true)
+                .details(Some("Latest release is 13 months old".to_string())),
+        );
+    }
 }
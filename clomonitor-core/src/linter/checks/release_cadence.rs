@@ -0,0 +1,259 @@
+use super::util::helpers::median;
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput},
+    CheckSet,
+};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "release_cadence";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
+
+/// Default maximum interval between releases (in days, ~6 months).
+const DEFAULT_MAX_INTERVAL_DAYS: i64 = 180;
+
+/// Thresholds used to decide whether a project releases regularly or not.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReleaseCadenceThresholds {
+    /// Maximum interval between releases (in days). It applies to the median
+    /// interval between the latest releases as well as to the time elapsed
+    /// since the latest one.
+    pub max_interval_days: i64,
+}
+
+impl Default for ReleaseCadenceThresholds {
+    fn default() -> Self {
+        Self {
+            max_interval_days: DEFAULT_MAX_INTERVAL_DAYS,
+        }
+    }
+}
+
+lazy_static! {
+    #[rustfmt::skip]
+    static ref SEMVER_TAG: Regex = Regex::new(
+        r"^(?:[\w.-]+/)?v?(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?(?:\+[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?$"
+    ).expect("exprs in SEMVER_TAG to be valid");
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
+    let releases: Vec<_> = input
+        .gh_md
+        .releases
+        .nodes
+        .as_ref()
+        .map(|nodes| nodes.iter().flatten().collect())
+        .unwrap_or_default();
+
+    // At least two releases are needed to compute the cadence
+    if releases.len() < 2 {
+        return Ok(CheckOutput::not_passed().details(Some(format!(
+            "At least 2 releases are needed to analyse the release cadence (found {})",
+            releases.len()
+        ))));
+    }
+
+    // Median interval between consecutive releases
+    let mut dates = releases
+        .iter()
+        .map(|r| OffsetDateTime::parse(&r.created_at, &Rfc3339))
+        .collect::<Result<Vec<_>, _>>()?;
+    dates.sort_unstable();
+    let mut intervals: Vec<i64> = dates
+        .windows(2)
+        .map(|w| (w[1] - w[0]).whole_days())
+        .collect();
+    intervals.sort_unstable();
+    let median_interval = median(&intervals);
+
+    // Time elapsed since the latest release
+    let latest = dates.last().expect("at least two releases");
+    let latest_days_ago = (OffsetDateTime::now_utc() - *latest).whole_days();
+
+    // Pre-releases and tags following SemVer
+    let total = releases.len();
+    let prereleases = releases.iter().filter(|r| r.is_prerelease).count();
+    let semver_tags = releases
+        .iter()
+        .filter(|r| SEMVER_TAG.is_match(&r.tag_name))
+        .count();
+
+    let details = format!(
+        "Median interval between releases: {median_interval} days. Latest release: {latest_days_ago} days ago. Pre-releases: {}% ({prereleases} of {total}). SemVer tags: {}% ({semver_tags} of {total})",
+        prereleases * 100 / total,
+        semver_tags * 100 / total,
+    );
+    let max_interval = input.li.release_cadence_thresholds.max_interval_days;
+    if median_interval <= max_interval && latest_days_ago <= max_interval && semver_tags == total {
+        return Ok(CheckOutput::passed().details(Some(details)));
+    }
+    Ok(CheckOutput::not_passed().details(Some(details)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        datasource::{
+            forge::Forge,
+            github::md::{
+                MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
+                MdRepositoryReleasesNodesReleaseAssets,
+            },
+        },
+        LinterInput,
+    };
+    use anyhow::format_err;
+    use time::Duration;
+
+    lazy_static! {
+        static ref NOW: OffsetDateTime = OffsetDateTime::now_utc();
+    }
+
+    fn days_ago(days: i64) -> String {
+        (*NOW - Duration::days(days)).format(&Rfc3339).unwrap()
+    }
+
+    fn release(created_at: &str, tag_name: &str, is_prerelease: bool) -> MdRepositoryReleasesNodes {
+        MdRepositoryReleasesNodes {
+            created_at: created_at.to_string(),
+            description: None,
+            is_prerelease,
+            release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+            tag_name: tag_name.to_string(),
+            url: "release_url".to_string(),
+        }
+    }
+
+    fn check_with(releases: Vec<MdRepositoryReleasesNodes>) -> CheckOutput {
+        check_with_thresholds(releases, ReleaseCadenceThresholds::default())
+    }
+
+    fn check_with_thresholds(
+        releases: Vec<MdRepositoryReleasesNodes>,
+        thresholds: ReleaseCadenceThresholds,
+    ) -> CheckOutput {
+        check(&CheckInput {
+            li: &LinterInput {
+                release_cadence_thresholds: thresholds,
+                ..LinterInput::default()
+            },
+            forge: Forge::GitHub,
+            cm_md: None,
+            gh_md: MdRepository {
+                releases: MdRepositoryReleases {
+                    nodes: Some(releases.into_iter().map(Some).collect()),
+                },
+                ..MdRepository::default()
+            },
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn not_passed_not_enough_releases() {
+        assert_eq!(
+            check_with(vec![release("2022-06-01T00:00:00Z", "v1.0.0", false)]),
+            CheckOutput::not_passed().details(Some(
+                "At least 2 releases are needed to analyse the release cadence (found 1)"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_infrequent_releases() {
+        assert_eq!(
+            check_with(vec![
+                release(&days_ago(30), "v1.2.0", false),
+                release(&days_ago(395), "v1.1.0", false),
+                release(&days_ago(760), "v1.0.0", false),
+            ]),
+            CheckOutput::not_passed().details(Some(
+                "Median interval between releases: 365 days. Latest release: 30 days ago. Pre-releases: 0% (0 of 3). SemVer tags: 100% (3 of 3)"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_no_recent_releases() {
+        assert_eq!(
+            check_with(vec![
+                release(&days_ago(400), "v1.2.0", false),
+                release(&days_ago(430), "v1.1.0", false),
+                release(&days_ago(460), "v1.0.0", false),
+            ]),
+            CheckOutput::not_passed().details(Some(
+                "Median interval between releases: 30 days. Latest release: 400 days ago. Pre-releases: 0% (0 of 3). SemVer tags: 100% (3 of 3)"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_tags_not_following_semver() {
+        assert_eq!(
+            check_with(vec![
+                release(&days_ago(10), "release-2022-03", false),
+                release(&days_ago(40), "v1.1.0", false),
+                release(&days_ago(70), "v1.0.0", false),
+            ]),
+            CheckOutput::not_passed().details(Some(
+                "Median interval between releases: 30 days. Latest release: 10 days ago. Pre-releases: 0% (0 of 3). SemVer tags: 66% (2 of 3)"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn passed_regular_semver_releases() {
+        assert_eq!(
+            check_with(vec![
+                release(&days_ago(10), "component/v1.2.0", false),
+                release(&days_ago(40), "v1.2.0-rc.1", true),
+                release(&days_ago(100), "1.1.0", false),
+                release(&days_ago(130), "v1.0.0", false),
+            ]),
+            CheckOutput::passed().details(Some(
+                "Median interval between releases: 30 days. Latest release: 10 days ago. Pre-releases: 25% (1 of 4). SemVer tags: 100% (4 of 4)"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn passed_custom_max_interval() {
+        let releases = || {
+            vec![
+                release(&days_ago(200), "v1.1.0", false),
+                release(&days_ago(400), "v1.0.0", false),
+            ]
+        };
+
+        assert!(!check_with(releases()).passed);
+        assert!(
+            check_with_thresholds(
+                releases(),
+                ReleaseCadenceThresholds {
+                    max_interval_days: 365
+                }
+            )
+            .passed
+        );
+    }
+}
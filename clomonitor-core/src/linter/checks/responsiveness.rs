@@ -0,0 +1,434 @@
+use super::{
+    datasource::github::md::{CommentAuthorAssociation, MdRepository},
+    util::helpers::median,
+};
+use crate::linter::{
+    check::{CheckId, CheckInput, CheckOutput},
+    datasource::forge::Forge,
+    CheckSet,
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "responsiveness";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 2;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
+
+/// Number of days considered when looking for recent issues and pull requests.
+const WINDOW_DAYS: i64 = 90;
+
+/// Number of days without activity after which an open item is stale.
+const STALE_DAYS: i64 = 30;
+
+/// Default maximum median time to the first maintainer response (one week).
+const DEFAULT_MAX_MEDIAN_RESPONSE_HOURS: i64 = 168;
+
+/// Default maximum ratio of stale open items.
+const DEFAULT_MAX_STALE_RATIO: f64 = 0.25;
+
+/// Thresholds used to decide whether maintainers are responsive or not.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResponsivenessThresholds {
+    /// Maximum median time to the first maintainer response (in hours).
+    pub max_median_response_hours: i64,
+
+    /// Maximum ratio (from 0 to 1) of stale open items.
+    pub max_stale_ratio: f64,
+}
+
+impl Default for ResponsivenessThresholds {
+    fn default() -> Self {
+        Self {
+            max_median_response_hours: DEFAULT_MAX_MEDIAN_RESPONSE_HOURS,
+            max_stale_ratio: DEFAULT_MAX_STALE_RATIO,
+        }
+    }
+}
+
+/// Responsiveness metrics, computed from the issues and pull requests opened
+/// by contributors (non maintainers) in the last 90 days.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResponsivenessMetrics {
+    /// Number of issues and pull requests analysed.
+    pub items: usize,
+
+    /// Median time to the first maintainer response (in hours). Items still
+    /// waiting for a response count as the time elapsed since they were
+    /// opened.
+    pub median_response_hours: i64,
+
+    /// Number of open items without activity in the last 30 days.
+    pub stale_items: usize,
+
+    /// Ratio (from 0 to 1) of stale open items.
+    pub stale_ratio: f64,
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<ResponsivenessMetrics>> {
+    // Issues and pull requests are only available for GitHub repositories
+    if input.forge != Forge::GitHub {
+        return Ok(CheckOutput::not_evaluated().details(Some(
+            "Responsiveness is only checked in GitHub repositories".to_string(),
+        )));
+    }
+
+    // Compute metrics from the items opened by contributors in the window
+    let now = OffsetDateTime::now_utc();
+    let mut response_times = Vec::new();
+    let (mut total, mut stale) = (0, 0);
+    for item in items(&input.gh_md) {
+        let created_at = OffsetDateTime::parse(item.created_at, &Rfc3339)?;
+        if now - created_at > Duration::days(WINDOW_DAYS) {
+            continue;
+        }
+        total += 1;
+
+        // Time to first maintainer response
+        let mut first_response = None;
+        for response in item.responses {
+            let responded_at = OffsetDateTime::parse(response, &Rfc3339)?;
+            if first_response.map_or(true, |first| responded_at < first) {
+                first_response = Some(responded_at);
+            }
+        }
+        // (items without a response count as the time elapsed so far)
+        let responded_at = first_response.unwrap_or(now);
+        response_times.push((responded_at - created_at).whole_hours().max(0));
+
+        // Stale open item
+        let updated_at = OffsetDateTime::parse(item.updated_at, &Rfc3339)?;
+        if !item.closed && now - updated_at > Duration::days(STALE_DAYS) {
+            stale += 1;
+        }
+    }
+    if total == 0 {
+        return Ok(CheckOutput::not_evaluated().details(Some(format!(
+            "No issues or pull requests opened by contributors found in the last {WINDOW_DAYS} days"
+        ))));
+    }
+    response_times.sort_unstable();
+    #[allow(clippy::cast_precision_loss)]
+    let metrics = ResponsivenessMetrics {
+        items: total,
+        median_response_hours: median(&response_times),
+        stale_items: stale,
+        stale_ratio: stale as f64 / total as f64,
+    };
+
+    // Check metrics against the thresholds
+    let details = format!(
+        "Median time to first maintainer response: {} hours. Stale open items: {}% ({stale} of {total})",
+        metrics.median_response_hours,
+        stale * 100 / total,
+    );
+    let thresholds = &input.li.responsiveness_thresholds;
+    let output = if metrics.median_response_hours <= thresholds.max_median_response_hours
+        && metrics.stale_ratio <= thresholds.max_stale_ratio
+    {
+        CheckOutput::passed()
+    } else {
+        CheckOutput::not_passed()
+    };
+    Ok(output.value(Some(metrics)).details(Some(details)))
+}
+
+/// Issue or pull request opened by a contributor.
+struct Item<'a> {
+    closed: bool,
+    created_at: &'a str,
+    updated_at: &'a str,
+
+    /// Creation date of the comments and reviews submitted by maintainers.
+    responses: Vec<&'a str>,
+}
+
+/// Get the issues and pull requests opened by contributors (non maintainers)
+/// from the metadata provided.
+fn items(gh_md: &MdRepository) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+
+    for issue in gh_md.issues.nodes.iter().flatten().flatten() {
+        if is_maintainer(&issue.author_association) {
+            continue;
+        }
+        items.push(Item {
+            closed: issue.closed,
+            created_at: &issue.created_at,
+            updated_at: &issue.updated_at,
+            responses: issue
+                .comments
+                .nodes
+                .iter()
+                .flatten()
+                .flatten()
+                .filter(|c| is_maintainer(&c.author_association))
+                .map(|c| c.created_at.as_str())
+                .collect(),
+        });
+    }
+
+    for pr in gh_md.recent_pull_requests.nodes.iter().flatten().flatten() {
+        if is_maintainer(&pr.author_association) {
+            continue;
+        }
+        let comments = pr
+            .comments
+            .nodes
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|c| is_maintainer(&c.author_association))
+            .map(|c| c.created_at.as_str());
+        let reviews = pr
+            .reviews
+            .iter()
+            .flat_map(|r| r.nodes.iter().flatten().flatten())
+            .filter(|r| is_maintainer(&r.author_association))
+            .map(|r| r.created_at.as_str());
+        items.push(Item {
+            closed: pr.closed,
+            created_at: &pr.created_at,
+            updated_at: &pr.updated_at,
+            responses: comments.chain(reviews).collect(),
+        });
+    }
+
+    items
+}
+
+/// Check if the author association provided corresponds to a maintainer.
+fn is_maintainer(author_association: &CommentAuthorAssociation) -> bool {
+    matches!(
+        author_association,
+        CommentAuthorAssociation::OWNER
+            | CommentAuthorAssociation::MEMBER
+            | CommentAuthorAssociation::COLLABORATOR
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        datasource::github::md::{
+            MdRepositoryIssues, MdRepositoryIssuesNodes, MdRepositoryIssuesNodesComments,
+            MdRepositoryIssuesNodesCommentsNodes, MdRepositoryRecentPullRequests,
+            MdRepositoryRecentPullRequestsNodes, MdRepositoryRecentPullRequestsNodesComments,
+            MdRepositoryRecentPullRequestsNodesReviews,
+            MdRepositoryRecentPullRequestsNodesReviewsNodes,
+        },
+        LinterInput,
+    };
+    use anyhow::format_err;
+
+    fn hours_ago(hours: i64) -> String {
+        (OffsetDateTime::now_utc() - Duration::hours(hours))
+            .format(&Rfc3339)
+            .unwrap()
+    }
+
+    fn issue(
+        author_association: CommentAuthorAssociation,
+        created_hours_ago: i64,
+        updated_hours_ago: i64,
+        responses_hours_ago: &[i64],
+    ) -> MdRepositoryIssuesNodes {
+        let created_at = hours_ago(created_hours_ago);
+        MdRepositoryIssuesNodes {
+            author_association,
+            closed: false,
+            comments: MdRepositoryIssuesNodesComments {
+                nodes: Some(
+                    responses_hours_ago
+                        .iter()
+                        .map(|hours| {
+                            Some(MdRepositoryIssuesNodesCommentsNodes {
+                                author_association: CommentAuthorAssociation::MEMBER,
+                                created_at: hours_ago(*hours),
+                            })
+                        })
+                        .collect(),
+                ),
+            },
+            created_at,
+            updated_at: hours_ago(updated_hours_ago),
+        }
+    }
+
+    fn check_with(
+        li: &LinterInput,
+        forge: Forge,
+        issues: Vec<MdRepositoryIssuesNodes>,
+        pull_requests: Vec<MdRepositoryRecentPullRequestsNodes>,
+    ) -> CheckOutput<ResponsivenessMetrics> {
+        check(&CheckInput {
+            li,
+            forge,
+            cm_md: None,
+            gh_md: MdRepository {
+                issues: MdRepositoryIssues {
+                    nodes: Some(issues.into_iter().map(Some).collect()),
+                },
+                recent_pull_requests: MdRepositoryRecentPullRequests {
+                    nodes: Some(pull_requests.into_iter().map(Some).collect()),
+                },
+                ..MdRepository::default()
+            },
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            license: None,
+        })
+        .unwrap()
+    }
+
+    fn responsive_project_items() -> (
+        Vec<MdRepositoryIssuesNodes>,
+        Vec<MdRepositoryRecentPullRequestsNodes>,
+    ) {
+        let issues = vec![
+            issue(CommentAuthorAssociation::NONE, 100, 1, &[98, 50]),
+            issue(CommentAuthorAssociation::CONTRIBUTOR, 200, 1, &[190]),
+            issue(CommentAuthorAssociation::OWNER, 300, 300, &[]),
+        ];
+        let pull_requests = vec![MdRepositoryRecentPullRequestsNodes {
+            author_association: CommentAuthorAssociation::FIRST_TIME_CONTRIBUTOR,
+            closed: true,
+            comments: MdRepositoryRecentPullRequestsNodesComments { nodes: None },
+            created_at: hours_ago(400),
+            reviews: Some(MdRepositoryRecentPullRequestsNodesReviews {
+                nodes: Some(vec![Some(
+                    MdRepositoryRecentPullRequestsNodesReviewsNodes {
+                        author_association: CommentAuthorAssociation::COLLABORATOR,
+                        created_at: hours_ago(380),
+                    },
+                )]),
+            }),
+            updated_at: hours_ago(380),
+        }];
+        (issues, pull_requests)
+    }
+
+    #[test]
+    fn not_evaluated_forge_not_github() {
+        assert_eq!(
+            check_with(&LinterInput::default(), Forge::GitLab, vec![], vec![]),
+            CheckOutput::not_evaluated().details(Some(
+                "Responsiveness is only checked in GitHub repositories".to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_evaluated_no_recent_items_opened_by_contributors() {
+        assert_eq!(
+            check_with(
+                &LinterInput::default(),
+                Forge::GitHub,
+                vec![
+                    issue(CommentAuthorAssociation::NONE, 24 * 100, 24 * 100, &[]),
+                    issue(CommentAuthorAssociation::MEMBER, 10, 10, &[]),
+                ],
+                vec![]
+            ),
+            CheckOutput::not_evaluated().details(Some(
+                "No issues or pull requests opened by contributors found in the last 90 days"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn not_passed_slow_responses_and_stale_items() {
+        assert_eq!(
+            check_with(
+                &LinterInput::default(),
+                Forge::GitHub,
+                vec![
+                    issue(CommentAuthorAssociation::NONE, 24 * 60, 24 * 40, &[]),
+                    issue(CommentAuthorAssociation::NONE, 24 * 20, 1, &[24 * 20 - 300]),
+                ],
+                vec![]
+            ),
+            CheckOutput::not_passed()
+                .value(Some(ResponsivenessMetrics {
+                    items: 2,
+                    median_response_hours: 870,
+                    stale_items: 1,
+                    stale_ratio: 0.5,
+                }))
+                .details(Some(
+                    "Median time to first maintainer response: 870 hours. Stale open items: 50% (1 of 2)"
+                        .to_string()
+                )),
+        );
+    }
+
+    #[test]
+    fn not_passed_unanswered_items() {
+        assert_eq!(
+            check_with(
+                &LinterInput::default(),
+                Forge::GitHub,
+                vec![
+                    issue(CommentAuthorAssociation::NONE, 240, 1, &[]),
+                    issue(CommentAuthorAssociation::NONE, 200, 1, &[]),
+                    issue(CommentAuthorAssociation::NONE, 100, 1, &[98]),
+                ],
+                vec![]
+            ),
+            CheckOutput::not_passed()
+                .value(Some(ResponsivenessMetrics {
+                    items: 3,
+                    median_response_hours: 200,
+                    stale_items: 0,
+                    stale_ratio: 0.0,
+                }))
+                .details(Some(
+                    "Median time to first maintainer response: 200 hours. Stale open items: 0% (0 of 3)"
+                        .to_string()
+                )),
+        );
+    }
+
+    #[test]
+    fn not_passed_custom_thresholds() {
+        let (issues, pull_requests) = responsive_project_items();
+        let li = LinterInput {
+            responsiveness_thresholds: ResponsivenessThresholds {
+                max_median_response_hours: 5,
+                ..ResponsivenessThresholds::default()
+            },
+            ..LinterInput::default()
+        };
+
+        assert!(!check_with(&li, Forge::GitHub, issues, pull_requests).passed);
+    }
+
+    #[test]
+    fn passed_responsive_maintainers() {
+        let (issues, pull_requests) = responsive_project_items();
+
+        assert_eq!(
+            check_with(&LinterInput::default(), Forge::GitHub, issues, pull_requests),
+            CheckOutput::passed()
+                .value(Some(ResponsivenessMetrics {
+                    items: 3,
+                    median_response_hours: 10,
+                    stale_items: 0,
+                    stale_ratio: 0.0,
+                }))
+                .details(Some(
+                    "Median time to first maintainer response: 10 hours. Stale open items: 0% (0 of 3)"
+                        .to_string()
+                )),
+        );
+    }
+}
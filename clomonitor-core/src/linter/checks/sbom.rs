@@ -47,8 +47,8 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     }
 
     // Reference in README file
-    if readme_matches(&input.li.root, &README_REF)? {
-        return Ok(CheckOutput::passed());
+    if let Some(evidence) = readme_matches(&input.li.root, &README_REF)? {
+        return Ok(CheckOutput::passed().evidence(Some(evidence)));
     }
 
     Ok(CheckOutput::not_passed())
@@ -57,6 +57,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::datasource::forge::Forge;
     use crate::linter::{
         datasource::github::md::{
             MdRepository, MdRepositoryReleases, MdRepositoryReleasesNodes,
@@ -71,12 +72,14 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     ..MdRepository::default()
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -88,6 +91,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     releases: MdRepositoryReleases {
@@ -102,6 +106,7 @@ mod tests {
                                     }
                                 )])
                             },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
@@ -109,6 +114,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -120,6 +126,7 @@ mod tests {
         assert_eq!(
             check(&CheckInput {
                 li: &LinterInput::default(),
+                forge: Forge::GitHub,
                 cm_md: None,
                 gh_md: MdRepository {
                     releases: MdRepositoryReleases {
@@ -134,6 +141,7 @@ mod tests {
                                     }
                                 )])
                             },
+                            tag_name: "v1.0.0".to_string(),
                             url: "release_url".to_string(),
                         })]),
                     },
@@ -141,6 +149,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                license: None,
             })
             .unwrap(),
             CheckOutput::passed(),
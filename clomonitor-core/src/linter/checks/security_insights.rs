@@ -1,4 +1,4 @@
-use super::datasource::{github, security_insights::SECURITY_INSIGHTS_MANIFEST_FILE};
+use super::datasource::security_insights::SECURITY_INSIGHTS_MANIFEST_FILE;
 use crate::linter::{check::CheckInput, CheckId, CheckOutput, CheckSet};
 use anyhow::{format_err, Result};
 use std::path::Path;
@@ -21,12 +21,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
         .map_err(|e| format_err!("{e:?}"))?
     {
         Some(_) => {
-            let url = github::build_url(
-                Path::new(SECURITY_INSIGHTS_MANIFEST_FILE),
-                &input.gh_md.owner.login,
-                &input.gh_md.name,
-                &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
-            );
+            let url = input.file_url(Path::new(SECURITY_INSIGHTS_MANIFEST_FILE));
             CheckOutput::passed().url(Some(url))
         }
         None => CheckOutput::not_passed(),
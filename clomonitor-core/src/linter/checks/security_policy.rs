@@ -17,7 +17,7 @@ pub(crate) const WEIGHT: usize = 3;
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
 
 /// Patterns used to locate a file in the repository.
-static FILE_PATTERNS: [&str; 3] = ["security*", ".github/security*", "docs/security*"];
+pub(crate) static FILE_PATTERNS: [&str; 3] = ["security*", ".github/security*", "docs/security*"];
 
 lazy_static! {
     #[rustfmt::skip]
@@ -14,8 +14,11 @@ pub(crate) const WEIGHT: usize = 2;
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Default score the scorecard check must reach to pass.
+pub(crate) const PASS_THRESHOLD: f64 = 1.0;
+
 /// Check main function.
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
-    Ok(scorecard::get_check(&input.scorecard, ID).into())
+    Ok(scorecard::check_output(input, ID))
 }
@@ -1,18 +1,38 @@
-use super::path::{self, Globs};
+use super::{
+    markdown::Markdown,
+    path::{self, Globs},
+};
+use crate::linter::check::Evidence;
 use anyhow::Result;
 use regex::{Regex, RegexSet};
+use reqwest::{header::LOCATION, redirect, Url};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    time::Duration,
+};
+use tokio::net::lookup_host;
+
+/// Maximum number of redirections followed when checking public urls.
+const MAX_REDIRECTS: usize = 5;
+
+/// Timeout (in seconds) of each request sent when checking public urls.
+const REQUEST_TIMEOUT: u64 = 10;
 
 /// Check if the content of any of the files that match the globs provided
 /// matches any of the regular expressions given, returning the captured value
-/// when there is a match. This function expects that the regular expressions
-/// provided contain one capture group.
-pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<String>> {
+/// along with the evidence of the match when there is one. This function
+/// expects that the regular expressions provided contain one capture group.
+pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<(String, Evidence)>> {
     for path in &path::matches(globs)? {
         if let Ok(content) = super::fs::read_to_string(path) {
             for re in regexps {
                 if let Some(c) = re.captures(&content) {
                     if c.len() > 1 {
-                        return Ok(Some(c[1].to_string()));
+                        let offset = c.get(0).map_or(0, |m| m.start());
+                        let line = content[..offset].matches('\n').count() + 1;
+                        let evidence = evidence(globs.root, path, line, re.as_str())?;
+                        return Ok(Some((c[1].to_string(), evidence)));
                     }
                 }
             }
@@ -22,14 +42,44 @@ pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<String>>
 }
 
 /// Check if the content of any of the files that match the globs provided
-/// matches any of the regular expressions given.
-pub(crate) fn matches(globs: &Globs, re: &RegexSet) -> Result<bool> {
-    Ok(path::matches(globs)?.iter().any(|path| {
+/// matches any of the regular expressions given, returning the evidence of
+/// the first match found. Markdown files are parsed and the expressions are
+/// matched against their headings, links and paragraphs, so that content in
+/// code blocks, html comments or images alternative text is not considered.
+/// The content of any other file is matched as is.
+pub(crate) fn matches(globs: &Globs, re: &RegexSet) -> Result<Option<Evidence>> {
+    for path in &path::matches(globs)? {
         if let Ok(content) = super::fs::read_to_string(path) {
-            return re.is_match(&content);
+            if is_markdown(path) {
+                if let Some((line, pattern)) = Markdown::parse(&content).matches(re) {
+                    return Ok(Some(evidence(globs.root, path, line, &pattern)?));
+                }
+            } else if let Some(i) = re.matches(&content).iter().next() {
+                let pattern = &re.patterns()[i];
+                let offset = Regex::new(pattern)?.find(&content).map_or(0, |m| m.start());
+                let line = content[..offset].matches('\n').count() + 1;
+                return Ok(Some(evidence(globs.root, path, line, pattern)?));
+            }
         }
-        false
-    }))
+    }
+    Ok(None)
+}
+
+/// Check if the file located at the path provided is a markdown file.
+fn is_markdown(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| {
+        ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+    })
+}
+
+/// Build the evidence of a match found at the line provided of the file
+/// located at the given path.
+fn evidence(root: &Path, path: &Path, line: usize, pattern: &str) -> Result<Evidence> {
+    Ok(Evidence {
+        file: path::relative(root, path)?.to_string_lossy().into_owned(),
+        line: Some(line),
+        pattern: Some(pattern.to_string()),
+    })
 }
 
 /// Check if the content of the url provided matches any of the regular
@@ -39,6 +89,108 @@ pub(crate) async fn remote_matches(url: &str, re: &RegexSet) -> Result<bool> {
     Ok(re.is_match(&content))
 }
 
+/// Check if the url provided, which comes from untrusted input (i.e. the
+/// metadata file), resolves successfully. Only https urls pointing to public
+/// hosts are requested. Redirections are followed manually, validating each
+/// target the same way, and requests are sent to the addresses validated.
+pub(crate) async fn remote_exists(url: &str) -> Result<bool> {
+    let mut url = Url::parse(url)?;
+    for _ in 0..=MAX_REDIRECTS {
+        let Some(addrs) = public_addrs(&url).await else {
+            return Ok(false);
+        };
+        let mut builder = reqwest::Client::builder()
+            .redirect(redirect::Policy::none())
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT));
+        if let Some(domain) = url.domain() {
+            builder = builder.resolve_to_addrs(domain, &addrs);
+        }
+        let resp = builder.build()?.get(url.clone()).send().await?;
+        if !resp.status().is_redirection() {
+            return Ok(resp.status().is_success());
+        }
+        let Some(location) = resp.headers().get(LOCATION).and_then(|v| v.to_str().ok()) else {
+            return Ok(false);
+        };
+        url = url.join(location)?;
+    }
+    Ok(false)
+}
+
+/// Return the addresses the url provided points to when it uses https and
+/// points to a public host. Domains are resolved, and all the addresses they
+/// resolve to must be public. None is returned otherwise.
+async fn public_addrs(url: &Url) -> Option<Vec<SocketAddr>> {
+    if !is_public_url_literal(url) {
+        return None;
+    }
+    let port = url.port_or_known_default().unwrap_or(443);
+    let host = url.host_str()?;
+    let addrs: Vec<SocketAddr> = match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+        Ok(ip) => vec![SocketAddr::new(ip, port)],
+        Err(_) => lookup_host((host, port)).await.ok()?.collect(),
+    };
+    if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(addr.ip())) {
+        return None;
+    }
+    Some(addrs)
+}
+
+/// Check if the url provided uses https and doesn't point to a local or
+/// private host, without resolving domains.
+fn is_public_url_literal(url: &Url) -> bool {
+    if url.scheme() != "https" {
+        return false;
+    }
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_public_ip(ip);
+    }
+    let host = host.trim_end_matches('.').to_lowercase();
+    host != "localhost" && !host.ends_with(".localhost") && host.contains('.')
+}
+
+/// Check if the ip address provided is a public one.
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+/// Check if the ipv4 address provided is a public one.
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || octets[0] == 0
+        || octets[0] >= 240
+        || (octets[0] == 100 && (octets[1] & 0xc0) == 64)
+        || (octets[0] == 198 && (octets[1] & 0xfe) == 18))
+}
+
+/// Check if the ipv6 address provided is a public one.
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || (first_segment & 0xfe00) == 0xfc00
+        || (first_segment & 0xffc0) == 0xfe80
+        || (first_segment == 0x2001 && ip.segments()[1] == 0x0db8))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,7 +215,14 @@ mod tests {
             )
             .unwrap()
             .unwrap(),
-            "https://snyk.io/test/github/username/repo".to_string()
+            (
+                "https://snyk.io/test/github/username/repo".to_string(),
+                Evidence {
+                    file: "README.md".to_string(),
+                    line: Some(3),
+                    pattern: Some(r#"(https://snyk.io/test/github/[^/]+/[^/"]+)"#.to_string()),
+                }
+            )
         );
     }
 
@@ -114,20 +273,27 @@ mod tests {
 
     #[test]
     fn matches_match() {
-        assert!(matches(
-            &Globs {
-                root: Path::new(TESTDATA_PATH),
-                patterns: &["README*"],
-                case_sensitive: true,
-            },
-            &RegexSet::new([r"(?im)^#+.*adopters.*$"]).unwrap(),
-        )
-        .unwrap());
+        assert_eq!(
+            matches(
+                &Globs {
+                    root: Path::new(TESTDATA_PATH),
+                    patterns: &["README*"],
+                    case_sensitive: true,
+                },
+                &RegexSet::new(["non-existing pattern", r"(?im)^#+.*adopters.*$"]).unwrap(),
+            )
+            .unwrap(),
+            Some(Evidence {
+                file: "README.md".to_string(),
+                line: Some(7),
+                pattern: Some(r"(?im)^#+.*adopters.*$".to_string()),
+            })
+        );
     }
 
     #[test]
     fn matches_no_match() {
-        assert!(!matches(
+        assert!(matches(
             &Globs {
                 root: Path::new(TESTDATA_PATH),
                 patterns: &["README*"],
@@ -135,12 +301,13 @@ mod tests {
             },
             &RegexSet::new(["non-existing pattern"]).unwrap(),
         )
-        .unwrap());
+        .unwrap()
+        .is_none());
     }
 
     #[test]
     fn matches_file_not_found() {
-        assert!(!matches(
+        assert!(matches(
             &Globs {
                 root: Path::new(TESTDATA_PATH),
                 patterns: &["nonexisting"],
@@ -148,7 +315,8 @@ mod tests {
             },
             &RegexSet::new(["pattern"]).unwrap(),
         )
-        .unwrap());
+        .unwrap()
+        .is_none());
     }
 
     #[test]
@@ -164,6 +332,60 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn matches_markdown_ignores_code_blocks_and_comments() {
+        assert!(matches(
+            &Globs {
+                root: Path::new(TESTDATA_PATH),
+                patterns: &["markdown/README.md"],
+                case_sensitive: true,
+            },
+            &RegexSet::new([r"(?im)^#+.*governance.*$", r"(?im)^#+.*roadmap.*$"]).unwrap(),
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn matches_markdown_match() {
+        assert_eq!(
+            matches(
+                &Globs {
+                    root: Path::new(TESTDATA_PATH),
+                    patterns: &["markdown/README.md"],
+                    case_sensitive: true,
+                },
+                &RegexSet::new([r"(?im)^#+.*governance.*$", r"(?i)\[.*meeting.*\]\(.*\)"]).unwrap(),
+            )
+            .unwrap(),
+            Some(Evidence {
+                file: "markdown/README.md".to_string(),
+                line: Some(9),
+                pattern: Some(r"(?i)\[.*meeting.*\]\(.*\)".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn matches_non_markdown_uses_raw_content() {
+        assert_eq!(
+            matches(
+                &Globs {
+                    root: Path::new(TESTDATA_PATH),
+                    patterns: &["markdown/README"],
+                    case_sensitive: true,
+                },
+                &RegexSet::new([r"(?im)^#+.*governance.*$"]).unwrap(),
+            )
+            .unwrap(),
+            Some(Evidence {
+                file: "markdown/README".to_string(),
+                line: Some(6),
+                pattern: Some(r"(?im)^#+.*governance.*$".to_string()),
+            })
+        );
+    }
+
     #[tokio::test]
     async fn remote_matches_match() {
         let mock_server = MockServer::start().await;
@@ -208,4 +430,65 @@ mod tests {
                 .is_err()
         );
     }
+
+    #[tokio::test]
+    async fn remote_exists_not_https() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        assert!(!remote_exists(&mock_server.uri()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn remote_exists_private_host() {
+        assert!(!remote_exists("https://127.0.0.1/governance").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn public_addrs_works() {
+        let addrs = |url: &str| {
+            let url = Url::parse(url).unwrap();
+            async move { public_addrs(&url).await }
+        };
+
+        assert_eq!(
+            addrs("https://93.184.215.14/governance").await,
+            Some(vec!["93.184.215.14:443".parse().unwrap()])
+        );
+        assert_eq!(
+            addrs("https://[2606:2800:21f:cb07:6820:80da:af6b:8b2c]:8443/governance").await,
+            Some(vec!["[2606:2800:21f:cb07:6820:80da:af6b:8b2c]:8443"
+                .parse()
+                .unwrap()])
+        );
+        assert_eq!(addrs("http://93.184.215.14/governance").await, None);
+        assert_eq!(
+            addrs("https://169.254.169.254/latest/meta-data").await,
+            None
+        );
+        assert_eq!(addrs("https://[::1]/governance").await, None);
+        assert_eq!(addrs("https://localhost/governance").await, None);
+    }
+
+    #[test]
+    fn is_public_url_literal_works() {
+        let is_public = |url: &str| is_public_url_literal(&Url::parse(url).unwrap());
+
+        assert!(is_public("https://github.com/owner/repo"));
+        assert!(is_public("https://93.184.215.14/governance"));
+        assert!(!is_public("http://github.com/owner/repo"));
+        assert!(!is_public("https://localhost/governance"));
+        assert!(!is_public("https://metadata/governance"));
+        assert!(!is_public("https://127.0.0.1/governance"));
+        assert!(!is_public("https://10.0.0.1/governance"));
+        assert!(!is_public("https://169.254.169.254/latest/meta-data"));
+        assert!(!is_public("https://100.64.0.1/governance"));
+        assert!(!is_public("https://[::1]/governance"));
+        assert!(!is_public("https://[fd00::1]/governance"));
+        assert!(!is_public("https://[::ffff:127.0.0.1]/governance"));
+    }
 }
@@ -3,11 +3,11 @@ use super::{
     path::{self, Globs},
 };
 use crate::linter::{
-    check::{CheckInput, CheckOutput},
+    check::{Check, CheckInput, CheckOutput, Evidence},
+    check_set::find_custom,
     checks::readme,
-    datasource::github,
     metadata::{Exemption, Metadata},
-    CheckSet, CHECKS,
+    CheckSet, CustomCheckSet,
 };
 use anyhow::Result;
 use regex::{Regex, RegexSet};
@@ -21,42 +21,88 @@ pub(crate) fn find_file_or_readme_ref(
     re: &RegexSet,
 ) -> Result<CheckOutput> {
     // File in repo
-    if let Some(path) = path::find(&Globs {
+    if let Some((path, pattern)) = path::find_with_pattern(&Globs {
         root: &input.li.root,
         patterns,
         case_sensitive: false,
     })? {
-        let url = github::build_url(
-            &path,
-            &input.gh_md.owner.login,
-            &input.gh_md.name,
-            &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
-        );
-        return Ok(CheckOutput::passed().url(Some(url)));
+        let url = input.file_url(&path);
+        let evidence = Evidence {
+            file: path.to_string_lossy().into_owned(),
+            line: None,
+            pattern: Some(pattern.to_string()),
+        };
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(Some(evidence)));
     }
 
     // Reference in README file
-    if readme_matches(&input.li.root, re)? {
-        return Ok(CheckOutput::passed());
+    if let Some(evidence) = readme_matches(&input.li.root, re)? {
+        return Ok(CheckOutput::passed().evidence(Some(evidence)));
     }
 
     Ok(CheckOutput::not_passed())
 }
 
+/// Check if a location (local path or url) has been declared in the metadata
+/// file for the check provided and, if so, verify that it exists. None is
+/// returned when no location has been declared for the check.
+pub(crate) async fn find_declared_location(
+    input: &CheckInput<'_>,
+    check_id: &str,
+) -> Result<Option<CheckOutput>> {
+    let Some(location) = input
+        .cm_md
+        .as_ref()
+        .and_then(|md| md.locations.as_ref())
+        .and_then(|locations| locations.get(check_id))
+    else {
+        return Ok(None);
+    };
+
+    // Url (can't be verified in offline mode). Only https urls pointing to
+    // public hosts are verified, so http urls are always reported as not found
+    if location.starts_with("https://") || location.starts_with("http://") {
+        if input.li.offline {
+            return Ok(Some(CheckOutput::not_evaluated()));
+        }
+        if content::remote_exists(location).await.unwrap_or(false) {
+            return Ok(Some(CheckOutput::passed().url(Some(location.clone()))));
+        }
+    } else {
+        // Local path (must be relative to the repository root and stay inside
+        // it once symbolic links are resolved)
+        let path = Path::new(location);
+        if path::is_contained(path) && path::exists_within(&input.li.root, path) {
+            let url = input.file_url(path);
+            return Ok(Some(CheckOutput::passed().url(Some(url))));
+        }
+    }
+
+    Ok(Some(CheckOutput::not_passed().details(Some(format!(
+        "The location declared in the metadata file for this check could not be found: {location}"
+    )))))
+}
+
 /// Check if the README file content matches any of the regular expressions
-/// provided.
-pub(crate) fn readme_matches(root: &Path, re: &RegexSet) -> Result<bool> {
+/// provided, returning the evidence of the match.
+pub(crate) fn readme_matches(root: &Path, re: &RegexSet) -> Result<Option<Evidence>> {
     content::matches(&readme_globs(root), re)
 }
 
 /// Check if the README file content matches any of the regular expressions
-/// provided, returning the value from the first capture group.
-pub(crate) fn readme_capture(root: &Path, regexps: &[&Regex]) -> Result<Option<String>> {
+/// provided, returning the value from the first capture group along with the
+/// evidence of the match.
+pub(crate) fn readme_capture(
+    root: &Path,
+    regexps: &[&Regex],
+) -> Result<Option<(String, Evidence)>> {
     content::find(&readme_globs(root), regexps)
 }
 
 // Returns a Globs instance used to locate the README file.
-pub(crate) fn readme_globs(root: &Path) -> Globs {
+pub(crate) fn readme_globs(root: &Path) -> Globs<'_> {
     Globs {
         root,
         patterns: &readme::FILE_PATTERNS,
@@ -84,29 +130,48 @@ pub(crate) fn find_exemption(check_id: &str, cm_md: Option<&Metadata>) -> Option
 }
 
 /// Check if the check provided should be skipped.
-pub(crate) fn should_skip_check(check_id: &str, check_sets: &[CheckSet]) -> bool {
+pub(crate) fn should_skip_check(
+    check: &dyn Check,
+    check_sets: &[CheckSet],
+    custom_check_sets: &[CustomCheckSet],
+) -> bool {
     // Skip if the check doesn't belong to any of the check sets provided
-    if !CHECKS[check_id]
-        .check_sets
-        .iter()
-        .any(|check_set| check_sets.contains(check_set))
-    {
+    if !check_sets.iter().any(|check_set| {
+        check.check_sets().contains(check_set)
+            || find_custom(check_set, custom_check_sets)
+                .map_or(false, |cs| cs.checks.iter().any(|id| id == check.id()))
+    }) {
         return true;
     }
 
     false
 }
 
+/// Compute the median of the sorted values provided.
+pub(crate) fn median(sorted_values: &[i64]) -> i64 {
+    let mid = sorted_values.len() / 2;
+    if sorted_values.len() % 2 == 0 {
+        (sorted_values[mid - 1] + sorted_values[mid]) / 2
+    } else {
+        sorted_values[mid]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linter::datasource::forge::Forge;
     use crate::linter::{
         adopters,
         datasource::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
-        sbom, LinterInput,
+        governance, sbom, LinterInput, CHECKS,
     };
     use anyhow::format_err;
-    use std::path::PathBuf;
+    use std::{collections::HashMap, path::PathBuf};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     const TESTDATA_PATH: &str = "src/testdata";
 
@@ -119,6 +184,7 @@ mod tests {
                         root: PathBuf::from(TESTDATA_PATH),
                         ..LinterInput::default()
                     },
+                    forge: Forge::GitHub,
                     cm_md: None,
                     gh_md: MdRepository {
                         name: "repo".to_string(),
@@ -130,14 +196,21 @@ mod tests {
                     },
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    license: None,
                 },
                 &["README*"],
                 &RegexSet::new(["nothing"]).unwrap(),
             )
             .unwrap(),
-            CheckOutput::passed().url(Some(
-                "https://github.com/owner/repo/blob/master/README.md".to_string()
-            )),
+            CheckOutput::passed()
+                .url(Some(
+                    "https://github.com/owner/repo/blob/master/README.md".to_string()
+                ))
+                .evidence(Some(Evidence {
+                    file: "README.md".to_string(),
+                    line: None,
+                    pattern: Some("README*".to_string()),
+                })),
         );
     }
 
@@ -150,16 +223,22 @@ mod tests {
                         root: PathBuf::from(TESTDATA_PATH),
                         ..LinterInput::default()
                     },
+                    forge: Forge::GitHub,
                     cm_md: None,
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    license: None,
                 },
                 &["ADOPTERS*"],
                 &RegexSet::new([r"(?im)^#+.*adopters.*$"]).unwrap(),
             )
             .unwrap(),
-            CheckOutput::passed(),
+            CheckOutput::passed().evidence(Some(Evidence {
+                file: "README.md".to_string(),
+                line: Some(7),
+                pattern: Some(r"(?im)^#+.*adopters.*$".to_string()),
+            })),
         );
     }
 
@@ -172,10 +251,12 @@ mod tests {
                         root: PathBuf::from(TESTDATA_PATH),
                         ..LinterInput::default()
                     },
+                    forge: Forge::GitHub,
                     cm_md: None,
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    license: None,
                 },
                 &["inexistent_file*"],
                 &RegexSet::new(["inexistent_ref"]).unwrap(),
@@ -185,6 +266,131 @@ mod tests {
         );
     }
 
+    async fn find_declared_location_output(offline: bool, location: &str) -> Option<CheckOutput> {
+        find_declared_location(
+            &CheckInput {
+                li: &LinterInput {
+                    root: PathBuf::from(TESTDATA_PATH),
+                    offline,
+                    ..LinterInput::default()
+                },
+                forge: Forge::GitHub,
+                cm_md: Some(Metadata {
+                    exemptions: None,
+                    license_scanning: None,
+                    locations: Some(HashMap::from([(
+                        governance::ID.to_string(),
+                        location.to_string(),
+                    )])),
+                }),
+                gh_md: MdRepository {
+                    name: "repo".to_string(),
+                    owner: MdRepositoryOwner {
+                        login: "owner".to_string(),
+                        on: MdRepositoryOwnerOn::Organization,
+                    },
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                license: None,
+            },
+            governance::ID,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_not_declared() {
+        assert_eq!(
+            find_declared_location(
+                &CheckInput {
+                    li: &LinterInput::default(),
+                    forge: Forge::GitHub,
+                    cm_md: None,
+                    gh_md: MdRepository::default(),
+                    scorecard: Err(format_err!("no scorecard available")),
+                    security_insights: Ok(None),
+                    license: None,
+                },
+                governance::ID,
+            )
+            .await
+            .unwrap(),
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_path_found() {
+        assert_eq!(
+            find_declared_location_output(false, "MAINTAINERS").await,
+            Some(CheckOutput::passed().url(Some(
+                "https://github.com/owner/repo/blob/master/MAINTAINERS".to_string()
+            ))),
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_path_not_found() {
+        assert_eq!(
+            find_declared_location_output(false, "community/GOVERNANCE.md").await,
+            Some(CheckOutput::not_passed().details(Some(
+                "The location declared in the metadata file for this check could not be found: community/GOVERNANCE.md".to_string()
+            ))),
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_path_outside_repository() {
+        assert!(
+            !find_declared_location_output(false, "../testdata/MAINTAINERS")
+                .await
+                .unwrap()
+                .passed
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_url_not_https() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/governance"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let url = format!("{}/governance", mock_server.uri());
+
+        assert_eq!(
+            find_declared_location_output(false, &url).await,
+            Some(CheckOutput::not_passed().details(Some(format!(
+                "The location declared in the metadata file for this check could not be found: {url}"
+            )))),
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_url_private_host() {
+        let url = "https://169.254.169.254/latest/meta-data";
+
+        assert!(
+            !find_declared_location_output(false, url)
+                .await
+                .unwrap()
+                .passed
+        );
+    }
+
+    #[tokio::test]
+    async fn find_declared_location_url_offline() {
+        assert_eq!(
+            find_declared_location_output(true, "https://example.com/governance").await,
+            Some(CheckOutput::not_evaluated()),
+        );
+    }
+
     #[test]
     fn find_exemption_found() {
         assert_eq!(
@@ -194,13 +400,16 @@ mod tests {
                     exemptions: Some(vec![Exemption {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
+                        ..Default::default()
                     }]),
-                    license_scanning: None
+                    license_scanning: None,
+                    locations: None
                 })
             ),
             Some(Exemption {
                 check: "check-id".to_string(),
                 reason: "sample reason".to_string(),
+                ..Default::default()
             }),
         );
     }
@@ -214,8 +423,10 @@ mod tests {
                     exemptions: Some(vec![Exemption {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
+                        ..Default::default()
                     }]),
-                    license_scanning: None
+                    license_scanning: None,
+                    locations: None
                 })
             ),
             None,
@@ -229,7 +440,8 @@ mod tests {
                 "check-id",
                 Some(&Metadata {
                     exemptions: None,
-                    license_scanning: None
+                    license_scanning: None,
+                    locations: None
                 })
             ),
             None,
@@ -243,19 +455,58 @@ mod tests {
 
     #[test]
     fn should_skip_check_affirmative() {
-        assert!(should_skip_check(adopters::ID, &[CheckSet::Code]));
-        assert!(should_skip_check(sbom::ID, &[CheckSet::Community]));
+        assert!(should_skip_check(
+            &CHECKS[adopters::ID],
+            &[CheckSet::Code],
+            &[]
+        ));
+        assert!(should_skip_check(
+            &CHECKS[sbom::ID],
+            &[CheckSet::Community],
+            &[]
+        ));
     }
 
     #[test]
     fn should_skip_check_negative() {
         assert!(!should_skip_check(
-            adopters::ID,
-            &[CheckSet::Code, CheckSet::Community]
+            &CHECKS[adopters::ID],
+            &[CheckSet::Code, CheckSet::Community],
+            &[]
         ));
         assert!(!should_skip_check(
-            sbom::ID,
-            &[CheckSet::Code, CheckSet::Community]
+            &CHECKS[sbom::ID],
+            &[CheckSet::Code, CheckSet::Community],
+            &[]
         ));
     }
+
+    #[test]
+    fn should_skip_check_custom_check_set() {
+        let custom_check_sets = vec![CustomCheckSet {
+            name: "spec".to_string(),
+            checks: vec![sbom::ID.to_string()],
+            ..Default::default()
+        }];
+        let check_sets = [CheckSet::Custom("spec".to_string())];
+
+        assert!(!should_skip_check(
+            &CHECKS[sbom::ID],
+            &check_sets,
+            &custom_check_sets
+        ));
+        assert!(should_skip_check(
+            &CHECKS[adopters::ID],
+            &check_sets,
+            &custom_check_sets
+        ));
+        assert!(should_skip_check(&CHECKS[sbom::ID], &check_sets, &[]));
+    }
+
+    #[test]
+    fn median_works() {
+        assert_eq!(median(&[5]), 5);
+        assert_eq!(median(&[1, 3, 10]), 3);
+        assert_eq!(median(&[1, 3, 5, 10]), 4);
+    }
 }
@@ -0,0 +1,289 @@
+use lazy_static::lazy_static;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::{Regex, RegexSet};
+
+lazy_static! {
+    static ref HTML_COMMENT: Regex =
+        Regex::new(r"(?s)<!--.*?(-->|$)").expect("expr in HTML_COMMENT to be valid");
+}
+
+/// Markdown document parsed into the elements checks are interested in. Code
+/// blocks, html comments and images alternative text are not part of any of
+/// the elements.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Markdown {
+    pub headings: Vec<Heading>,
+    pub links: Vec<Link>,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+/// Markdown heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Heading {
+    pub level: usize,
+    pub text: String,
+    pub line: usize,
+}
+
+/// Markdown link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Link {
+    pub text: String,
+    pub url: String,
+    pub line: usize,
+}
+
+/// Markdown paragraph text (list items, table cells and html blocks are
+/// considered paragraphs as well).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Paragraph {
+    pub text: String,
+    pub line: usize,
+}
+
+impl Markdown {
+    /// Parse the markdown content provided.
+    pub(crate) fn parse(content: &str) -> Self {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line = |offset: usize| line_starts.partition_point(|&start| start <= offset);
+
+        let mut md = Markdown::default();
+        let mut heading: Option<Heading> = None;
+        let mut paragraph: Option<Paragraph> = None;
+        let mut links: Vec<Link> = Vec::new();
+        let mut code_depth = 0;
+        let mut image_depth = 0;
+
+        let parser = Parser::new_ext(content, Options::ENABLE_TABLES);
+        for (event, range) in parser.into_offset_iter() {
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    heading = Some(Heading {
+                        level: level as usize,
+                        text: String::new(),
+                        line: line(range.start),
+                    });
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some(heading) = heading.take() {
+                        md.headings.push(heading);
+                    }
+                }
+                Event::Start(Tag::Paragraph | Tag::Item | Tag::TableCell | Tag::HtmlBlock)
+                    if paragraph.is_none() =>
+                {
+                    paragraph = Some(Paragraph {
+                        text: String::new(),
+                        line: line(range.start),
+                    });
+                }
+                Event::End(
+                    TagEnd::Paragraph | TagEnd::Item | TagEnd::TableCell | TagEnd::HtmlBlock,
+                ) => {
+                    if let Some(mut paragraph) = paragraph.take() {
+                        paragraph.text = HTML_COMMENT.replace_all(&paragraph.text, "").into_owned();
+                        if !paragraph.text.trim().is_empty() {
+                            md.paragraphs.push(paragraph);
+                        }
+                    }
+                }
+                Event::Start(Tag::Link { dest_url, .. }) => links.push(Link {
+                    text: String::new(),
+                    url: dest_url.to_string(),
+                    line: line(range.start),
+                }),
+                Event::End(TagEnd::Link) => {
+                    if let Some(link) = links.pop() {
+                        md.links.push(link);
+                    }
+                }
+                Event::Start(Tag::CodeBlock(_)) => code_depth += 1,
+                Event::End(TagEnd::CodeBlock) => code_depth -= 1,
+                Event::Start(Tag::Image { .. }) => image_depth += 1,
+                Event::End(TagEnd::Image) => image_depth -= 1,
+                Event::Text(text) | Event::Code(text) if code_depth == 0 && image_depth == 0 => {
+                    if let Some(heading) = heading.as_mut() {
+                        heading.text.push_str(&text);
+                    } else if let Some(paragraph) = paragraph.as_mut() {
+                        paragraph.text.push_str(&text);
+                    }
+                    for link in &mut links {
+                        link.text.push_str(&text);
+                    }
+                }
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    if let Some(paragraph) = paragraph.as_mut() {
+                        paragraph.text.push_str(&html);
+                    }
+                }
+                Event::SoftBreak | Event::HardBreak => {
+                    if let Some(heading) = heading.as_mut() {
+                        heading.text.push(' ');
+                    } else if let Some(paragraph) = paragraph.as_mut() {
+                        paragraph.text.push('\n');
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        md
+    }
+
+    /// Check if any of the document elements matches any of the regular
+    /// expressions provided, returning the line where the match was found
+    /// and the pattern that matched. Headings are matched in their markdown
+    /// form (i.e. `## Title`), as well as links (i.e. `[text](url)`), so
+    /// the expressions used to match the raw content can be used.
+    pub(crate) fn matches(&self, re: &RegexSet) -> Option<(usize, String)> {
+        let headings = self.headings.iter().map(|h| {
+            let text = format!("{} {}", "#".repeat(h.level), h.text.trim());
+            (text, h.line)
+        });
+        let links = self
+            .links
+            .iter()
+            .map(|l| (format!("[{}]({})", l.text.trim(), l.url), l.line));
+        let paragraphs = self.paragraphs.iter().map(|p| (p.text.clone(), p.line));
+
+        for (text, line) in headings.chain(links).chain(paragraphs) {
+            if let Some(i) = re.matches(&text).iter().next() {
+                let pattern = &re.patterns()[i];
+                let offset = Regex::new(pattern)
+                    .ok()
+                    .and_then(|re| re.find(&text))
+                    .map_or(0, |m| m.start());
+                return Some((line + text[..offset].matches('\n').count(), pattern.clone()));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# Project
+
+[![Community meeting](https://img.shields.io/badge/meeting-blue)](https://badge.url)
+
+<!-- ## Roadmap -->
+
+<p align="center"><a href="https://slack.cncf.io">Slack</a></p>
+
+```sh
+## Governance
+```
+
+Join our [community meeting](https://meeting.url) every
+Tuesday.
+
+- Item about the sbom
+"#;
+
+    #[test]
+    fn parse_works() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert_eq!(
+            md.headings,
+            vec![Heading {
+                level: 1,
+                text: "Project".to_string(),
+                line: 1,
+            }]
+        );
+        assert_eq!(
+            md.links,
+            vec![
+                Link {
+                    text: String::new(),
+                    url: "https://badge.url".to_string(),
+                    line: 3,
+                },
+                Link {
+                    text: "community meeting".to_string(),
+                    url: "https://meeting.url".to_string(),
+                    line: 13,
+                }
+            ]
+        );
+        assert_eq!(
+            md.paragraphs,
+            vec![
+                Paragraph {
+                    text: "<p align=\"center\"><a href=\"https://slack.cncf.io\">Slack</a></p>\n"
+                        .to_string(),
+                    line: 7,
+                },
+                Paragraph {
+                    text: "Join our community meeting every\nTuesday.".to_string(),
+                    line: 13,
+                },
+                Paragraph {
+                    text: "Item about the sbom".to_string(),
+                    line: 16,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_heading() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert_eq!(
+            md.matches(&RegexSet::new([r"(?im)^#+.*project.*$"]).unwrap()),
+            Some((1, r"(?im)^#+.*project.*$".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_link() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert_eq!(
+            md.matches(&RegexSet::new([r"(?i)\[.*meeting.*\]\(.*\)"]).unwrap()),
+            Some((13, r"(?i)\[.*meeting.*\]\(.*\)".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_paragraph_line() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert_eq!(md.matches(&RegexSet::new([r"(?i)^tuesday"]).unwrap()), None);
+        assert_eq!(
+            md.matches(&RegexSet::new([r"(?im)^tuesday"]).unwrap()),
+            Some((14, r"(?im)^tuesday".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_html_block() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert_eq!(
+            md.matches(&RegexSet::new([r"(?i)https?://slack.cncf.io"]).unwrap()),
+            Some((7, r"(?i)https?://slack.cncf.io".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_ignores_code_blocks_html_and_images() {
+        let md = Markdown::parse(SAMPLE);
+
+        assert!(md
+            .matches(&RegexSet::new([r"(?im)^#+.*governance.*$"]).unwrap())
+            .is_none());
+        assert!(md
+            .matches(&RegexSet::new([r"(?im)^#+.*roadmap.*$"]).unwrap())
+            .is_none());
+        assert!(md
+            .matches(&RegexSet::new([r"(?i)\[community meeting\]\(https://img"]).unwrap())
+            .is_none());
+    }
+}
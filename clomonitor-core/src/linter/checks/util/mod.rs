@@ -1,4 +1,5 @@
 pub(crate) mod content;
 pub(crate) mod fs;
 pub(crate) mod helpers;
+pub(crate) mod markdown;
 pub(crate) mod path;
@@ -2,7 +2,7 @@ use anyhow::Result;
 use glob::{glob_with, MatchOptions, PatternError};
 use std::{
     ffi::OsStr,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 
 /// Glob matching configuration.
@@ -15,19 +15,49 @@ pub(crate) struct Globs<'a> {
 
 /// Find the first path that matches any of the globs provided.
 pub(crate) fn find(globs: &Globs) -> Result<Option<PathBuf>> {
-    match matches(globs)?.first() {
-        Some(path) => Ok(Some(
-            if globs.root.as_os_str() == OsStr::new(".") || globs.root.as_os_str().is_empty() {
-                path
-            } else {
-                path.strip_prefix(globs.root)?
-            }
-            .to_owned(),
-        )),
-        None => Ok(None),
+    Ok(find_with_pattern(globs)?.map(|(path, _)| path))
+}
+
+/// Find the first path that matches any of the globs provided, returning it
+/// along with the pattern that matched it.
+pub(crate) fn find_with_pattern<'a>(globs: &Globs<'a>) -> Result<Option<(PathBuf, &'a str)>> {
+    for pattern in globs.patterns {
+        let pattern_globs = Globs {
+            patterns: &[*pattern],
+            ..globs.clone()
+        };
+        if let Some(path) = matches(&pattern_globs)?.first() {
+            return Ok(Some((relative(globs.root, path)?.to_owned(), *pattern)));
+        }
+    }
+    Ok(None)
+}
+
+/// Return the path provided relative to the root given.
+pub(crate) fn relative<'a>(root: &Path, path: &'a Path) -> Result<&'a Path> {
+    if root.as_os_str() == OsStr::new(".") || root.as_os_str().is_empty() {
+        Ok(path)
+    } else {
+        Ok(path.strip_prefix(root)?)
     }
 }
 
+/// Check if the path provided is relative and doesn't point outside of the
+/// directory it is relative to.
+pub(crate) fn is_contained(path: &Path) -> bool {
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// Check if the path provided, relative to the root given, exists and is
+/// located inside the root once resolved (i.e. following symbolic links).
+pub(crate) fn exists_within(root: &Path, path: &Path) -> bool {
+    let (Ok(root), Ok(path)) = (root.canonicalize(), root.join(path).canonicalize()) else {
+        return false;
+    };
+    path.starts_with(root)
+}
+
 /// Return all paths that match any of the globs provided.
 pub(crate) fn matches(globs: &Globs) -> Result<Vec<PathBuf>, PatternError> {
     let options = MatchOptions {
@@ -82,6 +112,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn find_with_pattern_existing_path() {
+        assert_eq!(
+            find_with_pattern(&Globs {
+                root: Path::new(TESTDATA_PATH),
+                patterns: &["nonexisting", "owners*", "maintainers*"],
+                case_sensitive: false,
+            })
+            .unwrap(),
+            Some((PathBuf::from("OWNERS"), "owners*"))
+        );
+    }
+
+    #[test]
+    fn is_contained_works() {
+        assert!(is_contained(Path::new("docs/GOVERNANCE.md")));
+        assert!(is_contained(Path::new("./docs")));
+        assert!(!is_contained(Path::new("../docs")));
+        assert!(!is_contained(Path::new("docs/../../GOVERNANCE.md")));
+        assert!(!is_contained(Path::new("/docs")));
+    }
+
+    #[test]
+    fn exists_within_works() {
+        let root = Path::new(TESTDATA_PATH);
+        assert!(exists_within(root, Path::new("README.md")));
+        assert!(!exists_within(root, Path::new("not-found.md")));
+        assert!(!exists_within(
+            &root.join("component"),
+            Path::new("../README.md")
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exists_within_symlink_outside_root() {
+        let root = tempfile::tempdir().unwrap();
+        std::os::unix::fs::symlink(
+            Path::new(TESTDATA_PATH).canonicalize().unwrap(),
+            root.path().join("docs"),
+        )
+        .unwrap();
+
+        assert!(!exists_within(root.path(), Path::new("docs/README.md")));
+    }
+
     #[test]
     fn find_invalid_glob_pattern() {
         assert!(find(&Globs {
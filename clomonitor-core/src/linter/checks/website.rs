@@ -19,7 +19,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Website in Github
     if let Some(url) = &input.gh_md.homepage_url {
         if !url.is_empty() {
-            return Ok(CheckOutput::passed().url(Some(url.to_string())));
+            return Ok(CheckOutput::passed().url(Some(url.clone())));
         }
     }
 
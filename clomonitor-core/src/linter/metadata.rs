@@ -1,8 +1,10 @@
-use super::util;
+use super::{date_format, util};
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::path::Path;
+use time::Date;
 
 /// Metadata file name.
 pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
@@ -10,9 +12,13 @@ pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
 /// CLOMonitor metadata.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Metadata {
+pub struct Metadata {
     pub exemptions: Option<Vec<Exemption>>,
     pub license_scanning: Option<LicenseScanning>,
+
+    /// Locations (local path or url) of the documents some checks look for
+    /// (check id -> location).
+    pub locations: Option<HashMap<String, String>>,
 }
 
 impl Metadata {
@@ -29,21 +35,39 @@ impl Metadata {
 }
 
 /// Metadata check exemption entry.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub(crate) struct Exemption {
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Exemption {
     pub check: String,
     pub reason: String,
+
+    /// Last date on which the exemption applies (inclusive).
+    #[serde(default, with = "date_format::option")]
+    pub expires: Option<Date>,
+
+    #[serde(alias = "approved_by")]
+    pub approved_by: Option<String>,
+}
+
+impl Exemption {
+    /// Check if the exemption has expired on the date provided (that is, if
+    /// the date is after the exemption's expiration date).
+    #[must_use]
+    pub fn is_expired(&self, today: Date) -> bool {
+        self.expires.map_or(false, |expires| expires < today)
+    }
 }
 
 /// License scanning section of the metadata.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
-pub(crate) struct LicenseScanning {
+pub struct LicenseScanning {
     pub url: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use time::macros::date;
 
     const TESTDATA_PATH: &str = "src/testdata";
 
@@ -57,10 +81,23 @@ mod tests {
                 license_scanning: Some(LicenseScanning {
                     url: Some("https://license-scanning-results.url".to_string()),
                 }),
-                exemptions: Some(vec![Exemption {
-                    check: "artifacthub_badge".to_string(),
-                    reason: "this is a sample reason".to_string(),
-                }])
+                locations: Some(HashMap::from([(
+                    "governance".to_string(),
+                    "community/GOVERNANCE.md".to_string()
+                )])),
+                exemptions: Some(vec![
+                    Exemption {
+                        check: "artifacthub_badge".to_string(),
+                        reason: "this is a sample reason".to_string(),
+                        ..Default::default()
+                    },
+                    Exemption {
+                        check: "openssf_badge".to_string(),
+                        reason: "this is another sample reason".to_string(),
+                        expires: Some(date!(2030 - 01 - 31)),
+                        approved_by: Some("tegan".to_string()),
+                    },
+                ])
             },
         );
     }
@@ -77,4 +114,16 @@ mod tests {
     fn metadata_from_path_invalid_metadata_file() {
         assert!(Metadata::from(Path::new(TESTDATA_PATH).join(".clomonitor-invalid.yaml")).is_err());
     }
+
+    #[test]
+    fn exemption_is_expired() {
+        let exemption = Exemption {
+            expires: Some(date!(2024 - 06 - 30)),
+            ..Default::default()
+        };
+
+        assert!(!exemption.is_expired(date!(2024 - 06 - 30)));
+        assert!(exemption.is_expired(date!(2024 - 07 - 01)));
+        assert!(!Exemption::default().is_expired(date!(2024 - 07 - 01)));
+    }
 }
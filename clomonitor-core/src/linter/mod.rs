@@ -1,29 +1,60 @@
+#[cfg(feature = "mocks")]
+pub use self::cache::MockCheckCache;
 use self::{
-    check::*,
-    checks::util::helpers::{find_exemption, should_skip_check},
+    cache::CachedCheck,
+    check::{run_check, run_component_check},
+    check_set::weight_overrides,
+    checks::responsiveness,
+    util::{
+        helpers::{find_exemption, should_skip_check},
+        path,
+    },
 };
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use async_trait::async_trait;
-use clap::ValueEnum;
+use futures::future;
 #[cfg(feature = "mocks")]
 use mockall::automock;
-use postgres_types::ToSql;
-use serde::{Deserialize, Serialize};
-use std::{fmt, path::PathBuf, sync::Arc};
-use time::Date;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+use time::{serde::format_description, Date, OffsetDateTime};
 
+mod cache;
 mod check;
+mod check_set;
 mod checks;
 mod metadata;
+mod registry;
+mod remediation;
 mod report;
 
 pub use self::{
-    check::{CheckId, CheckOutput},
+    cache::{CacheInputs, CacheKey, CheckCache, DynCheckCache, MemoryCheckCache},
+    check::{Check, CheckId, CheckInput, CheckOutput, CheckStatus, Evidence, RemoteData},
+    check_set::{CheckSet, CustomCheckSet},
+    checks::datasource::{
+        forge::Forge,
+        github::md::MdRepository,
+        scorecard::{Scorecard, ScorecardCheck, ScorecardCheckDocs, ScorecardSource},
+        security_insights::SecurityInsights,
+    },
+    checks::release_cadence::ReleaseCadenceThresholds,
+    checks::responsiveness::{ResponsivenessMetrics, ResponsivenessThresholds},
+    metadata::{Exemption, LicenseScanning, Metadata},
+    registry::CheckRegistry,
+    remediation::{FileTemplate, Remediation, TemplateValues},
     report::*,
 };
 pub use checks::datasource::github::setup_http_client as setup_github_http_client;
 pub(crate) use checks::*;
 
+// Serde format used for dates (i.e. 2024-06-30).
+format_description!(date_format, Date, "[year]-[month]-[day]");
+
 /// Type alias to represent a Linter trait object.
 pub type DynLinter = Arc<dyn Linter + Send + Sync>;
 
@@ -40,9 +71,63 @@ pub trait Linter {
 pub struct LinterInput {
     pub project: Option<Project>,
     pub root: PathBuf,
+    /// Path of the component to lint, relative to the repository root (used
+    /// when several components live in the same repository). Checks run on
+    /// the component's path and fall back to the repository root when they
+    /// don't pass there (i.e. for shared files like LICENSE).
+    pub subpath: Option<PathBuf>,
     pub url: String,
     pub check_sets: Vec<CheckSet>,
+    /// Custom check sets definitions. The custom check sets referenced in
+    /// `check_sets` must be defined here.
+    pub custom_check_sets: Vec<CustomCheckSet>,
     pub github_token: String,
+    /// Token used to authenticate the requests to the GitLab API.
+    pub gitlab_token: Option<String>,
+    /// Token used to authenticate the requests to the Gitea (or Forgejo) API.
+    pub gitea_token: Option<String>,
+    pub offline: bool,
+    /// Forge hosting the repository. When not provided, it'll be detected
+    /// from the repository url.
+    pub forge: Option<Forge>,
+    /// Precomputed OpenSSF Scorecard JSON file.
+    pub scorecard_json: Option<PathBuf>,
+    /// OpenSSF Scorecard API endpoint serving precomputed results.
+    pub scorecard_api_url: Option<String>,
+    /// OpenSSF Scorecard checks pass thresholds (check id -> score). The
+    /// default threshold is used for the checks not present in this map.
+    pub scorecard_thresholds: HashMap<String, f64>,
+    /// Thresholds used by the responsiveness check.
+    pub responsiveness_thresholds: ResponsivenessThresholds,
+    /// Thresholds used by the release cadence check.
+    pub release_cadence_thresholds: ReleaseCadenceThresholds,
+}
+
+impl LinterInput {
+    /// Return the input used to lint the component located at the subpath
+    /// provided, if any. The root of this input points to the component.
+    fn component(&self) -> Result<Option<LinterInput>> {
+        let Some(subpath) = &self.subpath else {
+            return Ok(None);
+        };
+        if !path::is_contained(subpath) {
+            return Err(format_err!(
+                "subpath must be relative to the repository root: {}",
+                subpath.display()
+            ));
+        }
+        let root = self.root.join(subpath);
+        if !root.is_dir() {
+            return Err(format_err!(
+                "subpath not found in repository: {}",
+                subpath.display()
+            ));
+        }
+        Ok(Some(LinterInput {
+            root,
+            ..self.clone()
+        }))
+    }
 }
 
 /// Project's details
@@ -61,114 +146,194 @@ pub struct Foundation {
     pub landscape_url: Option<String>,
 }
 
-/// Check sets define a set of checks that will be run on a given repository.
-/// Multiple check sets can be assigned to a repository.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize, ToSql)]
-#[serde(rename_all = "kebab-case")]
-#[postgres(name = "check_set")]
-pub enum CheckSet {
-    #[postgres(name = "code")]
-    Code,
-    #[postgres(name = "code-lite")]
-    CodeLite,
-    #[postgres(name = "community")]
-    Community,
-    #[postgres(name = "docs")]
-    Docs,
-}
-
-impl fmt::Display for CheckSet {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let output = match self {
-            Self::Code => "CODE",
-            Self::CodeLite => "CODE-LITE",
-            Self::Community => "COMMUNITY",
-            Self::Docs => "DOCS",
-        };
-        write!(f, "{output}")
-    }
-}
-
 /// CLOMonitor core linter (Linter implementation).
-pub struct CoreLinter;
+pub struct CoreLinter {
+    registry: CheckRegistry,
+    cache: Option<DynCheckCache>,
+}
 
 #[allow(clippy::new_without_default)]
 impl CoreLinter {
-    /// Create a new CoreLinter instance.
+    /// Create a new CoreLinter instance that will run the builtin checks.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::with_registry(CHECKS.clone())
+    }
+
+    /// Create a new CoreLinter instance that will run the checks available in
+    /// the registry provided.
+    #[must_use]
+    pub fn with_registry(registry: CheckRegistry) -> Self {
+        Self {
+            registry,
+            cache: None,
+        }
+    }
+
+    /// Use the cache provided to reuse the output of the checks whose inputs
+    /// haven't changed since they were last run.
+    #[must_use]
+    pub fn with_cache(mut self, cache: DynCheckCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Return the checks registry used by this linter.
+    #[must_use]
+    pub fn registry(&self) -> &CheckRegistry {
+        &self.registry
+    }
+
+    /// Check if any of the active checks provided relies on the OpenSSF
+    /// Scorecard and will need to be run (i.e. it isn't exempt and its
+    /// output is not cached).
+    async fn scorecard_needed(
+        &self,
+        active_checks: &[&dyn Check],
+        input: &CheckInput<'_>,
+        repository_root: &Path,
+    ) -> bool {
+        let today = OffsetDateTime::now_utc().date();
+        for check in active_checks {
+            let uses_scorecard = check.scorecard_name().is_some()
+                || check
+                    .cache_inputs()
+                    .map_or(false, |inputs| inputs.scorecard);
+            if !uses_scorecard {
+                continue;
+            }
+            if find_exemption(check.id(), input.cm_md.as_ref())
+                .map_or(false, |exemption| !exemption.is_expired(today))
+            {
+                continue;
+            }
+            match &self.cache {
+                Some(cache) if cache::is_cached(*check, cache, input, repository_root).await => {}
+                _ => return true,
+            }
+        }
+        false
     }
 }
 
 #[async_trait]
 impl Linter for CoreLinter {
     async fn lint(&self, li: &LinterInput) -> Result<Report> {
-        // Prepare check input
-        let ci = CheckInput::new(li).await?;
-
-        // Run some async checks concurrently
-        let (contributing, summary_table, trademark_disclaimer) = tokio::join!(
-            run_async!(contributing, &ci),
-            run_async!(summary_table, &ci),
-            run_async!(trademark_disclaimer, &ci),
-        );
+        // Check the custom check sets requested and get their weight overrides
+        let weights = weight_overrides(&li.check_sets, &li.custom_check_sets, &self.registry)?;
 
-        // Run some sync checks needed in advance
-        let spdx_id = run!(license_spdx_id, &ci);
-        let mut spdx_id_value: Option<String> = None;
-        if let Some(r) = &spdx_id {
-            spdx_id_value.clone_from(&r.value);
+        // Prepare check input (when a subpath is provided, checks are run on
+        // the component located at it, falling back to the repository root)
+        let repository = LinterInput {
+            subpath: None,
+            ..li.clone()
+        };
+        let active_checks: Vec<&dyn Check> = self
+            .registry
+            .iter()
+            .filter(|check| !should_skip_check(*check, &li.check_sets, &li.custom_check_sets))
+            .collect();
+        let remote_data = active_checks
+            .iter()
+            .any(|check| check.remote_data() != RemoteData::None);
+        let with_items = active_checks.iter().any(|c| c.id() == responsiveness::ID);
+        let mut repository_input = CheckInput::new(&repository, remote_data, with_items).await?;
+        let component = li.component()?;
+        let mut component_input = match &component {
+            Some(component) => Some(repository_input.component(component)?),
+            None => None,
+        };
+
+        // Fetch the OpenSSF Scorecard only when some of the checks relying on
+        // it need to be run
+        if self
+            .scorecard_needed(
+                &active_checks,
+                component_input.as_ref().unwrap_or(&repository_input),
+                &repository.root,
+            )
+            .await
+        {
+            repository_input.fetch_scorecard().await?;
+            if let Some(component) = &component {
+                component_input = Some(repository_input.component(component)?);
+            }
         }
+        let ci = component_input.as_ref().unwrap_or(&repository_input);
 
-        // Run the remaining sync checks and build report
-        let mut report = Report {
-            documentation: Documentation {
-                adopters: run!(adopters, &ci),
-                changelog: run!(changelog, &ci),
-                code_of_conduct: run!(code_of_conduct, &ci),
-                contributing,
-                governance: run!(governance, &ci),
-                maintainers: run!(maintainers, &ci),
-                readme: run!(readme, &ci),
-                roadmap: run!(roadmap, &ci),
-                summary_table,
-                website: run!(website, &ci),
-            },
-            license: License {
-                license_approved: license_approved::check(&ci, spdx_id_value),
-                license_scanning: run!(license_scanning, &ci),
-                license_spdx_id: spdx_id,
-            },
-            best_practices: BestPractices {
-                artifacthub_badge: run!(artifacthub_badge, &ci),
-                cla: run!(cla, &ci),
-                community_meeting: run!(community_meeting, &ci),
-                dco: run!(dco, &ci),
-                github_discussions: run!(github_discussions, &ci),
-                openssf_badge: run!(openssf_badge, &ci),
-                openssf_scorecard_badge: run!(openssf_scorecard_badge, &ci),
-                recent_release: run!(recent_release, &ci),
-                slack_presence: run!(slack_presence, &ci),
-            },
-            security: Security {
-                binary_artifacts: run!(binary_artifacts, &ci),
-                code_review: run!(code_review, &ci),
-                dangerous_workflow: run!(dangerous_workflow, &ci),
-                dependencies_policy: run!(dependencies_policy, &ci),
-                dependency_update_tool: run!(dependency_update_tool, &ci),
-                maintained: run!(maintained, &ci),
-                sbom: run!(sbom, &ci),
-                security_insights: run!(security_insights, &ci),
-                security_policy: run!(security_policy, &ci),
-                signed_releases: run!(signed_releases, &ci),
-                token_permissions: run!(token_permissions, &ci),
-            },
-            legal: Legal {
-                trademark_disclaimer,
-            },
+        // Run all checks registered (using the cache if available)
+        let component_input = component_input.as_ref();
+        let repository_input = &repository_input;
+        let repository_root = repository.root.as_path();
+        let run = |check| async move {
+            let cached_check;
+            let check = match &self.cache {
+                Some(cache) => {
+                    cached_check = CachedCheck {
+                        check,
+                        cache,
+                        repository_root,
+                    };
+                    &cached_check as &dyn Check
+                }
+                None => check,
+            };
+            let start = Instant::now();
+            let output = match component_input {
+                Some(input) => run_component_check(check, input, repository_input).await,
+                None => run_check(check, repository_input).await,
+            };
+            (output, start.elapsed())
         };
+
+        // Sync checks are run one after the other, so that the time they
+        // block the executor isn't attributed to other checks, and async ones
+        // are run concurrently
+        let mut outputs = HashMap::new();
+        for check in self.registry.iter().filter(|check| !check.is_async()) {
+            outputs.insert(check.id(), run(check).await);
+        }
+        let async_checks: Vec<&dyn Check> = self
+            .registry
+            .iter()
+            .filter(|check| check.is_async())
+            .collect();
+        let async_outputs = future::join_all(async_checks.iter().map(|check| run(*check))).await;
+        outputs.extend(
+            async_checks
+                .iter()
+                .map(|check| check.id())
+                .zip(async_outputs),
+        );
+
+        // Build report
+        let mut report = Report::default();
+        for check in self.registry.iter() {
+            let Some((Some(mut output), duration)) = outputs.remove(check.id()) else {
+                continue;
+            };
+            if !(output.passed || output.exempt || output.failed || output.not_evaluated) {
+                output.remediation = output.remediation.or_else(|| check.remediation());
+            }
+            report.set_check_output(check.section(), check.id(), output);
+            let duration = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+            report.durations.insert(check.id().to_string(), duration);
+        }
         report.apply_exemptions();
+        report.weights = weights;
+
+        // Warn about exemptions referencing unknown checks
+        if let Some(exemptions) = ci.cm_md.as_ref().and_then(|md| md.exemptions.as_ref()) {
+            for exemption in exemptions {
+                if self.registry.get(&exemption.check).is_none() {
+                    report.warnings.push(format!(
+                        "exemption declared for unknown check: {}",
+                        exemption.check
+                    ));
+                }
+            }
+        }
+        report.scorecard_source = repository_input.scorecard.as_ref().ok().map(|s| s.source);
 
         Ok(report)
     }
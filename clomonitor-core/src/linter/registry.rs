@@ -0,0 +1,169 @@
+use super::{check::Check, checks, CheckId};
+use std::{collections::HashMap, ops::Index, sync::Arc};
+
+/// Registry of the checks the linter will run.
+///
+/// The registry returned by `CheckRegistry::builtin` contains all the checks
+/// provided by CLOMonitor. Additional checks can be registered on it to extend
+/// the linter without having to modify this crate.
+#[derive(Clone, Default)]
+pub struct CheckRegistry {
+    checks: Vec<Arc<dyn Check>>,
+    index: HashMap<CheckId, usize>,
+}
+
+impl CheckRegistry {
+    /// Create a new empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new registry with all the builtin checks registered.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        checks::register_builtin(&mut registry);
+        registry
+    }
+
+    /// Register the check provided. If a check with the same identifier was
+    /// already registered, it'll be replaced.
+    pub fn register(&mut self, check: Arc<dyn Check>) {
+        if let Some(&i) = self.index.get(check.id()) {
+            self.checks[i] = check;
+        } else {
+            self.index.insert(check.id(), self.checks.len());
+            self.checks.push(check);
+        }
+    }
+
+    /// Get the check with the identifier provided.
+    #[must_use]
+    pub fn get(&self, check_id: &str) -> Option<&dyn Check> {
+        self.index.get(check_id).map(|&i| self.checks[i].as_ref())
+    }
+
+    /// Return an iterator over the registered checks (in registration order).
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Check> {
+        self.checks.iter().map(AsRef::as_ref)
+    }
+
+    /// Return the number of checks registered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Check if the registry is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
+
+impl Index<&str> for CheckRegistry {
+    type Output = dyn Check;
+
+    fn index(&self, check_id: &str) -> &Self::Output {
+        match self.index.get(check_id) {
+            Some(&i) => self.checks[i].as_ref(),
+            None => panic!("check {check_id} not registered"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linter::{
+        adopters, security_policy, CheckInput, CheckOutput, CheckSet, Section, CHECKS,
+    };
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use serde_json::Value;
+
+    struct TestCheck {
+        id: CheckId,
+        weight: usize,
+    }
+
+    #[async_trait]
+    impl Check for TestCheck {
+        fn id(&self) -> CheckId {
+            self.id
+        }
+
+        fn weight(&self) -> usize {
+            self.weight
+        }
+
+        fn check_sets(&self) -> &[CheckSet] {
+            &[CheckSet::Code]
+        }
+
+        fn section(&self) -> Section {
+            Section::BestPractices
+        }
+
+        async fn run(&self, _input: &CheckInput<'_>) -> Result<CheckOutput<Value>> {
+            Ok(CheckOutput::passed())
+        }
+    }
+
+    #[test]
+    fn builtin_registry_contains_core_checks() {
+        let registry = CheckRegistry::builtin();
+        assert_eq!(registry.len(), CHECKS.len());
+        assert_eq!(registry[adopters::ID].weight(), adopters::WEIGHT);
+        assert_eq!(registry[adopters::ID].section(), Section::Documentation);
+    }
+
+    #[test]
+    fn builtin_checks_provide_remediation() {
+        let registry = CheckRegistry::builtin();
+        for check in registry.iter() {
+            let remediation = check.remediation().unwrap();
+            assert!(!remediation.description.is_empty());
+        }
+        assert_eq!(
+            registry[security_policy::ID]
+                .remediation()
+                .unwrap()
+                .templates[0]
+                .path,
+            "SECURITY.md"
+        );
+    }
+
+    #[test]
+    fn register_new_check() {
+        let mut registry = CheckRegistry::builtin();
+        registry.register(Arc::new(TestCheck {
+            id: "test",
+            weight: 2,
+        }));
+
+        assert_eq!(registry.len(), CHECKS.len() + 1);
+        assert_eq!(registry["test"].weight(), 2);
+        assert_eq!(registry.iter().last().unwrap().id(), "test");
+    }
+
+    #[test]
+    fn register_replaces_existing_check() {
+        let mut registry = CheckRegistry::builtin();
+        registry.register(Arc::new(TestCheck {
+            id: adopters::ID,
+            weight: 7,
+        }));
+
+        assert_eq!(registry.len(), CHECKS.len());
+        assert_eq!(registry[adopters::ID].weight(), 7);
+        assert_eq!(registry[adopters::ID].section(), Section::BestPractices);
+    }
+
+    #[test]
+    fn get_not_registered_check() {
+        assert!(CheckRegistry::new().get("test").is_none());
+    }
+}
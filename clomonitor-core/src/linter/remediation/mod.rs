@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+/// Base url of the checks documentation.
+const DOCS_URL: &str = "https://clomonitor.io/docs/topics/checks/";
+
+/// Guidance on how to get a check to pass.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Remediation {
+    /// Short description of the fix.
+    pub description: String,
+
+    /// Minimal templates of the files that would get the check to pass. They
+    /// are not included in the serialized reports (they can be obtained from
+    /// the check in the registry).
+    #[serde(skip)]
+    pub templates: Vec<FileTemplate>,
+
+    /// Anchor of the check in the checks documentation.
+    pub docs_anchor: String,
+}
+
+impl Remediation {
+    /// Create a new remediation with the description and docs anchor given.
+    pub(crate) fn new(description: &str, docs_anchor: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            templates: Vec::new(),
+            docs_anchor: docs_anchor.to_string(),
+        }
+    }
+
+    /// Add the template of the file located at the path provided (relative
+    /// to the repository root). Only the builtin templates are supported.
+    pub(crate) fn template(mut self, path: &str) -> Self {
+        if let Some(template) = FileTemplate::builtin(path) {
+            self.templates.push(template);
+        }
+        self
+    }
+
+    /// Url of the check in the checks documentation.
+    #[must_use]
+    pub fn docs_url(&self) -> String {
+        format!("{DOCS_URL}#{}", self.docs_anchor)
+    }
+}
+
+/// Template of a file to add to the repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileTemplate {
+    /// Path of the file, relative to the repository root.
+    pub path: String,
+    pub content: String,
+}
+
+impl FileTemplate {
+    /// Get the builtin template for the file path provided (if available).
+    #[must_use]
+    pub fn builtin(path: &str) -> Option<Self> {
+        builtin_template(path).map(|content| Self {
+            path: path.to_string(),
+            content: content.to_string(),
+        })
+    }
+
+    /// Render the template filling in its placeholders with the values
+    /// provided. When the project belongs to a foundation that provides its
+    /// own template for this file, that one is used instead.
+    #[must_use]
+    pub fn render(&self, values: &TemplateValues) -> String {
+        let content = values
+            .foundation
+            .as_deref()
+            .and_then(|foundation| foundation_template(foundation, &self.path))
+            .unwrap_or(&self.content);
+        content
+            .replace("{{ project_name }}", &values.project_name)
+            .replace(
+                "{{ license }}",
+                values.license.as_deref().unwrap_or("<LICENSE>"),
+            )
+    }
+}
+
+/// Values used to fill in the placeholders of the files templates.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateValues {
+    pub project_name: String,
+    /// SPDX identifier of the project's license.
+    pub license: Option<String>,
+    /// Identifier of the foundation the project belongs to (i.e. cncf).
+    pub foundation: Option<String>,
+}
+
+/// Get the content of the builtin template for the file path provided.
+fn builtin_template(path: &str) -> Option<&'static str> {
+    let content = match path {
+        ".clomonitor.yml" => include_str!("templates/clomonitor.yml"),
+        "ADOPTERS.md" => include_str!("templates/ADOPTERS.md"),
+        "CHANGELOG.md" => include_str!("templates/CHANGELOG.md"),
+        "CODE_OF_CONDUCT.md" => include_str!("templates/CODE_OF_CONDUCT.md"),
+        "CONTRIBUTING.md" => include_str!("templates/CONTRIBUTING.md"),
+        "GOVERNANCE.md" => include_str!("templates/GOVERNANCE.md"),
+        "MAINTAINERS.md" => include_str!("templates/MAINTAINERS.md"),
+        "README.md" => include_str!("templates/README.md"),
+        "ROADMAP.md" => include_str!("templates/ROADMAP.md"),
+        "SECURITY.md" => include_str!("templates/SECURITY.md"),
+        ".github/dependabot.yml" => include_str!("templates/dependabot.yml"),
+        _ => return None,
+    };
+    Some(content)
+}
+
+/// Get the content of the template for the file path provided specific to
+/// the foundation given (if available).
+fn foundation_template(foundation: &str, path: &str) -> Option<&'static str> {
+    let content = match (foundation, path) {
+        ("cdf", "CODE_OF_CONDUCT.md") => include_str!("templates/cdf/CODE_OF_CONDUCT.md"),
+        ("cncf", "CODE_OF_CONDUCT.md") => include_str!("templates/cncf/CODE_OF_CONDUCT.md"),
+        _ => return None,
+    };
+    Some(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docs_url_works() {
+        assert_eq!(
+            Remediation::new("Add a security policy", "security-policy").docs_url(),
+            "https://clomonitor.io/docs/topics/checks/#security-policy"
+        );
+    }
+
+    #[test]
+    fn template_builtin() {
+        let remediation =
+            Remediation::new("Add a security policy", "security-policy").template("SECURITY.md");
+
+        assert_eq!(remediation.templates.len(), 1);
+        assert_eq!(remediation.templates[0].path, "SECURITY.md");
+        assert!(remediation.templates[0]
+            .content
+            .starts_with("# Security Policy"));
+    }
+
+    #[test]
+    fn templates_not_serialized() {
+        let remediation =
+            Remediation::new("Add a security policy", "security-policy").template("SECURITY.md");
+
+        assert_eq!(
+            serde_json::to_value(&remediation).unwrap(),
+            serde_json::json!({
+                "description": "Add a security policy",
+                "docs_anchor": "security-policy",
+            })
+        );
+    }
+
+    #[test]
+    fn template_not_builtin() {
+        assert!(Remediation::new("Add a file", "file")
+            .template("UNKNOWN.md")
+            .templates
+            .is_empty());
+    }
+
+    #[test]
+    fn render_fills_in_placeholders() {
+        let template = FileTemplate::builtin("README.md").unwrap();
+
+        let content = template.render(&TemplateValues {
+            project_name: "Test".to_string(),
+            license: Some("Apache-2.0".to_string()),
+            foundation: None,
+        });
+        assert!(content.starts_with("# Test\n"));
+        assert!(content.contains("Test is licensed under the Apache-2.0 license."));
+    }
+
+    #[test]
+    fn render_license_not_available() {
+        let template = FileTemplate::builtin("README.md").unwrap();
+
+        let content = template.render(&TemplateValues {
+            project_name: "Test".to_string(),
+            ..TemplateValues::default()
+        });
+        assert!(content.contains("Test is licensed under the <LICENSE> license."));
+    }
+
+    #[test]
+    fn render_uses_foundation_template() {
+        let template = FileTemplate::builtin("CODE_OF_CONDUCT.md").unwrap();
+
+        let values = |foundation: Option<&str>| TemplateValues {
+            project_name: "Test".to_string(),
+            foundation: foundation.map(ToString::to_string),
+            ..TemplateValues::default()
+        };
+        assert!(template
+            .render(&values(Some("cncf")))
+            .contains("Test follows the [CNCF Code of Conduct]"));
+        assert!(template
+            .render(&values(Some("unknown")))
+            .contains("Test follows the [Contributor Covenant Code of Conduct]"));
+    }
+}
@@ -1,17 +1,149 @@
-use super::{check::CheckId, checks::*, CheckOutput};
+use super::{
+    checks::*, CheckOutput, CheckStatus, Remediation, ResponsivenessMetrics, ScorecardSource,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::BTreeMap, fmt};
+
+/// Type alias to represent the output of the checks registered by third
+/// parties in a report's section, indexed by check identifier.
+pub type CustomChecks = BTreeMap<String, CheckOutput<Value>>;
+
+/// Report sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Section {
+    Documentation,
+    License,
+    BestPractices,
+    Security,
+    Legal,
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = match self {
+            Self::Documentation => "Documentation",
+            Self::License => "License",
+            Self::BestPractices => "Best practices",
+            Self::Security => "Security",
+            Self::Legal => "Legal",
+        };
+        write!(f, "{output}")
+    }
+}
 
 /// Linter report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Report {
     pub documentation: Documentation,
     pub license: License,
     pub best_practices: BestPractices,
     pub security: Security,
     pub legal: Legal,
+
+    /// Source the OpenSSF Scorecard used by some checks was obtained from
+    /// (only set when the scorecard had to be fetched).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scorecard_source: Option<ScorecardSource>,
+
+    /// Check score weight overrides set by the custom check sets used.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub weights: BTreeMap<String, usize>,
+
+    /// Warnings found while linting the repository (i.e. invalid metadata).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+
+    /// Time spent running each check, in milliseconds (check id -> ms). This
+    /// is only used to export metrics, so it's not serialized.
+    #[serde(skip)]
+    pub durations: BTreeMap<String, u64>,
 }
 
 impl Report {
+    /// Set the output of the check provided in the corresponding section.
+    pub(crate) fn set_check_output(
+        &mut self,
+        section: Section,
+        check_id: &str,
+        output: CheckOutput<Value>,
+    ) {
+        match section {
+            Section::Documentation => self.documentation.set(check_id, output),
+            Section::License => self.license.set(check_id, output),
+            Section::BestPractices => self.best_practices.set(check_id, output),
+            Section::Security => self.security.set(check_id, output),
+            Section::Legal => self.legal.set(check_id, output),
+        }
+    }
+
+    /// Return the identifiers of the checks that failed to run.
+    #[must_use]
+    pub fn failed_checks(&self) -> Vec<&str> {
+        let mut checks = self.documentation.failed();
+        checks.extend(self.license.failed());
+        checks.extend(self.best_practices.failed());
+        checks.extend(self.security.failed());
+        checks.extend(self.legal.failed());
+        checks
+    }
+
+    /// Return the status of all the checks in the report, along with the
+    /// section and the identifier of the check they belong to.
+    #[must_use]
+    pub fn check_statuses(&self) -> Vec<(Section, &str, CheckStatus)> {
+        let mut statuses = Vec::new();
+        for (section, section_statuses) in [
+            (Section::Documentation, self.documentation.statuses()),
+            (Section::License, self.license.statuses()),
+            (Section::BestPractices, self.best_practices.statuses()),
+            (Section::Security, self.security.statuses()),
+            (Section::Legal, self.legal.statuses()),
+        ] {
+            statuses.extend(
+                section_statuses
+                    .into_iter()
+                    .map(|(check_id, status)| (section, check_id, status)),
+            );
+        }
+        statuses
+    }
+
+    /// Return the output of all the checks in the report (holding a generic
+    /// json value), along with the section and the identifier of the check
+    /// they belong to.
+    #[must_use]
+    pub fn check_outputs(&self) -> Vec<(Section, &str, CheckOutput<Value>)> {
+        let mut outputs = Vec::new();
+        for (section, section_outputs) in [
+            (Section::Documentation, self.documentation.outputs()),
+            (Section::License, self.license.outputs()),
+            (Section::BestPractices, self.best_practices.outputs()),
+            (Section::Security, self.security.outputs()),
+            (Section::Legal, self.legal.outputs()),
+        ] {
+            outputs.extend(
+                section_outputs
+                    .into_iter()
+                    .map(|(check_id, output)| (section, check_id, output)),
+            );
+        }
+        outputs
+    }
+
+    /// Return the remediations of the checks that didn't pass, along with
+    /// the identifier of the check they belong to.
+    #[must_use]
+    pub fn remediations(&self) -> Vec<(&str, &Remediation)> {
+        let mut remediations = self.documentation.remediations();
+        remediations.extend(self.license.remediations());
+        remediations.extend(self.best_practices.remediations());
+        remediations.extend(self.security.remediations());
+        remediations.extend(self.legal.remediations());
+        remediations
+    }
+
     /// Apply inter-checks exemptions.
     pub(crate) fn apply_exemptions(&mut self) {
         let passed = |o: Option<&CheckOutput>| -> bool {
@@ -52,7 +184,7 @@ impl Report {
 }
 
 /// Documentation section of the report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Documentation {
     pub adopters: Option<CheckOutput>,
     pub changelog: Option<CheckOutput>,
@@ -64,6 +196,9 @@ pub struct Documentation {
     pub roadmap: Option<CheckOutput>,
     pub summary_table: Option<CheckOutput>,
     pub website: Option<CheckOutput>,
+
+    #[serde(flatten)]
+    pub custom: CustomChecks,
 }
 
 #[rustfmt::skip]
@@ -82,11 +217,14 @@ section_impl!(
 );
 
 /// License section of the report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct License {
     pub license_approved: Option<CheckOutput>,
     pub license_scanning: Option<CheckOutput>,
     pub license_spdx_id: Option<CheckOutput<String>>,
+
+    #[serde(flatten)]
+    pub custom: CustomChecks,
 }
 
 #[rustfmt::skip]
@@ -98,17 +236,23 @@ section_impl!(
 );
 
 /// BestPractices section of the report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct BestPractices {
     pub artifacthub_badge: Option<CheckOutput>,
     pub cla: Option<CheckOutput>,
     pub community_meeting: Option<CheckOutput>,
+    pub contributor_diversity: Option<CheckOutput>,
     pub dco: Option<CheckOutput>,
     pub github_discussions: Option<CheckOutput>,
     pub openssf_badge: Option<CheckOutput>,
     pub openssf_scorecard_badge: Option<CheckOutput>,
     pub recent_release: Option<CheckOutput>,
+    pub release_cadence: Option<CheckOutput>,
+    pub responsiveness: Option<CheckOutput<ResponsivenessMetrics>>,
     pub slack_presence: Option<CheckOutput>,
+
+    #[serde(flatten)]
+    pub custom: CustomChecks,
 }
 
 #[rustfmt::skip]
@@ -117,18 +261,22 @@ section_impl!(
     artifacthub_badge,
     cla,
     community_meeting,
+    contributor_diversity,
     dco,
     github_discussions,
     openssf_badge,
     openssf_scorecard_badge,
     recent_release,
+    release_cadence,
+    responsiveness,
     slack_presence
 );
 
 /// Security section of the report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Security {
     pub binary_artifacts: Option<CheckOutput>,
+    pub branch_protection: Option<CheckOutput>,
     pub code_review: Option<CheckOutput>,
     pub dangerous_workflow: Option<CheckOutput>,
     pub dependencies_policy: Option<CheckOutput>,
@@ -139,12 +287,16 @@ pub struct Security {
     pub security_policy: Option<CheckOutput>,
     pub signed_releases: Option<CheckOutput>,
     pub token_permissions: Option<CheckOutput>,
+
+    #[serde(flatten)]
+    pub custom: CustomChecks,
 }
 
 #[rustfmt::skip]
 section_impl!(
     Security,
     binary_artifacts,
+    branch_protection,
     code_review,
     dangerous_workflow,
     dependencies_policy,
@@ -158,9 +310,12 @@ section_impl!(
 );
 
 /// Legal section of the report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Legal {
     pub trademark_disclaimer: Option<CheckOutput>,
+
+    #[serde(flatten)]
+    pub custom: CustomChecks,
 }
 
 #[rustfmt::skip]
@@ -173,25 +328,109 @@ section_impl!(
 macro_rules! section_impl {
     ( $section:ident, $( $check:ident ),* ) => {
         impl $section {
-            pub(crate) fn available(&self) -> Vec<CheckId> {
+            pub(crate) fn available(&self) -> Vec<&str> {
                 let mut checks = Vec::new();
                 $(
-                if self.$check.as_ref().is_some() {
+                if self.$check.as_ref().map_or(false, |o| !o.not_evaluated) {
                     checks.push($check::ID);
                 }
                 )*
+                checks.extend(
+                    self.custom
+                        .iter()
+                        .filter(|(_, o)| !o.not_evaluated)
+                        .map(|(check_id, _)| check_id.as_str()),
+                );
                 checks
             }
 
-            pub(crate) fn passed_or_exempt(&self) -> Vec<CheckId> {
+            pub(crate) fn credits(&self) -> Vec<(&str, f64)> {
+                let mut credits = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref().filter(|o| !o.not_evaluated) {
+                    credits.push(($check::ID, o.credit()));
+                }
+                )*
+                credits.extend(
+                    self.custom
+                        .iter()
+                        .filter(|(_, o)| !o.not_evaluated)
+                        .map(|(check_id, o)| (check_id.as_str(), o.credit())),
+                );
+                credits
+            }
+
+            pub(crate) fn failed(&self) -> Vec<&str> {
                 let mut checks = Vec::new();
                 $(
-                if self.$check.as_ref().map_or(false, |o| o.passed || o.exempt) {
+                if self.$check.as_ref().map_or(false, |o| o.failed) {
                     checks.push($check::ID);
                 }
                 )*
+                checks.extend(
+                    self.custom
+                        .iter()
+                        .filter(|(_, o)| o.failed)
+                        .map(|(check_id, _)| check_id.as_str()),
+                );
                 checks
             }
+
+            pub(crate) fn statuses(&self) -> Vec<(&str, CheckStatus)> {
+                let mut statuses = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref() {
+                    statuses.push(($check::ID, o.status()));
+                }
+                )*
+                statuses.extend(
+                    self.custom
+                        .iter()
+                        .map(|(check_id, o)| (check_id.as_str(), o.status())),
+                );
+                statuses
+            }
+
+            pub(crate) fn outputs(&self) -> Vec<(&str, CheckOutput<Value>)> {
+                let mut outputs = Vec::new();
+                $(
+                if let Some(o) = self.$check.as_ref() {
+                    outputs.push(($check::ID, o.clone().into_json()));
+                }
+                )*
+                outputs.extend(
+                    self.custom
+                        .iter()
+                        .map(|(check_id, o)| (check_id.as_str(), o.clone())),
+                );
+                outputs
+            }
+
+            pub(crate) fn remediations(&self) -> Vec<(&str, &Remediation)> {
+                let mut remediations = Vec::new();
+                $(
+                if let Some(r) = self.$check.as_ref().and_then(|o| o.remediation.as_ref()) {
+                    remediations.push(($check::ID, r));
+                }
+                )*
+                remediations.extend(
+                    self.custom
+                        .iter()
+                        .filter_map(|(check_id, o)| Some((check_id.as_str(), o.remediation.as_ref()?))),
+                );
+                remediations
+            }
+
+            pub(crate) fn set(&mut self, check_id: &str, output: CheckOutput<Value>) {
+                match check_id {
+                    $(
+                    $check::ID => self.$check = Some(output.from_json()),
+                    )*
+                    _ => {
+                        self.custom.insert(check_id.to_string(), output);
+                    }
+                }
+            }
         }
     };
 }
@@ -201,6 +440,165 @@ use section_impl;
 mod tests {
     use super::*;
 
+    #[test]
+    fn set_check_output_builtin_check() {
+        let mut report = Report::default();
+        report.set_check_output(
+            Section::License,
+            license_spdx_id::ID,
+            CheckOutput::passed().value(Some(Value::String("MIT".to_string()))),
+        );
+
+        assert_eq!(
+            report.license.license_spdx_id,
+            Some(CheckOutput::passed().value(Some("MIT".to_string())))
+        );
+        assert!(report.license.custom.is_empty());
+    }
+
+    #[test]
+    fn set_check_output_custom_check() {
+        let mut report = Report::default();
+        report.set_check_output(Section::Security, "custom", CheckOutput::passed());
+
+        assert_eq!(
+            report.security.custom.get("custom"),
+            Some(&CheckOutput::passed())
+        );
+        assert_eq!(report.security.available(), vec!["custom"]);
+        assert_eq!(report.security.credits(), vec![("custom", 1.0)]);
+    }
+
+    #[test]
+    fn not_evaluated_checks_are_not_available() {
+        let report = Report {
+            security: Security {
+                code_review: Some(CheckOutput::not_evaluated()),
+                sbom: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(report.security.available(), vec![sbom::ID]);
+        assert_eq!(report.security.credits(), vec![(sbom::ID, 1.0)]);
+    }
+
+    #[test]
+    fn durations_not_serialized() {
+        let report = Report {
+            durations: BTreeMap::from([(adopters::ID.to_string(), 10)]),
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(&report).unwrap();
+        assert!(value.get("durations").is_none());
+    }
+
+    #[test]
+    fn failed_checks() {
+        let mut report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::failed()),
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report.set_check_output(Section::Legal, "custom", CheckOutput::failed());
+
+        assert_eq!(report.failed_checks(), vec![adopters::ID, "custom"]);
+    }
+
+    #[test]
+    fn check_statuses() {
+        let mut report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed().warning(true)),
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::exempt()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report.set_check_output(Section::Legal, "custom", CheckOutput::failed());
+
+        assert_eq!(
+            report.check_statuses(),
+            vec![
+                (Section::Documentation, adopters::ID, CheckStatus::Warning),
+                (Section::Documentation, readme::ID, CheckStatus::NotPassed),
+                (Section::Security, sbom::ID, CheckStatus::Exempt),
+                (Section::Legal, "custom", CheckStatus::Failed),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_outputs() {
+        let mut report = Report {
+            license: License {
+                license_spdx_id: Some(CheckOutput::passed().value(Some("MIT".to_string()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report.set_check_output(Section::Legal, "custom", CheckOutput::not_passed());
+
+        assert_eq!(
+            report.check_outputs(),
+            vec![
+                (
+                    Section::License,
+                    license_spdx_id::ID,
+                    CheckOutput::passed().value(Some(Value::String("MIT".to_string())))
+                ),
+                (Section::Legal, "custom", CheckOutput::not_passed()),
+            ]
+        );
+    }
+
+    #[test]
+    fn remediations() {
+        let remediation = Remediation::new("Add a SECURITY.md file", "security-policy");
+        let mut report = Report {
+            security: Security {
+                security_policy: Some(
+                    CheckOutput::not_passed().remediation(Some(remediation.clone())),
+                ),
+                sbom: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report.set_check_output(
+            Section::Legal,
+            "custom",
+            CheckOutput::not_passed().remediation(Some(remediation.clone())),
+        );
+
+        assert_eq!(
+            report.remediations(),
+            vec![
+                (security_policy::ID, &remediation),
+                ("custom", &remediation)
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_checks_are_serialized_inline() {
+        let mut report = Report::default();
+        report.set_check_output(Section::Legal, "custom", CheckOutput::passed());
+
+        let data = serde_json::to_value(&report).unwrap();
+        assert_eq!(data["legal"]["custom"]["passed"], Value::Bool(true));
+        assert_eq!(serde_json::from_value::<Report>(data).unwrap(), report);
+    }
+
     #[test]
     fn apply_exemptions_cla_passed() {
         let mut report = Report {
@@ -1,5 +1,6 @@
 use crate::linter::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Score information.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
@@ -55,27 +56,48 @@ impl Score {
 /// Calculate score for the given linter report.
 #[must_use]
 pub fn calculate(report: &Report) -> Score {
+    calculate_with_registry(report, &CHECKS)
+}
+
+/// Calculate score for the given linter report, using the checks registry
+/// provided to get the weight of the checks. Checks not found in the registry
+/// are not taken into account. Weight overrides recorded in the report (set by
+/// custom check sets) take precedence over the registry weights.
+#[must_use]
+pub fn calculate_with_registry(report: &Report, registry: &CheckRegistry) -> Score {
     let mut score = Score::default();
 
     // Sections
     (score.documentation, score.documentation_weight) = calculate_section(
+        registry,
+        &report.weights,
         &report.documentation.available(),
-        &report.documentation.passed_or_exempt(),
+        &report.documentation.credits(),
     );
     (score.license, score.license_weight) = calculate_section(
+        registry,
+        &report.weights,
         &report.license.available(),
-        &report.license.passed_or_exempt(),
+        &report.license.credits(),
     );
     (score.best_practices, score.best_practices_weight) = calculate_section(
+        registry,
+        &report.weights,
         &report.best_practices.available(),
-        &report.best_practices.passed_or_exempt(),
+        &report.best_practices.credits(),
     );
     (score.security, score.security_weight) = calculate_section(
+        registry,
+        &report.weights,
         &report.security.available(),
-        &report.security.passed_or_exempt(),
+        &report.security.credits(),
+    );
+    (score.legal, score.legal_weight) = calculate_section(
+        registry,
+        &report.weights,
+        &report.legal.available(),
+        &report.legal.credits(),
     );
-    (score.legal, score.legal_weight) =
-        calculate_section(&report.legal.available(), &report.legal.passed_or_exempt());
 
     // Global
     let sections_scores = &[
@@ -107,22 +129,31 @@ pub fn calculate(report: &Report) -> Score {
 }
 
 /// Calculate score and weight for a report's section from the checks provided.
+/// Each check contributes to the score the fraction of its weight it has been
+/// credited with.
 fn calculate_section(
-    checks_available: &[CheckId],
-    checks_passed_or_exempt: &[CheckId],
+    registry: &CheckRegistry,
+    weights: &BTreeMap<String, usize>,
+    checks_available: &[&str],
+    checks_credits: &[(&str, f64)],
 ) -> (Option<f64>, Option<usize>) {
+    let check_weight = |check_id: &&str| match registry.get(check_id) {
+        Some(check) => weights.get(*check_id).copied().unwrap_or(check.weight()),
+        None => 0,
+    };
+
     // Calculate section weight
-    let weight = checks_available
-        .iter()
-        .fold(0, |weight, check_id| weight + CHECKS[check_id].weight);
+    let weight = checks_available.iter().map(check_weight).sum::<usize>();
     if weight == 0 {
         return (None, None);
     }
 
     // Calculate section score
-    let score = checks_passed_or_exempt.iter().fold(0.0, |score, check_id| {
-        score + CHECKS[check_id].weight as f64 / weight as f64 * 100.0
-    });
+    let score = checks_credits
+        .iter()
+        .fold(0.0, |score, (check_id, credit)| {
+            score + check_weight(check_id) as f64 * credit / weight as f64 * 100.0
+        });
 
     (Some(score), Some(weight))
 }
@@ -261,6 +292,7 @@ mod tests {
                     roadmap: Some(CheckOutput::passed()),
                     summary_table: Some(CheckOutput::passed()),
                     website: Some(CheckOutput::passed()),
+                    ..Default::default()
                 },
                 license: License {
                     license_approved: Some(CheckOutput::passed()),
@@ -270,6 +302,7 @@ mod tests {
                     license_spdx_id: Some(
                         CheckOutput::passed().value(Some("Apache-2.0".to_string()))
                     ),
+                    ..Default::default()
                 },
                 best_practices: BestPractices {
                     artifacthub_badge: Some(CheckOutput::exempt()),
@@ -281,6 +314,7 @@ mod tests {
                     openssf_scorecard_badge: Some(CheckOutput::passed()),
                     recent_release: Some(CheckOutput::passed()),
                     slack_presence: Some(CheckOutput::passed()),
+                    ..Default::default()
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::passed()),
@@ -294,10 +328,16 @@ mod tests {
                     security_policy: Some(CheckOutput::passed()),
                     signed_releases: Some(CheckOutput::passed()),
                     token_permissions: Some(CheckOutput::passed()),
+                    ..Default::default()
                 },
                 legal: Legal {
                     trademark_disclaimer: Some(CheckOutput::passed()),
+                    ..Default::default()
                 },
+                scorecard_source: None,
+                weights: BTreeMap::new(),
+                warnings: vec![],
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 100.0,
@@ -331,11 +371,13 @@ mod tests {
                     roadmap: Some(CheckOutput::not_passed()),
                     summary_table: Some(CheckOutput::not_passed()),
                     website: Some(CheckOutput::not_passed()),
+                    ..Default::default()
                 },
                 license: License {
                     license_approved: Some(CheckOutput::not_passed()),
                     license_scanning: Some(CheckOutput::not_passed()),
                     license_spdx_id: Some(CheckOutput::not_passed()),
+                    ..Default::default()
                 },
                 best_practices: BestPractices {
                     artifacthub_badge: Some(CheckOutput::not_passed()),
@@ -347,6 +389,7 @@ mod tests {
                     openssf_scorecard_badge: Some(CheckOutput::not_passed()),
                     recent_release: Some(CheckOutput::not_passed()),
                     slack_presence: Some(CheckOutput::not_passed()),
+                    ..Default::default()
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::not_passed()),
@@ -360,10 +403,16 @@ mod tests {
                     security_policy: Some(CheckOutput::not_passed()),
                     signed_releases: Some(CheckOutput::not_passed()),
                     token_permissions: Some(CheckOutput::not_passed()),
+                    ..Default::default()
                 },
                 legal: Legal {
                     trademark_disclaimer: Some(CheckOutput::not_passed()),
+                    ..Default::default()
                 },
+                scorecard_source: None,
+                weights: BTreeMap::new(),
+                warnings: vec![],
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 0.0,
@@ -397,6 +446,7 @@ mod tests {
                     roadmap: None,
                     summary_table: None,
                     website: None,
+                    ..Default::default()
                 },
                 license: License {
                     license_approved: Some(CheckOutput::passed()),
@@ -406,6 +456,7 @@ mod tests {
                     license_spdx_id: Some(
                         CheckOutput::passed().value(Some("Apache-2.0".to_string()))
                     ),
+                    ..Default::default()
                 },
                 best_practices: BestPractices {
                     artifacthub_badge: Some(CheckOutput::exempt()),
@@ -417,6 +468,7 @@ mod tests {
                     openssf_scorecard_badge: Some(CheckOutput::passed()),
                     recent_release: Some(CheckOutput::passed()),
                     slack_presence: None,
+                    ..Default::default()
                 },
                 security: Security {
                     binary_artifacts: Some(CheckOutput::passed()),
@@ -430,10 +482,16 @@ mod tests {
                     security_insights: Some(CheckOutput::passed()),
                     signed_releases: Some(CheckOutput::passed()),
                     token_permissions: Some(CheckOutput::passed()),
+                    ..Default::default()
                 },
                 legal: Legal {
                     trademark_disclaimer: None,
+                    ..Default::default()
                 },
+                scorecard_source: None,
+                weights: BTreeMap::new(),
+                warnings: vec![],
+                durations: BTreeMap::new(),
             }),
             Score {
                 global: 100.0,
@@ -452,6 +510,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn calculate_report_ignores_checks_not_registered() {
+        let mut report = Report {
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report
+            .legal
+            .custom
+            .insert("not_registered".to_string(), CheckOutput::not_passed());
+
+        assert_eq!(
+            calculate(&report),
+            Score {
+                global: 100.0,
+                global_weight: 5,
+                legal: Some(100.0),
+                legal_weight: Some(5),
+                ..Score::default()
+            }
+        );
+    }
+
+    #[test]
+    fn calculate_report_applies_weight_overrides() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            weights: BTreeMap::from([(adopters::ID.to_string(), 30)]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate(&report),
+            Score {
+                global: 75.0,
+                global_weight: 40,
+                documentation: Some(75.0),
+                documentation_weight: Some(40),
+                ..Score::default()
+            }
+        );
+    }
+
+    #[test]
+    fn calculate_report_honours_partial_credit() {
+        let report = Report {
+            best_practices: BestPractices {
+                openssf_badge: Some(CheckOutput::passed()),
+                recent_release: Some(CheckOutput::passed().score(Some(0.5)).warning(true)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate(&report),
+            Score {
+                global: 81.25,
+                global_weight: 8,
+                best_practices: Some(81.25),
+                best_practices_weight: Some(8),
+                ..Score::default()
+            }
+        );
+    }
+
     #[test]
     fn merge_scores() {
         assert_eq!(
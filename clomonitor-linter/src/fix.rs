@@ -0,0 +1,241 @@
+use anyhow::Result;
+use clomonitor_core::linter::{Check, CheckRegistry, FileTemplate, Report, TemplateValues};
+use similar::TextDiff;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+/// CLOMonitor metadata file name.
+pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
+
+/// Scaffold the files missing in the repository located at the path provided
+/// using the templates of the checks that didn't pass in the report given
+/// (as defined in the registry provided).
+/// When any check didn't pass, the CLOMonitor metadata file is scaffolded as
+/// well, so that exemptions can be declared. Existing files are never
+/// overwritten. In dry run mode, the changes are printed as a unified diff
+/// instead of being applied.
+pub(crate) fn scaffold(
+    report: &Report,
+    registry: &CheckRegistry,
+    values: &TemplateValues,
+    root: &Path,
+    dry_run: bool,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    // Collect the templates of the files to scaffold
+    let remediations = report.remediations();
+    let mut templates: Vec<FileTemplate> = Vec::new();
+    for (check_id, _) in &remediations {
+        let Some(remediation) = registry.get(check_id).and_then(Check::remediation) else {
+            continue;
+        };
+        for template in remediation.templates {
+            if !templates.iter().any(|t| t.path == template.path) {
+                templates.push(template);
+            }
+        }
+    }
+    if !remediations.is_empty() {
+        templates.extend(FileTemplate::builtin(METADATA_FILE));
+    }
+
+    // Scaffold the files that don't exist yet
+    let mut changes = 0;
+    for template in &templates {
+        let path = root.join(&template.path);
+        let content = template.render(values);
+        if dry_run {
+            if path.symlink_metadata().is_ok() {
+                writeln!(w, "Skipped {} (file already exists)", template.path)?;
+                continue;
+            }
+            let diff = TextDiff::from_lines("", &content)
+                .unified_diff()
+                .header("/dev/null", &format!("b/{}", template.path))
+                .to_string();
+            write!(w, "{diff}")?;
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            // The file is created only if nothing exists at its path (not
+            // even a dangling symlink), without checking it beforehand
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => file.write_all(content.as_bytes())?,
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    writeln!(w, "Skipped {} (file already exists)", template.path)?;
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            }
+            writeln!(w, "Created {}", template.path)?;
+        }
+        changes += 1;
+    }
+    if changes == 0 {
+        writeln!(w, "Nothing to fix")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{
+        CheckOutput, CoreLinter, Documentation, Remediation, Report, Security,
+    };
+
+    fn remediation() -> Remediation {
+        Remediation {
+            description: "Add a file".to_string(),
+            templates: vec![],
+            docs_anchor: "anchor".to_string(),
+        }
+    }
+
+    fn values() -> TemplateValues {
+        TemplateValues {
+            project_name: "Test".to_string(),
+            license: Some("Apache-2.0".to_string()),
+            foundation: Some("cncf".to_string()),
+        }
+    }
+
+    fn report() -> Report {
+        Report {
+            documentation: Documentation {
+                code_of_conduct: Some(CheckOutput::not_passed().remediation(Some(remediation()))),
+                maintainers: Some(CheckOutput::not_passed().remediation(Some(remediation()))),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed().remediation(Some(remediation()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scaffold_creates_missing_files() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("MAINTAINERS.md"), "existing").unwrap();
+
+        let mut w = Vec::new();
+        scaffold(
+            &report(),
+            CoreLinter::new().registry(),
+            &values(),
+            root.path(),
+            false,
+            &mut w,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(w).unwrap(),
+            "Created CODE_OF_CONDUCT.md
+Skipped MAINTAINERS.md (file already exists)
+Created SECURITY.md
+Created .clomonitor.yml
+"
+        );
+        let code_of_conduct = fs::read_to_string(root.path().join("CODE_OF_CONDUCT.md")).unwrap();
+        assert!(code_of_conduct.contains("Test follows the [CNCF Code of Conduct]"));
+        assert_eq!(
+            fs::read_to_string(root.path().join("MAINTAINERS.md")).unwrap(),
+            "existing"
+        );
+        assert!(root.path().join(METADATA_FILE).exists());
+    }
+
+    #[test]
+    fn scaffold_dry_run_prints_diff() {
+        let root = tempfile::tempdir().unwrap();
+        let report = Report {
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed().remediation(Some(remediation()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        fs::write(root.path().join(METADATA_FILE), "").unwrap();
+
+        let mut w = Vec::new();
+        scaffold(
+            &report,
+            CoreLinter::new().registry(),
+            &values(),
+            root.path(),
+            true,
+            &mut w,
+        )
+        .unwrap();
+
+        let output = String::from_utf8(w).unwrap();
+        assert!(output.starts_with(
+            "--- /dev/null\n+++ b/SECURITY.md\n@@ -0,0 +1,10 @@\n+# Security Policy\n"
+        ));
+        assert!(output.contains("+Please do not report security vulnerabilities in Test through\n"));
+        assert!(output.ends_with("Skipped .clomonitor.yml (file already exists)\n"));
+        assert!(!root.path().join("SECURITY.md").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scaffold_skips_dangling_symlinks() {
+        let root = tempfile::tempdir().unwrap();
+        let target = root.path().join("outside.md");
+        std::os::unix::fs::symlink(&target, root.path().join("SECURITY.md")).unwrap();
+        fs::write(root.path().join(METADATA_FILE), "").unwrap();
+        let report = Report {
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed().remediation(Some(remediation()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut w = Vec::new();
+        scaffold(
+            &report,
+            CoreLinter::new().registry(),
+            &values(),
+            root.path(),
+            false,
+            &mut w,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(w).unwrap(),
+            "Skipped SECURITY.md (file already exists)
+Skipped .clomonitor.yml (file already exists)
+Nothing to fix
+"
+        );
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn scaffold_nothing_to_fix() {
+        let root = tempfile::tempdir().unwrap();
+
+        let mut w = Vec::new();
+        scaffold(
+            &Report::default(),
+            CoreLinter::new().registry(),
+            &values(),
+            root.path(),
+            false,
+            &mut w,
+        )
+        .unwrap();
+
+        assert_eq!(String::from_utf8(w).unwrap(), "Nothing to fix\n");
+    }
+}
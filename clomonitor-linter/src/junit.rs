@@ -0,0 +1,211 @@
+use anyhow::Result;
+use clomonitor_core::linter::{Check, CheckOutput, CheckRegistry, CheckStatus, Report, Section};
+use serde_json::Value;
+use std::{fmt::Write as _, io};
+
+/// Sections of the report, in the order they are displayed.
+const SECTIONS: [Section; 5] = [
+    Section::Documentation,
+    Section::License,
+    Section::BestPractices,
+    Section::Security,
+    Section::Legal,
+];
+
+/// Display linter results in JUnit XML format. Each section of the report is
+/// displayed as a test suite, with a test case per check. Exempt checks, as
+/// well as the ones not applicable or not evaluated, are marked as skipped.
+pub(crate) fn display(
+    report: &Report,
+    registry: &CheckRegistry,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    let outputs = report.check_outputs();
+    let (mut tests, mut failures, mut errors, mut skipped) = (0, 0, 0, 0);
+    let mut suites = String::new();
+
+    for section in SECTIONS {
+        // Checks in the section: builtin ones first, custom ones after
+        let mut checks: Vec<&str> = registry
+            .iter()
+            .filter(|check| check.section() == section)
+            .map(Check::id)
+            .collect();
+        checks.extend(
+            outputs
+                .iter()
+                .filter(|(s, check_id, _)| *s == section && registry.get(check_id).is_none())
+                .map(|(_, check_id, _)| *check_id),
+        );
+
+        // Test cases
+        let (mut suite_failures, mut suite_errors, mut suite_skipped) = (0, 0, 0);
+        let mut cases = String::new();
+        for check_id in &checks {
+            let output = outputs
+                .iter()
+                .find(|(_, id, _)| id == check_id)
+                .map(|(_, _, output)| output);
+            let result = match output.map(CheckOutput::status) {
+                Some(CheckStatus::Passed | CheckStatus::Warning) => String::new(),
+                Some(CheckStatus::NotPassed) => {
+                    suite_failures += 1;
+                    let message = details(output).unwrap_or("Check did not pass");
+                    format!(r#"<failure message="{}"/>"#, escape(message))
+                }
+                Some(CheckStatus::Failed) => {
+                    suite_errors += 1;
+                    let message = details(output).unwrap_or("Check failed to run");
+                    format!(r#"<error message="{}"/>"#, escape(message))
+                }
+                Some(CheckStatus::Exempt) => {
+                    suite_skipped += 1;
+                    let message = match output.and_then(|o| o.exemption_reason.as_deref()) {
+                        Some(reason) => format!("Exempt: {reason}"),
+                        None => "Exempt".to_string(),
+                    };
+                    format!(r#"<skipped message="{}"/>"#, escape(&message))
+                }
+                Some(CheckStatus::NotEvaluated) => {
+                    suite_skipped += 1;
+                    r#"<skipped message="Not evaluated"/>"#.to_string()
+                }
+                None => {
+                    suite_skipped += 1;
+                    r#"<skipped message="Not applicable"/>"#.to_string()
+                }
+            };
+            let classname = section_id(section);
+            if result.is_empty() {
+                writeln!(
+                    cases,
+                    r#"    <testcase name="{}" classname="{classname}"/>"#,
+                    escape(check_id)
+                )?;
+            } else {
+                writeln!(
+                    cases,
+                    r#"    <testcase name="{}" classname="{classname}">"#,
+                    escape(check_id)
+                )?;
+                writeln!(cases, "      {result}")?;
+                writeln!(cases, "    </testcase>")?;
+            }
+        }
+
+        writeln!(
+            suites,
+            r#"  <testsuite name="{section}" tests="{}" failures="{suite_failures}" errors="{suite_errors}" skipped="{suite_skipped}">"#,
+            checks.len()
+        )?;
+        suites.push_str(&cases);
+        writeln!(suites, "  </testsuite>")?;
+
+        tests += checks.len();
+        failures += suite_failures;
+        errors += suite_errors;
+        skipped += suite_skipped;
+    }
+
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        w,
+        r#"<testsuites name="CLOMonitor linter" tests="{tests}" failures="{failures}" errors="{errors}" skipped="{skipped}">"#
+    )?;
+    write!(w, "{suites}")?;
+    writeln!(w, "</testsuites>")?;
+    Ok(())
+}
+
+/// Get the details of the check output provided (if any).
+fn details(output: Option<&CheckOutput<Value>>) -> Option<&str> {
+    output.and_then(|o| o.details.as_deref())
+}
+
+/// Get the identifier of the section provided (i.e. best_practices).
+fn section_id(section: Section) -> String {
+    serde_json::to_value(section)
+        .ok()
+        .and_then(|v| v.as_str().map(ToString::to_string))
+        .unwrap_or_default()
+}
+
+/// Escape the text provided so that it can be used in XML attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{BestPractices, CoreLinter, Documentation, Security};
+    use std::fs;
+
+    #[test]
+    fn display_prints_results() {
+        let mut report = Report {
+            documentation: Documentation {
+                adopters: Some(
+                    CheckOutput::exempt()
+                        .exemption_reason(Some("Not a \"real\" project".to_string())),
+                ),
+                code_of_conduct: Some(CheckOutput::passed()),
+                contributing: Some(CheckOutput::passed().warning(true)),
+                readme: Some(
+                    CheckOutput::not_passed()
+                        .details(Some("README <empty>\nPlease fix".to_string())),
+                ),
+                roadmap: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                openssf_badge: Some(CheckOutput::not_evaluated()),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::failed().details(Some("Rate limited".to_string()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report
+            .legal
+            .custom
+            .insert("custom".to_string(), CheckOutput::passed());
+
+        // Display linter results using a vector as output
+        let linter = CoreLinter::new();
+        let mut w = Vec::new();
+        display(&report, linter.registry(), &mut w).unwrap();
+
+        let golden_path = "src/testdata/display.golden.xml";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = String::from_utf8(w).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+
+    #[test]
+    fn escape_works() {
+        assert_eq!(
+            escape("a < b & \"c\"\nd"),
+            "a &lt; b &amp; &quot;c&quot;&#10;d"
+        );
+    }
+}
@@ -2,23 +2,40 @@
 #![allow(clippy::doc_markdown, clippy::wildcard_imports)]
 
 use anyhow::{format_err, Result};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use clomonitor_core::{
-    linter::{CheckSet, CoreLinter, Linter, LinterInput},
-    score,
+    diff,
+    linter::{
+        Check, CheckSet, CoreLinter, CustomCheckSet, Forge, Linter, LinterInput,
+        ReleaseCadenceThresholds, Report, ResponsivenessThresholds, TemplateValues,
+    },
+    score::{self, Score},
 };
-use serde_json::json;
-use std::{env, io, path::PathBuf};
+use serde_json::{json, Value};
+use std::{env, fs, io, path::PathBuf};
 
+mod fix;
+mod junit;
+mod markdown;
+mod sarif;
 mod table;
 
 /// Environment variable containing Github token.
 const GITHUB_TOKEN: &str = "GITHUB_TOKEN";
 
+/// Environment variable containing GitLab token (optional).
+const GITLAB_TOKEN: &str = "GITLAB_TOKEN";
+
+/// Environment variable containing Gitea (or Forgejo) token (optional).
+const GITEA_TOKEN: &str = "GITEA_TOKEN";
+
 /// CLI output format options.
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Format {
     Json,
+    Junit,
+    Markdown,
+    Sarif,
     Table,
 }
 
@@ -26,20 +43,125 @@ pub enum Format {
 #[clap(
     author,
     version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true,
     about = "Checks repository to verify it meets certain project health best practices
 
 The CLOMonitor linter runs some checks on the repository provided and produces
 a report with the result. Some of the checks are done locally using the path
-provided and some remotely as they rely on external APIs. Only GitHub repos
-are supported at the moment. For more information about the checks, please see
+provided and some remotely as they rely on external APIs. GitHub, GitLab and
+Gitea/Forgejo repos are supported. The forge is detected from the repository
+url, but it can also be set explicitly (useful for self-hosted instances). For
+more information about the checks, please see
 https://clomonitor.io/docs/topics/checks/. The exit code will be 0 if the
 linter runs successfully and the score is equal or higher than the pass score
 provided, or non-zero otherwise.
 
 This tool uses the Github GraphQL API for some checks, which requires
 authentication. Please make sure you provide a Github token (with public_repo
-scope) by setting the GITHUB_TOKEN environment variable."
+scope) by setting the GITHUB_TOKEN environment variable. Repositories hosted on
+GitLab or Gitea/Forgejo are accessed anonymously, unless a token is provided by
+setting the GITLAB_TOKEN or GITEA_TOKEN environment variables.
+
+When running in offline mode, only the local path provided is used. Checks
+that rely on remote data are reported as not evaluated and are not taken into
+account when calculating the score. A GitHub token is not required in this
+mode.
+
+Some security checks rely on OpenSSF Scorecard. Precomputed results can be
+provided using a JSON file or a Scorecard API endpoint. When they are not
+provided, or the API has no results for the repository, the scorecard CLI
+will be run. Scorecard checks pass when their
+score is equal or higher than 5 (1 for signed_releases) by default, but these
+thresholds can be adjusted per check. OpenSSF Scorecard is only available for
+repositories hosted on GitHub, so these checks are reported as not evaluated
+for other forges.
+
+When a repository contains several components, one of them can be linted by
+providing its path relative to the repository root. Checks are run on the
+component's path first, falling back to the repository root when they don't
+pass there (i.e. for shared files like LICENSE).
+
+Custom check sets can be defined in a YAML file containing a list of entries
+with the check set name, the checks included and, optionally, check weight
+overrides. They can then be used by name in the check set argument.
+
+The fix subcommand scaffolds the files missing in the repository to get the
+checks that don't pass to pass (i.e. SECURITY.md or CODE_OF_CONDUCT.md), using
+the project name and license to fill in the templates. Existing files are
+never overwritten.
+
+The diff subcommand compares the results of two linter runs (produced using the
+json format), displaying the checks whose status changed, the score deltas per
+section and the exemptions added or removed.
+
+When a baseline is provided (results of a previous linter run produced using
+the json format), only the regressions are displayed: checks that passed in the
+baseline and don't pass anymore (including those not evaluated or not run,
+i.e. when using a different check set or the offline mode), and the global
+score when it dropped more than the tolerance set. In this mode the exit code
+will be non-zero only when regressions are found, and the pass score is ignored.
+
+The sarif format reports each check that didn't pass (or failed to run) as a
+SARIF result, so that the linter results can be uploaded to code scanning
+dashboards. The severity of the results is derived from the weight of the
+checks.
+
+The junit format displays a test case per check (exempt and not applicable
+checks are skipped), and the markdown format uses the same layout as the
+CLOMonitor repository report, which is handy for CI job summaries."
 )]
+struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    #[clap(flatten)]
+    args: Option<Args>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Scaffold the files missing to get the checks that don't pass to pass
+    Fix(Box<FixArgs>),
+
+    /// Compare the results of two linter runs
+    Diff(DiffArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct FixArgs {
+    #[clap(flatten)]
+    args: Args,
+
+    /// Print the changes as a unified diff instead of applying them
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Project name used in the templates (defaults to the repository name)
+    #[clap(long)]
+    project_name: Option<String>,
+
+    /// Foundation the project belongs to, used to pick its own templates when available [cdf, cncf]
+    #[clap(long)]
+    foundation: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+struct DiffArgs {
+    /// Base linter results JSON file (linter output using the json format)
+    #[clap(long)]
+    base: PathBuf,
+
+    /// Head linter results JSON file (linter output using the json format)
+    #[clap(long)]
+    head: PathBuf,
+
+    /// Output format
+    #[clap(value_enum, long, default_value = "table")]
+    format: Format,
+}
+
+#[derive(Debug, clap::Args)]
 struct Args {
     /// Repository local path (used for checks that can be done locally)
     #[clap(long)]
@@ -49,43 +171,92 @@ struct Args {
     #[clap(long)]
     url: String,
 
-    /// Sets of checks to run
-    #[clap(value_enum, long, default_values = &["code", "community"])]
+    /// Component path, relative to the repository path, to lint instead of the whole repository
+    #[clap(long)]
+    subpath: Option<PathBuf>,
+
+    /// Sets of checks to run [code, code-lite, community, docs or a custom check set]
+    #[clap(long, default_values = &["code", "community"])]
     check_set: Vec<CheckSet>,
 
+    /// Custom check sets definitions YAML file
+    #[clap(long)]
+    check_sets_file: Option<PathBuf>,
+
     /// Linter pass score
     #[clap(long, default_value = "75")]
     pass_score: f64,
 
+    /// Previous linter results JSON file to compare against, failing only on regressions
+    #[clap(long)]
+    baseline: Option<PathBuf>,
+
+    /// Global score drop allowed when comparing against a baseline
+    #[clap(long, default_value = "0", value_parser = parse_baseline_tolerance)]
+    baseline_tolerance: f64,
+
     /// Output format
     #[clap(value_enum, long, default_value = "table")]
     format: Format,
+
+    /// Run only the checks that can be done locally (no remote APIs are used)
+    #[clap(long)]
+    offline: bool,
+
+    /// Forge hosting the repository (detected from the url when not provided)
+    #[clap(value_enum, long)]
+    forge: Option<Forge>,
+
+    /// Precomputed OpenSSF Scorecard results JSON file
+    #[clap(long)]
+    scorecard_json: Option<PathBuf>,
+
+    /// OpenSSF Scorecard API endpoint [https://api.securityscorecards.dev]
+    #[clap(long)]
+    scorecard_api_url: Option<String>,
+
+    /// OpenSSF Scorecard check pass threshold [check_id=score] (can be repeated)
+    #[clap(long, value_parser = parse_scorecard_threshold)]
+    scorecard_threshold: Vec<(String, f64)>,
+
+    /// Maximum median time to the first maintainer response (in hours) in the responsiveness check
+    #[clap(long, default_value = "168")]
+    max_median_response_hours: i64,
+
+    /// Maximum ratio of stale open issues and pull requests in the responsiveness check
+    #[clap(long, default_value = "0.25")]
+    max_stale_ratio: f64,
+
+    /// Maximum interval between releases (in days) in the release cadence check
+    #[clap(long, default_value = "180")]
+    max_release_interval_days: i64,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
+    let cli = Cli::parse();
 
-    // Check if required Github token is present in environment
-    let github_token = match env::var(GITHUB_TOKEN) {
-        Err(_) => return Err(format_err!("{} not found in environment", GITHUB_TOKEN)),
-        Ok(token) => token,
-    };
+    match (&cli.command, &cli.args) {
+        (Some(Command::Fix(fix_args)), _) => fix(fix_args).await,
+        (Some(Command::Diff(diff_args)), _) => diff(diff_args),
+        (None, Some(args)) => lint(args).await,
+        (None, None) => unreachable!("lint arguments are required when no command is provided"),
+    }
+}
 
-    // Lint repository provided
-    let input = LinterInput {
-        project: None,
-        root: args.path.clone(),
-        url: args.url.clone(),
-        check_sets: args.check_set.clone(),
-        github_token,
-    };
-    let report = CoreLinter::new().lint(&input).await?;
+/// Lint the repository and display the results.
+async fn lint(args: &Args) -> Result<()> {
+    let report = run_linter(args).await?;
     let score = score::calculate(&report);
 
+    // When a baseline is provided, only regressions are taken into account
+    if let Some(baseline) = &args.baseline {
+        return check_regressions(baseline, &report, &score, args);
+    }
+
     // Display results using the requested format
     match args.format {
-        Format::Table => table::display(&report, &score, &args, &mut io::stdout())?,
+        Format::Table => table::display(&report, &score, args, &mut io::stdout())?,
         Format::Json => {
             let output = json!({
                 "report": report,
@@ -93,6 +264,20 @@ async fn main() -> Result<()> {
             });
             println!("{output}");
         }
+        Format::Junit => {
+            junit::display(&report, CoreLinter::new().registry(), &mut io::stdout())?;
+        }
+        Format::Markdown => markdown::display(
+            &repository_name(&args.url),
+            &args.url,
+            &args.check_set,
+            &report,
+            &score,
+            &mut io::stdout(),
+        )?,
+        Format::Sarif => {
+            sarif::display(&report, CoreLinter::new().registry(), &mut io::stdout())?;
+        }
     }
 
     // Check if the linter succeeded according to the provided pass score
@@ -101,3 +286,172 @@ async fn main() -> Result<()> {
     }
     Ok(())
 }
+
+/// Compare the report and score provided against the baseline ones,
+/// displaying the regressions found (if any).
+fn check_regressions(
+    baseline: &PathBuf,
+    report: &Report,
+    score: &Score,
+    args: &Args,
+) -> Result<()> {
+    let (base_report, base_score) = read_results(baseline)?;
+    let regressions =
+        diff::diff(&base_report, &base_score, report, score).regressions(args.baseline_tolerance);
+
+    // Display regressions using the requested format
+    match args.format {
+        Format::Table => table::display_regressions(&regressions, &mut io::stdout())?,
+        Format::Json => println!("{}", json!(regressions)),
+        Format::Junit | Format::Markdown | Format::Sarif => {
+            return Err(format_err!(
+                "only the json and table formats are supported in baseline mode"
+            ))
+        }
+    }
+
+    // Check if the linter succeeded (no regressions found)
+    if !regressions.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Lint the repository and scaffold the files missing to get the checks that
+/// didn't pass to pass.
+async fn fix(fix_args: &FixArgs) -> Result<()> {
+    let args = &fix_args.args;
+    let report = run_linter(args).await?;
+
+    let values = TemplateValues {
+        project_name: fix_args
+            .project_name
+            .clone()
+            .unwrap_or_else(|| repository_name(&args.url)),
+        license: report
+            .license
+            .license_spdx_id
+            .as_ref()
+            .and_then(|output| output.value.clone()),
+        foundation: fix_args.foundation.clone(),
+    };
+    let root = match &args.subpath {
+        Some(subpath) => args.path.join(subpath),
+        None => args.path.clone(),
+    };
+    fix::scaffold(
+        &report,
+        CoreLinter::new().registry(),
+        &values,
+        &root,
+        fix_args.dry_run,
+        &mut io::stdout(),
+    )
+}
+
+/// Compare the results of two linter runs and display the differences.
+fn diff(diff_args: &DiffArgs) -> Result<()> {
+    let (base_report, base_score) = read_results(&diff_args.base)?;
+    let (head_report, head_score) = read_results(&diff_args.head)?;
+    let diff = diff::diff(&base_report, &base_score, &head_report, &head_score);
+
+    // Display differences using the requested format
+    match diff_args.format {
+        Format::Table => table::display_diff(&diff, &mut io::stdout())?,
+        Format::Json => println!("{}", json!(diff)),
+        Format::Junit | Format::Markdown | Format::Sarif => {
+            return Err(format_err!(
+                "only the json and table formats are supported by the diff command"
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Read the linter results (report and score) from the JSON file provided.
+fn read_results(path: &PathBuf) -> Result<(Report, Score)> {
+    let data = fs::read_to_string(path)
+        .map_err(|err| format_err!("error reading {}: {err}", path.display()))?;
+    let mut results: Value = serde_json::from_str(&data)?;
+    let report = serde_json::from_value(results["report"].take())?;
+    let score = serde_json::from_value(results["score"].take())?;
+    Ok((report, score))
+}
+
+/// Run the linter on the repository provided returning the report produced.
+async fn run_linter(args: &Args) -> Result<Report> {
+    // Check if required Github token is present in environment
+    let github_token = match env::var(GITHUB_TOKEN) {
+        Ok(token) => token,
+        Err(_) if args.offline => String::new(),
+        Err(_) => return Err(format_err!("{} not found in environment", GITHUB_TOKEN)),
+    };
+
+    // Load custom check sets definitions (if provided)
+    let custom_check_sets = match &args.check_sets_file {
+        Some(path) => CustomCheckSet::from_file(path)?,
+        None => vec![],
+    };
+
+    // Lint repository provided
+    let input = LinterInput {
+        project: None,
+        root: args.path.clone(),
+        subpath: args.subpath.clone(),
+        url: args.url.clone(),
+        check_sets: args.check_set.clone(),
+        custom_check_sets,
+        github_token,
+        gitlab_token: env::var(GITLAB_TOKEN).ok(),
+        gitea_token: env::var(GITEA_TOKEN).ok(),
+        offline: args.offline,
+        forge: args.forge,
+        scorecard_json: args.scorecard_json.clone(),
+        scorecard_api_url: args.scorecard_api_url.clone(),
+        scorecard_thresholds: args.scorecard_threshold.iter().cloned().collect(),
+        responsiveness_thresholds: ResponsivenessThresholds {
+            max_median_response_hours: args.max_median_response_hours,
+            max_stale_ratio: args.max_stale_ratio,
+        },
+        release_cadence_thresholds: ReleaseCadenceThresholds {
+            max_interval_days: args.max_release_interval_days,
+        },
+    };
+    CoreLinter::new().lint(&input).await
+}
+
+/// Get the repository name from the repository url provided.
+fn repository_name(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    url.rsplit('/').next().unwrap_or(url).to_string()
+}
+
+/// Parse the global score drop allowed when comparing against a baseline.
+fn parse_baseline_tolerance(s: &str) -> Result<f64> {
+    let tolerance: f64 = s.parse()?;
+    if !(0.0..=100.0).contains(&tolerance) {
+        return Err(format_err!("tolerance must be between 0 and 100"));
+    }
+    Ok(tolerance)
+}
+
+/// Parse a scorecard check pass threshold provided as check_id=score.
+fn parse_scorecard_threshold(s: &str) -> Result<(String, f64)> {
+    let (check_id, score) = s
+        .split_once('=')
+        .ok_or_else(|| format_err!("expected format: check_id=score"))?;
+    let linter = CoreLinter::new();
+    if linter
+        .registry()
+        .get(check_id)
+        .and_then(Check::scorecard_name)
+        .is_none()
+    {
+        return Err(format_err!("{check_id} is not an OpenSSF Scorecard check"));
+    }
+    let score: f64 = score.parse()?;
+    if !(0.0..=10.0).contains(&score) {
+        return Err(format_err!("score must be between 0 and 10"));
+    }
+    Ok((check_id.to_string(), score))
+}
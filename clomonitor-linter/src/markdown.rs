@@ -0,0 +1,102 @@
+use anyhow::Result;
+use askama::Template;
+use clomonitor_core::{
+    linter::{CheckSet, Report},
+    score::Score,
+};
+use std::io;
+
+/// Template for the repository report in markdown format (same layout used
+/// by the apiserver).
+#[derive(Debug, Clone, Template)]
+#[template(path = "repository-report.md")]
+struct RepositoryReportMDTemplate<'a> {
+    name: &'a str,
+    url: &'a str,
+    check_sets: &'a [CheckSet],
+    score: Option<&'a Score>,
+    report: Option<&'a Report>,
+}
+
+/// Display linter results in markdown format.
+pub(crate) fn display(
+    name: &str,
+    url: &str,
+    check_sets: &[CheckSet],
+    report: &Report,
+    score: &Score,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    let template = RepositoryReportMDTemplate {
+        name,
+        url,
+        check_sets,
+        score: Some(score),
+        report: Some(report),
+    };
+    writeln!(w, "{}", template.render()?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{CheckOutput, Documentation, Evidence, License, Security};
+    use std::fs;
+
+    #[test]
+    fn display_prints_results() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::exempt()),
+                readme: Some(CheckOutput::passed().evidence(Some(Evidence {
+                    file: "README.md".to_string(),
+                    ..Default::default()
+                }))),
+                ..Default::default()
+            },
+            license: License {
+                license_spdx_id: Some(CheckOutput::passed().value(Some("Apache-2.0".to_string()))),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score {
+            global: 66.666_666_666_666_67,
+            global_weight: 20,
+            documentation: Some(100.0),
+            documentation_weight: Some(10),
+            license: Some(100.0),
+            license_weight: Some(5),
+            security: Some(0.0),
+            security_weight: Some(5),
+            ..Score::default()
+        };
+
+        // Display linter results using a vector as output
+        let mut w = Vec::new();
+        display(
+            "test-repo",
+            "https://github.com/test-org/test-repo",
+            &[CheckSet::Code, CheckSet::Community],
+            &report,
+            &score,
+            &mut w,
+        )
+        .unwrap();
+
+        let golden_path = "src/testdata/display.golden.md";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = String::from_utf8(w).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::fix::METADATA_FILE;
+use anyhow::Result;
+use clomonitor_core::linter::{Check, CheckRegistry, CheckStatus, Report};
+use serde_json::{json, Value};
+use std::io;
+
+/// SARIF schema location.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF version used.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// CLOMonitor website url.
+const CLOMONITOR_URL: &str = "https://clomonitor.io";
+
+/// Minimum check weight for a failed check to be reported as an error.
+const ERROR_LEVEL_WEIGHT: usize = 5;
+
+/// Minimum check weight for a failed check to be reported as a warning.
+const WARNING_LEVEL_WEIGHT: usize = 2;
+
+/// Display linter results in SARIF format. Each check that didn't pass (or
+/// failed to run) is reported as a result of the rule corresponding to the
+/// check.
+pub(crate) fn display(
+    report: &Report,
+    registry: &CheckRegistry,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    writeln!(w, "{}", sarif_log(report, registry))?;
+    Ok(())
+}
+
+/// Build a SARIF log from the linter report provided.
+fn sarif_log(report: &Report, registry: &CheckRegistry) -> Value {
+    let mut rules = Vec::new();
+    let mut results = Vec::new();
+
+    for (section, check_id, output) in report.check_outputs() {
+        let status = output.status();
+        if !matches!(status, CheckStatus::NotPassed | CheckStatus::Failed) {
+            continue;
+        }
+
+        // Rule metadata (from the check documentation when available)
+        let remediation = output
+            .remediation
+            .clone()
+            .or_else(|| registry.get(check_id).and_then(Check::remediation));
+        let weight = report
+            .weights
+            .get(check_id)
+            .copied()
+            .or_else(|| registry.get(check_id).map(Check::weight))
+            .unwrap_or_default();
+        let level = level(weight);
+        let mut rule = json!({
+            "id": check_id,
+            "name": check_id,
+            "defaultConfiguration": {
+                "level": level,
+            },
+            "properties": {
+                "section": section,
+                "weight": weight,
+            },
+        });
+        if let Some(remediation) = &remediation {
+            rule["shortDescription"] = json!({ "text": remediation.description });
+            rule["help"] = json!({ "text": remediation.description });
+            rule["helpUri"] = json!(remediation.docs_url());
+        }
+
+        // Result
+        let message = if status == CheckStatus::Failed {
+            match &output.fail_reason {
+                Some(reason) => format!("Check {check_id} failed to run: {reason}"),
+                None => format!("Check {check_id} failed to run"),
+            }
+        } else {
+            output
+                .details
+                .clone()
+                .or_else(|| remediation.map(|remediation| remediation.description))
+                .unwrap_or_else(|| format!("Check {check_id} did not pass"))
+        };
+        let mut result = json!({
+            "ruleId": check_id,
+            "ruleIndex": rules.len(),
+            "level": level,
+            "message": {
+                "text": message,
+            },
+        });
+
+        // Location (results must have one to be displayed by some consumers,
+        // so when there is no evidence the file that would get the check to
+        // pass is used, falling back to the CLOMonitor metadata file)
+        let uri = output.evidence.as_ref().map_or_else(
+            || {
+                registry
+                    .get(check_id)
+                    .and_then(Check::remediation)
+                    .and_then(|remediation| remediation.templates.into_iter().next())
+                    .map_or_else(|| METADATA_FILE.to_string(), |template| template.path)
+            },
+            |evidence| evidence.file.clone(),
+        );
+        let mut physical_location = json!({
+            "artifactLocation": {
+                "uri": uri,
+            },
+        });
+        if let Some(line) = output.evidence.as_ref().and_then(|evidence| evidence.line) {
+            physical_location["region"] = json!({ "startLine": line });
+        }
+        result["locations"] = json!([{ "physicalLocation": physical_location }]);
+
+        rules.push(rule);
+        results.push(result);
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clomonitor-linter",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": CLOMONITOR_URL,
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Get the SARIF level of a failed check from its weight.
+fn level(weight: usize) -> &'static str {
+    if weight >= ERROR_LEVEL_WEIGHT {
+        "error"
+    } else if weight >= WARNING_LEVEL_WEIGHT {
+        "warning"
+    } else {
+        "note"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clomonitor_core::linter::{
+        CheckOutput, CoreLinter, Documentation, Evidence, Remediation, Security,
+    };
+
+    #[test]
+    fn sarif_log_reports_checks_not_passed_or_failed() {
+        let mut report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                maintainers: Some(CheckOutput::exempt()),
+                readme: Some(
+                    CheckOutput::not_passed()
+                        .details(Some("README file is empty".to_string()))
+                        .evidence(Some(Evidence {
+                            file: "README.md".to_string(),
+                            line: Some(1),
+                            pattern: None,
+                        })),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed().remediation(Some(Remediation {
+                    description: "Add a SECURITY.md file".to_string(),
+                    templates: vec![],
+                    docs_anchor: "security-policy".to_string(),
+                }))),
+                sbom: Some(CheckOutput::failed().fail_reason(Some("timeout".to_string()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        report.weights.insert("custom".to_string(), 1);
+        report
+            .legal
+            .custom
+            .insert("custom".to_string(), CheckOutput::not_passed());
+
+        let linter = CoreLinter::new();
+        let log = sarif_log(&report, linter.registry());
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "clomonitor-linter");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][2],
+            json!({
+                "id": "security_policy",
+                "name": "security_policy",
+                "shortDescription": { "text": "Add a SECURITY.md file" },
+                "help": { "text": "Add a SECURITY.md file" },
+                "helpUri": "https://clomonitor.io/docs/topics/checks/#security-policy",
+                "defaultConfiguration": { "level": "warning" },
+                "properties": { "section": "security", "weight": 3 },
+            })
+        );
+        assert_eq!(
+            run["results"],
+            json!([
+                {
+                    "ruleId": "readme",
+                    "ruleIndex": 0,
+                    "level": "error",
+                    "message": { "text": "README file is empty" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "README.md" },
+                            "region": { "startLine": 1 },
+                        },
+                    }],
+                },
+                {
+                    "ruleId": "sbom",
+                    "ruleIndex": 1,
+                    "level": "note",
+                    "message": { "text": "Check sbom failed to run: timeout" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": ".clomonitor.yml" },
+                        },
+                    }],
+                },
+                {
+                    "ruleId": "security_policy",
+                    "ruleIndex": 2,
+                    "level": "warning",
+                    "message": { "text": "Add a SECURITY.md file" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "SECURITY.md" },
+                        },
+                    }],
+                },
+                {
+                    "ruleId": "custom",
+                    "ruleIndex": 3,
+                    "level": "note",
+                    "message": { "text": "Check custom did not pass" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": ".clomonitor.yml" },
+                        },
+                    }],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn level_from_weight() {
+        assert_eq!(level(10), "error");
+        assert_eq!(level(5), "error");
+        assert_eq!(level(3), "warning");
+        assert_eq!(level(1), "note");
+        assert_eq!(level(0), "note");
+    }
+}
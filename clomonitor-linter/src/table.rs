@@ -1,7 +1,8 @@
 use crate::Args;
 use anyhow::Result;
 use clomonitor_core::{
-    linter::{CheckOutput, Report},
+    diff::{CheckChange, Diff, Regressions, ScoreChange},
+    linter::{CheckOutput, CheckStatus, Evidence, Report},
     score::Score,
 };
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table, *};
@@ -12,6 +13,7 @@ const FAILURE_SYMBOL: char = '✗';
 const WARNING_SYMBOL: char = '!';
 const NOT_APPLICABLE_MSG: &str = "n/a";
 const EXEMPT_MSG: &str = "Exempt";
+const NOT_EVALUATED_MSG: &str = "Not evaluated";
 
 /// Print the linter results provided.
 #[allow(clippy::too_many_lines)]
@@ -39,6 +41,21 @@ pub(crate) fn display(
             cell_entry("Check sets"),
             cell_entry(&format!("{:?}", args.check_set)),
         ]);
+    if let Some(subpath) = &args.subpath {
+        repo_info.add_row(vec![
+            cell_entry("Subpath"),
+            cell_entry(&subpath.to_string_lossy()),
+        ]);
+    }
+    if args.offline {
+        repo_info.add_row(vec![cell_entry("Mode"), cell_entry("Offline")]);
+    }
+    if let Some(source) = report.scorecard_source {
+        repo_info.add_row(vec![
+            cell_entry("Scorecard source"),
+            cell_entry(&source.to_string()),
+        ]);
+    }
     writeln!(w, "{repo_info}\n")?;
 
     // Summary table
@@ -68,46 +85,60 @@ pub(crate) fn display(
     checks_summary
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_header(vec![cell_header("Check"), cell_header("Passed")])
+        .set_header(vec![
+            cell_header("Check"),
+            cell_header("Passed"),
+            cell_header("Evidence"),
+        ])
         .add_row(vec![
             cell_entry("Documentation / Adopters"),
-            cell_check(&report.documentation.adopters),
+            cell_check(report.documentation.adopters.as_ref()),
+            cell_evidence(report.documentation.adopters.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Changelog"),
-            cell_check(&report.documentation.changelog),
+            cell_check(report.documentation.changelog.as_ref()),
+            cell_evidence(report.documentation.changelog.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Code of conduct"),
-            cell_check(&report.documentation.code_of_conduct),
+            cell_check(report.documentation.code_of_conduct.as_ref()),
+            cell_evidence(report.documentation.code_of_conduct.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Contributing"),
-            cell_check(&report.documentation.contributing),
+            cell_check(report.documentation.contributing.as_ref()),
+            cell_evidence(report.documentation.contributing.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Governance"),
-            cell_check(&report.documentation.governance),
+            cell_check(report.documentation.governance.as_ref()),
+            cell_evidence(report.documentation.governance.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Maintainers"),
-            cell_check(&report.documentation.maintainers),
+            cell_check(report.documentation.maintainers.as_ref()),
+            cell_evidence(report.documentation.maintainers.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Readme"),
-            cell_check(&report.documentation.readme),
+            cell_check(report.documentation.readme.as_ref()),
+            cell_evidence(report.documentation.readme.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Roadmap"),
-            cell_check(&report.documentation.roadmap),
+            cell_check(report.documentation.roadmap.as_ref()),
+            cell_evidence(report.documentation.roadmap.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Summary table"),
-            cell_check(&report.documentation.summary_table),
+            cell_check(report.documentation.summary_table.as_ref()),
+            cell_evidence(report.documentation.summary_table.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Documentation / Website"),
-            cell_check(&report.documentation.website),
+            cell_check(report.documentation.website.as_ref()),
+            cell_evidence(report.documentation.website.as_ref()),
         ])
         .add_row(vec![
             cell_entry("License"),
@@ -120,101 +151,187 @@ pub(crate) fn display(
             })
             .set_alignment(CellAlignment::Center)
             .add_attribute(Attribute::Bold),
+            cell_evidence(report.license.license_spdx_id.as_ref()),
         ])
         .add_row(vec![
             cell_entry("License / Approved"),
-            cell_check(&report.license.license_approved),
+            cell_check(report.license.license_approved.as_ref()),
+            cell_evidence(report.license.license_approved.as_ref()),
         ])
         .add_row(vec![
             cell_entry("License / Scanning"),
-            cell_check(&report.license.license_scanning),
+            cell_check(report.license.license_scanning.as_ref()),
+            cell_evidence(report.license.license_scanning.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / Artifact Hub badge"),
-            cell_check(&report.best_practices.artifacthub_badge),
+            cell_check(report.best_practices.artifacthub_badge.as_ref()),
+            cell_evidence(report.best_practices.artifacthub_badge.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / CLA"),
-            cell_check(&report.best_practices.cla),
+            cell_check(report.best_practices.cla.as_ref()),
+            cell_evidence(report.best_practices.cla.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / Community meeting"),
-            cell_check(&report.best_practices.community_meeting),
+            cell_check(report.best_practices.community_meeting.as_ref()),
+            cell_evidence(report.best_practices.community_meeting.as_ref()),
+        ])
+        .add_row(vec![
+            cell_entry("Best practices / Contributor diversity"),
+            cell_check(report.best_practices.contributor_diversity.as_ref()),
+            cell_evidence(report.best_practices.contributor_diversity.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / DCO"),
-            cell_check(&report.best_practices.dco),
+            cell_check(report.best_practices.dco.as_ref()),
+            cell_evidence(report.best_practices.dco.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / GitHub discussions"),
-            cell_check(&report.best_practices.github_discussions),
+            cell_check(report.best_practices.github_discussions.as_ref()),
+            cell_evidence(report.best_practices.github_discussions.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / OpenSSF best practices badge"),
-            cell_check(&report.best_practices.openssf_badge),
+            cell_check(report.best_practices.openssf_badge.as_ref()),
+            cell_evidence(report.best_practices.openssf_badge.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / OpenSSF Scorecard badge"),
-            cell_check(&report.best_practices.openssf_scorecard_badge),
+            cell_check(report.best_practices.openssf_scorecard_badge.as_ref()),
+            cell_evidence(report.best_practices.openssf_scorecard_badge.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / Recent release"),
-            cell_check(&report.best_practices.recent_release),
+            cell_check(report.best_practices.recent_release.as_ref()),
+            cell_evidence(report.best_practices.recent_release.as_ref()),
+        ])
+        .add_row(vec![
+            cell_entry("Best practices / Release cadence"),
+            cell_check(report.best_practices.release_cadence.as_ref()),
+            cell_evidence(report.best_practices.release_cadence.as_ref()),
+        ])
+        .add_row(vec![
+            cell_entry("Best practices / Responsiveness"),
+            cell_check(report.best_practices.responsiveness.as_ref()),
+            cell_evidence(report.best_practices.responsiveness.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Best practices / Slack presence"),
-            cell_check(&report.best_practices.slack_presence),
+            cell_check(report.best_practices.slack_presence.as_ref()),
+            cell_evidence(report.best_practices.slack_presence.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Binary artifacts"),
-            cell_check(&report.security.binary_artifacts),
+            cell_check(report.security.binary_artifacts.as_ref()),
+            cell_evidence(report.security.binary_artifacts.as_ref()),
+        ])
+        .add_row(vec![
+            cell_entry("Security / Branch protection"),
+            cell_check(report.security.branch_protection.as_ref()),
+            cell_evidence(report.security.branch_protection.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Code review"),
-            cell_check(&report.security.code_review),
+            cell_check(report.security.code_review.as_ref()),
+            cell_evidence(report.security.code_review.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Dangerous workflow"),
-            cell_check(&report.security.dangerous_workflow),
+            cell_check(report.security.dangerous_workflow.as_ref()),
+            cell_evidence(report.security.dangerous_workflow.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Dependencies policy"),
-            cell_check(&report.security.dependencies_policy),
+            cell_check(report.security.dependencies_policy.as_ref()),
+            cell_evidence(report.security.dependencies_policy.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Dependency update tool"),
-            cell_check(&report.security.dependency_update_tool),
+            cell_check(report.security.dependency_update_tool.as_ref()),
+            cell_evidence(report.security.dependency_update_tool.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Maintained"),
-            cell_check(&report.security.maintained),
+            cell_check(report.security.maintained.as_ref()),
+            cell_evidence(report.security.maintained.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / SBOM"),
-            cell_check(&report.security.sbom),
+            cell_check(report.security.sbom.as_ref()),
+            cell_evidence(report.security.sbom.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Security insights"),
-            cell_check(&report.security.security_insights),
+            cell_check(report.security.security_insights.as_ref()),
+            cell_evidence(report.security.security_insights.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Security policy"),
-            cell_check(&report.security.security_policy),
+            cell_check(report.security.security_policy.as_ref()),
+            cell_evidence(report.security.security_policy.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Signed release"),
-            cell_check(&report.security.signed_releases),
+            cell_check(report.security.signed_releases.as_ref()),
+            cell_evidence(report.security.signed_releases.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Security / Token permissions"),
-            cell_check(&report.security.token_permissions),
+            cell_check(report.security.token_permissions.as_ref()),
+            cell_evidence(report.security.token_permissions.as_ref()),
         ])
         .add_row(vec![
             cell_entry("Legal / Trademark disclaimer"),
-            cell_check(&report.legal.trademark_disclaimer),
+            cell_check(report.legal.trademark_disclaimer.as_ref()),
+            cell_evidence(report.legal.trademark_disclaimer.as_ref()),
         ]);
+    for (section, custom_checks) in [
+        ("Documentation", &report.documentation.custom),
+        ("License", &report.license.custom),
+        ("Best practices", &report.best_practices.custom),
+        ("Security", &report.security.custom),
+        ("Legal", &report.legal.custom),
+    ] {
+        for (check_id, output) in custom_checks {
+            checks_summary.add_row(vec![
+                cell_entry(&format!("{section} / {check_id}")),
+                cell_check(Some(output)),
+                cell_evidence(Some(output)),
+            ]);
+        }
+    }
     writeln!(w, "{checks_summary}\n")?;
 
+    // Remediation guidance for the checks that didn't pass
+    let remediations = report.remediations();
+    if !remediations.is_empty() {
+        writeln!(w, "How to fix\n")?;
+        for (check_id, remediation) in remediations {
+            writeln!(
+                w,
+                "{FAILURE_SYMBOL} {check_id}: {}",
+                remediation.description
+            )?;
+            for template in &remediation.templates {
+                writeln!(w, "    template available: {}", template.path)?;
+            }
+            writeln!(w, "    docs: {}", remediation.docs_url())?;
+        }
+        writeln!(w)?;
+    }
+
+    // Warnings
+    if !report.warnings.is_empty() {
+        writeln!(w, "Warnings\n")?;
+        for warning in &report.warnings {
+            writeln!(w, "{WARNING_SYMBOL} {warning}")?;
+        }
+        writeln!(w)?;
+    }
+
     // Check if the linter succeeded according to the provided pass score
     if score.global() >= args.pass_score {
         writeln!(
@@ -234,6 +351,104 @@ pub(crate) fn display(
     Ok(())
 }
 
+/// Print the differences between two linter runs provided.
+pub(crate) fn display_diff(diff: &Diff, w: &mut impl io::Write) -> Result<()> {
+    writeln!(w, "\nCLOMonitor linter results diff\n")?;
+
+    // Score changes table
+    writeln!(w, "Score changes\n")?;
+    let mut score_changes = new_table();
+    score_changes
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Section"),
+            cell_header("Base"),
+            cell_header("Head"),
+            cell_header("Delta"),
+        ]);
+    for (section, change) in [
+        ("Global", &diff.score.global),
+        ("Documentation", &diff.score.documentation),
+        ("License", &diff.score.license),
+        ("Best practices", &diff.score.best_practices),
+        ("Security", &diff.score.security),
+        ("Legal", &diff.score.legal),
+    ] {
+        score_changes.add_row(vec![
+            cell_entry(section),
+            cell_score(change.base),
+            cell_score(change.head),
+            cell_delta(change),
+        ]);
+    }
+    writeln!(w, "{score_changes}\n")?;
+
+    // Checks changes table
+    writeln!(w, "Checks changes\n")?;
+    if diff.checks.is_empty() {
+        writeln!(w, "No checks changed\n")?;
+    } else {
+        writeln!(w, "{}\n", checks_changes_table(&diff.checks))?;
+    }
+
+    // Exemptions changes
+    for (title, exemptions) in [
+        ("Exemptions added", &diff.exemptions_added),
+        ("Exemptions removed", &diff.exemptions_removed),
+    ] {
+        if !exemptions.is_empty() {
+            writeln!(w, "{title}: {}\n", exemptions.join(", "))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Display the regressions found compared to a baseline in table format.
+pub(crate) fn display_regressions(regressions: &Regressions, w: &mut impl io::Write) -> Result<()> {
+    writeln!(w, "\nCLOMonitor linter regressions\n")?;
+
+    if regressions.is_empty() {
+        writeln!(w, "No regressions found\n")?;
+        return Ok(());
+    }
+    if let Some(change) = &regressions.score {
+        writeln!(
+            w,
+            "Global score dropped from {:.0} to {:.0}\n",
+            change.base.unwrap_or_default(),
+            change.head.unwrap_or_default()
+        )?;
+    }
+    if !regressions.checks.is_empty() {
+        writeln!(w, "{}\n", checks_changes_table(&regressions.checks))?;
+    }
+
+    Ok(())
+}
+
+/// Build a table with the checks changes provided.
+fn checks_changes_table(changes: &[CheckChange]) -> Table {
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Check"),
+            cell_header("Base"),
+            cell_header("Head"),
+        ]);
+    for change in changes {
+        table.add_row(vec![
+            cell_entry(&format!("{} / {}", change.section, change.check_id)),
+            cell_status(change.base),
+            cell_status(change.head),
+        ]);
+    }
+    table
+}
+
 /// Helper function to create a new table that will be forced to use a non-tty
 /// mode when running tests.
 #[allow(clippy::let_and_return, unused_mut)]
@@ -277,10 +492,44 @@ fn cell_score(score: Option<f64>) -> Cell {
         .fg(color)
 }
 
+/// Build a cell used for scores deltas.
+fn cell_delta(change: &ScoreChange) -> Cell {
+    let (content, color) = match change.delta.map(f64::round) {
+        Some(delta) if delta > 0.0 => (format!("{delta:+}"), Color::Green),
+        Some(delta) if delta < 0.0 => (format!("{delta:+}"), Color::Red),
+        Some(_) => ("0".to_string(), Color::Grey),
+        None => (NOT_APPLICABLE_MSG.to_string(), Color::Grey),
+    };
+    Cell::new(content)
+        .set_alignment(CellAlignment::Center)
+        .add_attribute(Attribute::Bold)
+        .fg(color)
+}
+
+/// Build a cell used for checks status.
+fn cell_status(status: Option<CheckStatus>) -> Cell {
+    let (content, color) = match status {
+        Some(CheckStatus::Passed) => (SUCCESS_SYMBOL.to_string(), Color::Green),
+        Some(CheckStatus::Warning | CheckStatus::Failed) => {
+            (WARNING_SYMBOL.to_string(), Color::Yellow)
+        }
+        Some(CheckStatus::NotPassed) => (FAILURE_SYMBOL.to_string(), Color::Red),
+        Some(CheckStatus::Exempt) => (EXEMPT_MSG.to_string(), Color::Grey),
+        Some(CheckStatus::NotEvaluated) => (NOT_EVALUATED_MSG.to_string(), Color::Grey),
+        None => (NOT_APPLICABLE_MSG.to_string(), Color::Grey),
+    };
+    Cell::new(content)
+        .set_alignment(CellAlignment::Center)
+        .add_attribute(Attribute::Bold)
+        .fg(color)
+}
+
 /// Build a cell used for checks output.
-fn cell_check<T>(output: &Option<CheckOutput<T>>) -> Cell {
+fn cell_check<T>(output: Option<&CheckOutput<T>>) -> Cell {
     let (content, color) = match output {
+        Some(r) if r.not_evaluated => (NOT_EVALUATED_MSG.to_string(), Color::Grey),
         Some(r) => match (r.passed, r.exempt, r.failed) {
+            (true, _, _) if r.warning => (WARNING_SYMBOL.to_string(), Color::Yellow),
             (true, _, _) => (SUCCESS_SYMBOL.to_string(), Color::Green),
             (false, true, _) => (EXEMPT_MSG.to_string(), Color::Grey),
             (false, _, false) => (FAILURE_SYMBOL.to_string(), Color::Red),
@@ -294,33 +543,67 @@ fn cell_check<T>(output: &Option<CheckOutput<T>>) -> Cell {
         .fg(color)
 }
 
+/// Build a cell used for checks evidence.
+fn cell_evidence<T>(output: Option<&CheckOutput<T>>) -> Cell {
+    let content = match output.and_then(|r| r.evidence.as_ref()) {
+        Some(Evidence {
+            file,
+            line,
+            pattern,
+        }) => {
+            let location = match line {
+                Some(line) => format!("{file}:{line}"),
+                None => file.clone(),
+            };
+            match pattern {
+                Some(pattern) => format!("{location}\n{pattern}"),
+                None => location,
+            }
+        }
+        None => String::new(),
+    };
+    Cell::new(content).set_alignment(CellAlignment::Left)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::display;
+    use super::{display, display_diff, display_regressions};
     use crate::{Args, Format};
     use clomonitor_core::{
+        diff::{CheckChange, Diff, Regressions, ScoreChange, ScoreDiff},
         linter::{
-            BestPractices, CheckOutput, CheckSet, Documentation, Legal, License, Report, Security,
+            BestPractices, CheckOutput, CheckSet, CheckStatus, Documentation, Evidence,
+            FileTemplate, Legal, License, Remediation, Report, Section, Security,
         },
         score::Score,
     };
-    use std::{fs, path::PathBuf, str, str::FromStr};
+    use std::{collections::BTreeMap, fs, path::PathBuf, str, str::FromStr};
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn display_prints_results() {
         // Setup test linter results
         let report = Report {
             documentation: Documentation {
-                adopters: Some(CheckOutput::passed()),
+                adopters: Some(CheckOutput::passed().evidence(Some(Evidence {
+                    file: "README.md".to_string(),
+                    line: Some(7),
+                    pattern: Some(r"(?im)^#+.*adopters.*$".to_string()),
+                }))),
                 code_of_conduct: Some(CheckOutput::passed()),
                 contributing: Some(CheckOutput::passed()),
                 changelog: Some(CheckOutput::passed()),
-                governance: Some(CheckOutput::passed()),
+                governance: Some(CheckOutput::passed().evidence(Some(Evidence {
+                    file: "GOVERNANCE.md".to_string(),
+                    line: None,
+                    pattern: Some("governance*".to_string()),
+                }))),
                 maintainers: Some(CheckOutput::passed()),
                 readme: Some(CheckOutput::passed()),
                 roadmap: Some(CheckOutput::passed()),
                 summary_table: Some(CheckOutput::passed()),
                 website: Some(CheckOutput::passed()),
+                ..Default::default()
             },
             license: License {
                 license_approved: Some(CheckOutput::passed()),
@@ -328,20 +611,26 @@ mod tests {
                     CheckOutput::passed().url(Some("https://license-scanning.url".to_string())),
                 ),
                 license_spdx_id: Some(CheckOutput::passed().value(Some("Apache-2.0".to_string()))),
+                ..Default::default()
             },
             best_practices: BestPractices {
                 artifacthub_badge: Some(CheckOutput::exempt()),
                 cla: Some(CheckOutput::passed()),
                 community_meeting: Some(CheckOutput::passed()),
+                contributor_diversity: Some(CheckOutput::passed()),
                 dco: Some(CheckOutput::passed()),
                 github_discussions: Some(CheckOutput::passed()),
                 openssf_badge: Some(CheckOutput::passed()),
                 openssf_scorecard_badge: Some(CheckOutput::passed()),
                 recent_release: Some(CheckOutput::passed()),
+                release_cadence: Some(CheckOutput::passed()),
+                responsiveness: Some(CheckOutput::passed()),
                 slack_presence: Some(CheckOutput::passed()),
+                ..Default::default()
             },
             security: Security {
                 binary_artifacts: Some(CheckOutput::passed()),
+                branch_protection: Some(CheckOutput::passed()),
                 code_review: Some(CheckOutput::passed()),
                 dangerous_workflow: Some(CheckOutput::passed()),
                 dependencies_policy: Some(CheckOutput::passed()),
@@ -349,13 +638,26 @@ mod tests {
                 maintained: Some(CheckOutput::passed()),
                 sbom: Some(CheckOutput::passed()),
                 security_insights: Some(CheckOutput::passed()),
-                security_policy: Some(CheckOutput::passed()),
+                security_policy: Some(CheckOutput::not_passed().remediation(Some(Remediation {
+                    description: "Add a SECURITY.md file".to_string(),
+                    templates: vec![FileTemplate {
+                        path: "SECURITY.md".to_string(),
+                        content: "# Security Policy".to_string(),
+                    }],
+                    docs_anchor: "security-policy".to_string(),
+                }))),
                 signed_releases: Some(CheckOutput::passed()),
                 token_permissions: Some(CheckOutput::passed()),
+                ..Default::default()
             },
             legal: Legal {
                 trademark_disclaimer: Some(CheckOutput::passed()),
+                ..Default::default()
             },
+            scorecard_source: None,
+            weights: BTreeMap::new(),
+            warnings: vec![],
+            durations: BTreeMap::new(),
         };
         let score = Score {
             global: 99.999_999_999_999_99,
@@ -374,9 +676,21 @@ mod tests {
         let args = Args {
             path: PathBuf::from_str("test-repo-path").unwrap(),
             url: "https://github.com/test-org/test-repo".to_string(),
+            subpath: None,
             check_set: vec![CheckSet::Code, CheckSet::Community],
+            check_sets_file: None,
             pass_score: 80.0,
+            baseline: None,
+            baseline_tolerance: 0.0,
             format: Format::Table,
+            offline: false,
+            forge: None,
+            scorecard_json: None,
+            scorecard_api_url: None,
+            scorecard_threshold: vec![],
+            max_median_response_hours: 168,
+            max_stale_ratio: 0.25,
+            max_release_interval_days: 180,
         };
 
         // Display linter results using a vector as output
@@ -393,4 +707,100 @@ mod tests {
         let golden = fs::read_to_string(golden_path).unwrap();
         assert_eq!(output, golden);
     }
+
+    #[test]
+    fn display_diff_prints_differences() {
+        let diff = Diff {
+            checks: vec![
+                CheckChange {
+                    check_id: "adopters".to_string(),
+                    section: Section::Documentation,
+                    base: Some(CheckStatus::Exempt),
+                    head: Some(CheckStatus::NotPassed),
+                },
+                CheckChange {
+                    check_id: "sbom".to_string(),
+                    section: Section::Security,
+                    base: None,
+                    head: Some(CheckStatus::Passed),
+                },
+            ],
+            score: ScoreDiff {
+                global: ScoreChange {
+                    base: Some(40.0),
+                    head: Some(55.5),
+                    delta: Some(15.5),
+                },
+                documentation: ScoreChange {
+                    base: Some(50.0),
+                    head: Some(40.0),
+                    delta: Some(-10.0),
+                },
+                security: ScoreChange {
+                    base: None,
+                    head: Some(100.0),
+                    delta: None,
+                },
+                ..ScoreDiff::default()
+            },
+            exemptions_added: vec![],
+            exemptions_removed: vec!["adopters".to_string()],
+        };
+
+        // Display differences using a vector as output
+        let mut w = Vec::new();
+        display_diff(&diff, &mut w).unwrap();
+
+        let golden_path = "src/testdata/display_diff.golden";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+
+    #[test]
+    fn display_regressions_prints_regressions() {
+        let regressions = Regressions {
+            checks: vec![CheckChange {
+                check_id: "readme".to_string(),
+                section: Section::Documentation,
+                base: Some(CheckStatus::Passed),
+                head: Some(CheckStatus::NotPassed),
+            }],
+            score: Some(ScoreChange {
+                base: Some(80.0),
+                head: Some(70.0),
+                delta: Some(-10.0),
+            }),
+        };
+
+        // Display regressions using a vector as output
+        let mut w = Vec::new();
+        display_regressions(&regressions, &mut w).unwrap();
+
+        let golden_path = "src/testdata/display_regressions.golden";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+
+    #[test]
+    fn display_regressions_none_found() {
+        let mut w = Vec::new();
+        display_regressions(&Regressions::default(), &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "\nCLOMonitor linter regressions\n\nNo regressions found\n\n"
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::notifier::AnnualReviewNotification;
+use crate::notifier::{AnnualReviewNotification, ExemptionExpiryNotification};
 use anyhow::Result;
 use async_trait::async_trait;
 use deadpool_postgres::Pool;
@@ -29,6 +29,28 @@ pub(crate) trait DB {
         issue_number: Option<i64>,
         comment_id: Option<i64>,
     ) -> Result<()>;
+
+    /// Returns the pending exemption expiry notifications for the exemptions
+    /// expiring in the number of days provided.
+    async fn get_pending_exemption_expiry_notifications(
+        &self,
+        days: i32,
+    ) -> Result<Vec<ExemptionExpiryNotification>>;
+
+    /// Pre-register exemption expiry notification.
+    async fn pre_register_exemption_expiry_notification(
+        &self,
+        repository_id: &Uuid,
+        check_id: &str,
+        expires: &str,
+    ) -> Result<Uuid>;
+
+    /// Update exemption expiry notification details.
+    async fn update_exemption_expiry_notification(
+        &self,
+        notification_id: &Uuid,
+        issue_number: Option<i64>,
+    ) -> Result<()>;
 }
 
 /// Type alias to represent a DB trait object.
@@ -114,4 +136,74 @@ impl DB for PgDB {
         .await?;
         Ok(())
     }
+
+    /// [DB::get_pending_exemption_expiry_notifications]
+    async fn get_pending_exemption_expiry_notifications(
+        &self,
+        days: i32,
+    ) -> Result<Vec<ExemptionExpiryNotification>> {
+        let db = self.pool.get().await?;
+        let pending_notifications = db
+            .query(
+                "select * from get_pending_exemption_expiry_notifications($1::integer)",
+                &[&days],
+            )
+            .await?
+            .iter()
+            .map(|row| ExemptionExpiryNotification {
+                repository_id: row.get("repository_id"),
+                repository_url: row.get("repository_url"),
+                check_id: row.get("check_id"),
+                expires: row.get("expires"),
+            })
+            .collect();
+        Ok(pending_notifications)
+    }
+
+    /// [DB::pre_register_exemption_expiry_notification]
+    async fn pre_register_exemption_expiry_notification(
+        &self,
+        repository_id: &Uuid,
+        check_id: &str,
+        expires: &str,
+    ) -> Result<Uuid> {
+        let db = self.pool.get().await?;
+        let notification_id = db
+            .query_one(
+                "
+                insert into exemption_expiry_notification (
+                    repository_id,
+                    check_id,
+                    expires
+                ) values (
+                    $1::uuid,
+                    $2::text,
+                    $3::text::date
+                ) returning exemption_expiry_notification_id;
+                ",
+                &[&repository_id, &check_id, &expires],
+            )
+            .await?
+            .get("exemption_expiry_notification_id");
+        Ok(notification_id)
+    }
+
+    /// [DB::update_exemption_expiry_notification]
+    async fn update_exemption_expiry_notification(
+        &self,
+        notification_id: &Uuid,
+        issue_number: Option<i64>,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update exemption_expiry_notification set
+                issue_number = $1::bigint
+            where exemption_expiry_notification_id = $2::uuid;
+            ",
+            &[&issue_number, &notification_id],
+        )
+        .await?;
+        Ok(())
+    }
 }
@@ -14,7 +14,8 @@ use uuid::Uuid;
 pub(crate) async fn run(cfg: &Config, db: DynDB, gh: DynGH) -> Result<()> {
     info!("started");
 
-    process_annual_review_notifications(cfg, db, gh).await?;
+    process_annual_review_notifications(cfg, &db, &gh).await?;
+    process_exemption_expiry_notifications(cfg, &db, &gh).await?;
 
     info!("finished");
     Ok(())
@@ -30,16 +31,32 @@ pub(crate) struct AnnualReviewNotification {
     pub issue_number: Option<i64>,
 }
 
+/// Title used in the issues created to notify that an exemption is about to
+/// expire.
+const EXEMPTION_EXPIRING_TITLE: &str = "CLOMonitor exemption about to expire";
+
+/// Default number of days before an exemption expires when the notification
+/// is sent.
+const DEFAULT_EXEMPTION_EXPIRY_DAYS: i32 = 30;
+
+/// Information needed to send an exemption expiry notification.
+pub(crate) struct ExemptionExpiryNotification {
+    pub repository_id: Uuid,
+    pub repository_url: String,
+    pub check_id: String,
+    pub expires: String,
+}
+
 /// Process annual review notifications.
 #[instrument(skip_all, err)]
-async fn process_annual_review_notifications(cfg: &Config, db: DynDB, gh: DynGH) -> Result<()> {
+async fn process_annual_review_notifications(cfg: &Config, db: &DynDB, gh: &DynGH) -> Result<()> {
     match db.get_pending_annual_review_notifications().await {
         Ok(mut notifications) => {
             // If a list of allowed repositories is provided, filter out
             // notifications whose repository isn't listed on it
             if let Ok(allowed_repos) = cfg.get::<Vec<String>>("notifier.allowedRepositories") {
                 notifications.retain(|n| allowed_repos.contains(&n.community_repo_url));
-            };
+            }
 
             // Process pending notifications
             for (i, n) in notifications.iter().enumerate() {
@@ -129,6 +146,79 @@ async fn process_annual_review_notifications(cfg: &Config, db: DynDB, gh: DynGH)
     }
 }
 
+/// Process exemption expiry notifications.
+#[instrument(skip_all, err)]
+async fn process_exemption_expiry_notifications(
+    cfg: &Config,
+    db: &DynDB,
+    gh: &DynGH,
+) -> Result<()> {
+    let days = cfg
+        .get::<i32>("notifier.exemptionExpiryDays")
+        .unwrap_or(DEFAULT_EXEMPTION_EXPIRY_DAYS);
+    let mut notifications = db.get_pending_exemption_expiry_notifications(days).await?;
+
+    // If a list of allowed repositories is provided, filter out notifications
+    // whose repository isn't listed on it
+    if let Ok(allowed_repos) = cfg.get::<Vec<String>>("notifier.allowedRepositories") {
+        notifications.retain(|n| allowed_repos.contains(&n.repository_url));
+    }
+
+    // Process pending notifications
+    for (i, n) in notifications.iter().enumerate() {
+        info!(?n.repository_url, ?n.check_id, ?n.expires, "processing pending exemption expiry notification");
+
+        // Extract owner and repo from url
+        let Ok((owner, repo)) = get_owner_and_repo(&n.repository_url) else {
+            continue;
+        };
+
+        // Pre-register notification in database
+        // (to avoid sending multiple notifications if registration failed after sending)
+        let notification_id = db
+            .pre_register_exemption_expiry_notification(&n.repository_id, &n.check_id, &n.expires)
+            .await?;
+
+        // Send notification
+        let body = tmpl::ExemptionExpiring {
+            check_id: &n.check_id,
+            expires: &n.expires,
+        }
+        .render()
+        .unwrap();
+        let issue_number = match gh
+            .create_issue(&owner, &repo, EXEMPTION_EXPIRING_TITLE, &body)
+            .await
+        {
+            Ok(v) => {
+                info!(
+                    ?owner,
+                    ?repo,
+                    issue_number = v,
+                    "exemption expiry notification sent"
+                );
+                Some(v)
+            }
+            Err(err) => {
+                error!(?err, ?owner, ?repo, "error creating issue");
+                continue;
+            }
+        };
+
+        // Update notification details in database
+        db.update_exemption_expiry_notification(&notification_id, issue_number)
+            .await?;
+
+        // If there are more notifications to process, pause before the next
+        // one to avoid hitting GitHub secondary rate limits
+        if i < notifications.len() - 1 {
+            sleep(Duration::from_secs(10)).await;
+        }
+    }
+
+    Ok(())
+}
+
 lazy_static! {
     static ref GITHUB_REPO_URL: Regex =
         Regex::new("^https://github.com/(?P<owner>[^/]+)/(?P<repo>[^/]+)/?$")
@@ -155,12 +245,16 @@ mod tests {
     const REPO2_URL: &str = "https://github.com/owner/repo2";
     const ISSUE_NUMBER: i64 = 1;
     const COMMENT_ID: i64 = 1234;
+    const CHECK_ID: &str = "roadmap";
+    const EXPIRES: &str = "2030-01-31";
 
     lazy_static! {
         static ref PROJECT_ID: Uuid =
             Uuid::parse_str("00000000-0001-0000-0000-000000000000").unwrap();
         static ref NOTIFICATION_ID: Uuid =
             Uuid::parse_str("00000000-0001-0000-0000-000000000000").unwrap();
+        static ref REPOSITORY_ID: Uuid =
+            Uuid::parse_str("00000000-0000-0001-0000-000000000000").unwrap();
     }
 
     #[tokio::test]
@@ -187,6 +281,11 @@ mod tests {
             .times(1)
             .returning(|| Box::pin(future::ready(Ok(vec![]))));
 
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(DEFAULT_EXEMPTION_EXPIRY_DAYS))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+
         let gh = MockGH::new();
 
         run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
@@ -211,6 +310,11 @@ mod tests {
                 }])))
             });
 
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(DEFAULT_EXEMPTION_EXPIRY_DAYS))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
+
         let gh = MockGH::new();
 
         run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
@@ -238,6 +342,10 @@ mod tests {
             .with(eq(*NOTIFICATION_ID), eq(Some(ISSUE_NUMBER)), eq(None))
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(DEFAULT_EXEMPTION_EXPIRY_DAYS))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
 
         let mut gh = MockGH::new();
         gh.expect_create_issue()
@@ -275,6 +383,10 @@ mod tests {
             .with(eq(*NOTIFICATION_ID), eq(Some(ISSUE_NUMBER)), eq(None))
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(DEFAULT_EXEMPTION_EXPIRY_DAYS))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
 
         let mut gh = MockGH::new();
         gh.expect_is_issue_closed()
@@ -320,6 +432,10 @@ mod tests {
             )
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(DEFAULT_EXEMPTION_EXPIRY_DAYS))
+            .times(1)
+            .returning(|_| Box::pin(future::ready(Ok(vec![]))));
 
         let mut gh = MockGH::new();
         gh.expect_is_issue_closed()
@@ -338,4 +454,55 @@ mod tests {
 
         run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn create_new_issue_for_exemption_about_to_expire() {
+        let cfg = Config::builder()
+            .set_default("notifier.exemptionExpiryDays", 15)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut db = MockDB::new();
+        db.expect_get_pending_annual_review_notifications()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(vec![]))));
+        db.expect_get_pending_exemption_expiry_notifications()
+            .with(eq(15))
+            .times(1)
+            .returning(|_| {
+                Box::pin(future::ready(Ok(vec![ExemptionExpiryNotification {
+                    repository_id: *REPOSITORY_ID,
+                    repository_url: REPO1_URL.to_string(),
+                    check_id: CHECK_ID.to_string(),
+                    expires: EXPIRES.to_string(),
+                }])))
+            });
+        db.expect_pre_register_exemption_expiry_notification()
+            .with(eq(*REPOSITORY_ID), eq(CHECK_ID), eq(EXPIRES))
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
+        db.expect_update_exemption_expiry_notification()
+            .with(eq(*NOTIFICATION_ID), eq(Some(ISSUE_NUMBER)))
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(Ok(()))));
+
+        let mut gh = MockGH::new();
+        gh.expect_create_issue()
+            .with(
+                eq("owner"),
+                eq("repo1"),
+                eq(EXEMPTION_EXPIRING_TITLE),
+                eq(tmpl::ExemptionExpiring {
+                    check_id: CHECK_ID,
+                    expires: EXPIRES,
+                }
+                .render()
+                .unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(future::ready(Ok(ISSUE_NUMBER))));
+
+        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+    }
 }
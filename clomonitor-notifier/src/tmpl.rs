@@ -9,3 +9,11 @@ pub(crate) struct AnnualReviewDue {}
 #[derive(Template)]
 #[template(path = "annual-review-due-reminder.md")]
 pub(crate) struct AnnualReviewDueReminder {}
+
+/// Template for the exemption about to expire issue.
+#[derive(Template)]
+#[template(path = "exemption-expiring.md")]
+pub(crate) struct ExemptionExpiring<'a> {
+    pub check_id: &'a str,
+    pub expires: &'a str,
+}
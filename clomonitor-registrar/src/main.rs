@@ -29,6 +29,7 @@ async fn main() -> Result<()> {
 
     // Setup configuration
     let cfg = Config::builder()
+        .set_default("registrar.checkSets", Vec::<String>::new())?
         .add_source(File::from(args.config))
         .build()
         .context("error setting up configuration")?;
@@ -41,7 +42,7 @@ async fn main() -> Result<()> {
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
         _ => s.init(),
-    };
+    }
 
     // Setup database
     debug!("setting up database");
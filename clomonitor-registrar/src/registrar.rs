@@ -12,6 +12,9 @@ use tracing::{debug, error, info, instrument};
 /// Maximum time that can take processing a foundation data file.
 const FOUNDATION_TIMEOUT: u64 = 300;
 
+/// Builtin check sets that repositories can use.
+const BUILTIN_CHECK_SETS: [&str; 4] = ["code", "code-lite", "community", "docs"];
+
 /// Represents a foundation registered in the database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Foundation {
@@ -63,6 +66,24 @@ impl Project {
         self.digest = Some(digest);
         Ok(())
     }
+
+    /// Check that the check sets used by the project's repositories are
+    /// either builtin or one of the custom check sets provided.
+    fn validate_check_sets(&self, custom_check_sets: &[String]) -> Result<()> {
+        for r in &self.repositories {
+            for check_set in r.check_sets.iter().flatten() {
+                if !BUILTIN_CHECK_SETS.contains(&check_set.as_str())
+                    && !custom_check_sets.contains(check_set)
+                {
+                    return Err(format_err!(
+                        "repository {} uses an unknown check set: {check_set}",
+                        r.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Represents a project's repository.
@@ -71,6 +92,11 @@ pub(crate) struct Repository {
     pub name: String,
     pub url: String,
 
+    /// Path of the component to lint, relative to the repository root. Used
+    /// when several components of a project live in the same repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub check_sets: Option<Vec<String>>,
 
@@ -83,6 +109,9 @@ pub(crate) struct Repository {
 pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
     info!("started");
 
+    // Custom check sets repositories can use (besides the builtin ones)
+    let custom_check_sets: Vec<String> = cfg.get("registrar.checkSets")?;
+
     // Process foundations
     let http_client = reqwest::Client::new();
     let foundations = db.foundations().await?;
@@ -92,7 +121,12 @@ pub(crate) async fn run(cfg: &Config, db: DynDB) -> Result<()> {
             let foundation_id = foundation.foundation_id.clone();
             match timeout(
                 Duration::from_secs(FOUNDATION_TIMEOUT),
-                process_foundation(db.clone(), http_client.clone(), foundation),
+                process_foundation(
+                    db.clone(),
+                    http_client.clone(),
+                    foundation,
+                    &custom_check_sets,
+                ),
             )
             .await
             {
@@ -131,6 +165,7 @@ async fn process_foundation(
     db: DynDB,
     http_client: reqwest::Client,
     foundation: Foundation,
+    custom_check_sets: &[String],
 ) -> Result<()> {
     let start = Instant::now();
     debug!("started");
@@ -174,6 +209,12 @@ async fn process_foundation(
             }
         }
 
+        // Check the project only uses known check sets
+        if let Err(err) = project.validate_check_sets(custom_check_sets) {
+            error!(?err, project = project.name, "invalid project");
+            continue;
+        }
+
         // Register project
         debug!(project = project.name, "registering");
         if let Err(err) = db.register_project(foundation_id, project).await {
@@ -400,6 +441,7 @@ mod tests {
                     repositories: vec![Repository{
                         name: "artifact-hub".to_string(),
                         url: "https://github.com/artifacthub/hub".to_string(),
+                        path: None,
                         check_sets: Some(vec!["community".to_string(), "code".to_string()]),
                         exclude: None,
                     }]
@@ -464,10 +506,55 @@ mod tests {
         data_file_req.assert_async().await;
     }
 
+    #[test]
+    fn validate_check_sets_known() {
+        let project = setup_test_project(&["code", "spec"]);
+
+        assert!(project.validate_check_sets(&["spec".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn validate_check_sets_unknown() {
+        let project = setup_test_project(&["code", "unknown"]);
+
+        assert_eq!(
+            project
+                .validate_check_sets(&["spec".to_string()])
+                .unwrap_err()
+                .to_string(),
+            "repository repo uses an unknown check set: unknown"
+        );
+    }
+
+    fn setup_test_project(check_sets: &[&str]) -> Project {
+        Project {
+            name: "project".to_string(),
+            display_name: None,
+            description: "description".to_string(),
+            category: None,
+            home_url: None,
+            logo_url: None,
+            logo_dark_url: None,
+            devstats_url: None,
+            accepted_at: None,
+            maturity: None,
+            digest: None,
+            repositories: vec![Repository {
+                name: "repo".to_string(),
+                url: "https://github.com/org/repo".to_string(),
+                path: None,
+                check_sets: Some(check_sets.iter().map(ToString::to_string).collect()),
+                exclude: None,
+            }],
+        }
+    }
+
     fn setup_test_config() -> Config {
         Config::builder()
             .set_default("registrar.concurrency", 1)
             .unwrap()
+            .set_default("registrar.checkSets", vec!["spec"])
+            .unwrap()
             .build()
             .unwrap()
     }
@@ -2,12 +2,13 @@ use crate::tracker::Repository;
 use anyhow::Result;
 use async_trait::async_trait;
 use clomonitor_core::{
-    linter::{CheckSet, Foundation, Project, Report},
+    linter::{CacheKey, CheckCache, CheckOutput, CheckSet, Foundation, Project, Report},
     score::{self, Score},
 };
 use deadpool_postgres::{Pool, Transaction};
 #[cfg(test)]
 use mockall::automock;
+use serde_json::Value;
 use std::sync::Arc;
 use tokio_postgres::types::Json;
 use uuid::Uuid;
@@ -49,6 +50,7 @@ impl DB for PgDB {
                 select
                     r.repository_id,
                     r.url,
+                    r.path,
                     r.digest,
                     to_json(r.check_sets) as check_sets,
                     r.updated_at,
@@ -72,6 +74,7 @@ impl DB for PgDB {
                 Repository {
                     repository_id: row.get("repository_id"),
                     url: row.get("url"),
+                    path: row.get("path"),
                     check_sets,
                     digest: row.get("digest"),
                     updated_at: row.get("updated_at"),
@@ -109,6 +112,59 @@ impl DB for PgDB {
     }
 }
 
+#[async_trait]
+impl CheckCache for PgDB {
+    async fn get(&self, key: &CacheKey, fingerprint: &str) -> Result<Option<CheckOutput<Value>>> {
+        let db = self.pool.get().await?;
+        let output = db
+            .query_opt(
+                "
+                select output from check_cache
+                where repository_url = $1::text
+                and repository_path = $2::text
+                and check_id = $3::text
+                and fingerprint = $4::text;
+                ",
+                &[&key.url, &key.path, &key.check_id, &fingerprint],
+            )
+            .await?
+            .map(|row| {
+                let Json(output): Json<CheckOutput<Value>> = row.get("output");
+                output
+            });
+        Ok(output)
+    }
+
+    async fn set(
+        &self,
+        key: &CacheKey,
+        fingerprint: &str,
+        output: &CheckOutput<Value>,
+    ) -> Result<()> {
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            insert into check_cache (repository_url, repository_path, check_id, fingerprint, output)
+            values ($1::text, $2::text, $3::text, $4::text, $5::jsonb)
+            on conflict (repository_url, repository_path, check_id) do update
+            set
+                fingerprint = excluded.fingerprint,
+                output = excluded.output,
+                updated_at = current_timestamp;
+            ",
+            &[
+                &key.url,
+                &key.path,
+                &key.check_id,
+                &fingerprint,
+                &Json(output),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
 impl PgDB {
     /// Create a new PgDB instance.
     pub(crate) fn new(pool: Pool) -> Self {
@@ -125,10 +181,11 @@ impl PgDB {
     ) -> Result<()> {
         match report {
             Some(report) => {
+                let check_sets: Vec<&str> = check_sets.iter().map(CheckSet::name).collect();
                 tx.execute(
                     "
                     insert into report (check_sets, data, errors, repository_id)
-                    values ($1::check_set[], $2::jsonb, $3::text, $4::uuid)
+                    values ($1::text[], $2::jsonb, $3::text, $4::uuid)
                     on conflict (repository_id) do update
                     set
                         check_sets = excluded.check_sets,
@@ -21,15 +21,21 @@ pub(crate) trait Git {
 }
 
 /// Git implementation backed by the git cli tool.
-pub(crate) struct GitCLI;
+pub(crate) struct GitCLI {
+    /// Number of commits fetched when cloning a repository.
+    depth: usize,
+}
 
 impl GitCLI {
     /// Create a new GitCLI instance.
-    pub(crate) fn new() -> Result<Self> {
+    pub(crate) fn new(depth: usize) -> Result<Self> {
+        if depth == 0 {
+            return Err(format_err!("clone depth must be greater than 0"));
+        }
         if which("git").is_err() {
             return Err(format_err!("git not found in PATH"));
         }
-        Ok(Self {})
+        Ok(Self { depth })
     }
 }
 
@@ -38,7 +44,7 @@ impl Git for GitCLI {
     async fn clone_repository(&self, url: &str, dst: &Path) -> Result<()> {
         let output = Command::new("git")
             .arg("clone")
-            .arg("--depth=10")
+            .arg(format!("--depth={}", self.depth))
             .arg(url)
             .arg(dst)
             .output()
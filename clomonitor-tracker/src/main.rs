@@ -7,10 +7,11 @@ use clap::Parser;
 use clomonitor_core::linter::CoreLinter;
 use config::{Config, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
-use std::{path::PathBuf, sync::Arc};
-use tracing::debug;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use tracing::{debug, warn};
 use tracing_subscriber::EnvFilter;
 
 mod db;
@@ -32,6 +33,22 @@ async fn main() -> Result<()> {
     // Setup configuration
     let cfg = Config::builder()
         .set_default("tracker.concurrency", 10)?
+        .set_default("tracker.cloneDepth", 10)?
+        .set_default("tracker.forges", Vec::<String>::new())?
+        .set_default(
+            "tracker.scorecardThresholds",
+            HashMap::<String, String>::new(),
+        )?
+        .set_default(
+            "tracker.responsivenessThresholds",
+            HashMap::<String, String>::new(),
+        )?
+        .set_default(
+            "tracker.releaseCadenceThresholds",
+            HashMap::<String, String>::new(),
+        )?
+        .set_default("tracker.checkSets", Vec::<String>::new())?
+        .set_default("tracker.cacheChecks", true)?
         .add_source(File::from(args.config))
         .build()
         .context("error setting up configuration")?;
@@ -44,7 +61,7 @@ async fn main() -> Result<()> {
     match cfg.get_string("log.format").as_deref() {
         Ok("json") => s.json().init(),
         _ => s.init(),
-    };
+    }
 
     // Setup database
     debug!("setting up database");
@@ -56,7 +73,45 @@ async fn main() -> Result<()> {
     let db = Arc::new(PgDB::new(pool));
 
     // Run tracker
-    let git = Arc::new(GitCLI::new()?);
-    let linter = Arc::new(CoreLinter::new());
-    tracker::run(&cfg, db, git, linter).await
+    let git = Arc::new(GitCLI::new(cfg.get("tracker.cloneDepth")?)?);
+    let mut linter = CoreLinter::new();
+    if cfg.get_bool("tracker.cacheChecks")? {
+        linter = linter.with_cache(db.clone());
+    }
+    let linter = Arc::new(linter);
+
+    // Setup Prometheus metrics recorder
+    let metrics = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full("clomonitor_tracker_check_duration".to_string()),
+            &[0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0],
+        )?
+        .install_recorder()?;
+
+    // Run tracker
+    let result = tracker::run(&cfg, db, git, linter).await;
+
+    // Push metrics collected to the Prometheus Pushgateway (if configured)
+    if let Ok(url) = cfg.get_string("tracker.metricsPushGatewayUrl") {
+        if let Err(err) = push_metrics(&url, metrics.render()).await {
+            warn!(?err, "error pushing metrics");
+        }
+    }
+
+    result
+}
+
+/// Push the metrics provided to the Prometheus Pushgateway at the url given.
+async fn push_metrics(url: &str, metrics: String) -> Result<()> {
+    let url = format!(
+        "{}/metrics/job/clomonitor_tracker",
+        url.trim_end_matches('/')
+    );
+    reqwest::Client::new()
+        .put(url)
+        .body(metrics)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
 }
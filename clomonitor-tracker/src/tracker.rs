@@ -2,13 +2,21 @@ use crate::{db::DynDB, git::DynGit};
 use anyhow::{format_err, Error, Result};
 #[cfg(not(test))]
 use clomonitor_core::linter::setup_github_http_client;
-use clomonitor_core::linter::{CheckSet, DynLinter, LinterInput, Project};
+use clomonitor_core::linter::{
+    CheckSet, CustomCheckSet, DynLinter, Forge, LinterInput, Project, ReleaseCadenceThresholds,
+    Report, ResponsivenessThresholds,
+};
 use config::Config;
 use deadpool::unmanaged::{Object, Pool};
 use futures::stream::{self, StreamExt};
+use serde::Deserialize;
 #[cfg(not(test))]
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 use tempfile::Builder;
 use time::{self, OffsetDateTime};
 use tokio::{task::JoinError, time::timeout};
@@ -24,12 +32,22 @@ const REPOSITORY_TRACK_TIMEOUT: u64 = 600;
 pub(crate) struct Repository {
     pub repository_id: Uuid,
     pub url: String,
+    pub path: Option<String>,
     pub check_sets: Vec<CheckSet>,
     pub digest: Option<String>,
     pub updated_at: OffsetDateTime,
     pub project: Project,
 }
 
+/// Forge routing rule. Repositories of the foundation provided hosted in the
+/// given host will be linted using the corresponding forge backend.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ForgeRoute {
+    pub foundation: String,
+    pub host: String,
+    pub forge: Forge,
+}
+
 /// Track all repositories registered in the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter) -> Result<()> {
@@ -44,6 +62,32 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
     }
     let gh_tokens_pool = Pool::from(gh_tokens.clone());
 
+    // Tokens used to authenticate the requests to the GitLab and Gitea APIs
+    // (optional)
+    let gitlab_token = cfg.get_string("creds.gitlabToken").ok();
+    let gitea_token = cfg.get_string("creds.giteaToken").ok();
+
+    // Setup forges routing rules
+    let forges: Vec<ForgeRoute> = cfg.get("tracker.forges")?;
+
+    // Scorecard API endpoint serving precomputed results (optional)
+    let scorecard_api_url = cfg.get_string("tracker.scorecardApiUrl").ok();
+
+    // Setup scorecard checks pass thresholds (foundation -> check id -> score)
+    let scorecard_thresholds: HashMap<String, HashMap<String, f64>> =
+        cfg.get("tracker.scorecardThresholds")?;
+
+    // Setup responsiveness check thresholds (foundation -> thresholds)
+    let responsiveness_thresholds: HashMap<String, ResponsivenessThresholds> =
+        cfg.get("tracker.responsivenessThresholds")?;
+
+    // Setup release cadence check thresholds (foundation -> thresholds)
+    let release_cadence_thresholds: HashMap<String, ReleaseCadenceThresholds> =
+        cfg.get("tracker.releaseCadenceThresholds")?;
+
+    // Setup custom check sets definitions
+    let custom_check_sets: Vec<CustomCheckSet> = cfg.get("tracker.checkSets")?;
+
     // Get repositories to process
     debug!("getting repositories");
     let repositories = db.repositories().await?;
@@ -62,11 +106,32 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
             let linter = linter.clone();
             let github_token = gh_tokens_pool.get().await.expect("token -when available-");
             let url = repository.url.clone();
+            let foundation_id = &repository.project.foundation.foundation_id;
+            let base_input = LinterInput {
+                custom_check_sets: custom_check_sets.clone(),
+                gitlab_token: gitlab_token.clone(),
+                gitea_token: gitea_token.clone(),
+                forge: forge_for(&forges, &repository),
+                scorecard_api_url: scorecard_api_url.clone(),
+                scorecard_thresholds: scorecard_thresholds
+                    .get(foundation_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                responsiveness_thresholds: responsiveness_thresholds
+                    .get(foundation_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                release_cadence_thresholds: release_cadence_thresholds
+                    .get(foundation_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                ..LinterInput::default()
+            };
 
             tokio::spawn(async move {
                 match timeout(
                     Duration::from_secs(REPOSITORY_TRACK_TIMEOUT),
-                    track_repository(db, git, linter, github_token, repository),
+                    track_repository(db, git, linter, github_token, base_input, repository),
                 )
                 .await
                 {
@@ -122,12 +187,15 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
 
 /// Track repository if it has changed since the last time it was tracked.
 /// This involves cloning the repository, linting it and storing the results.
+/// The base linter input provided contains the settings that apply to this
+/// repository (forge, scorecard options, etc).
 #[instrument(fields(url = repository.url), skip_all, err)]
 async fn track_repository(
     db: DynDB,
     git: DynGit,
     linter: DynLinter,
     github_token: Object<String>,
+    base_input: LinterInput,
     repository: Repository,
 ) -> Result<()> {
     let start = Instant::now();
@@ -154,12 +222,17 @@ async fn track_repository(
     let input = LinterInput {
         project: Some(repository.project),
         root: tmp_dir.into_path(),
+        subpath: repository.path.as_ref().map(PathBuf::from),
         url: repository.url.clone(),
         check_sets: repository.check_sets.clone(),
         github_token: github_token.to_owned(),
+        ..base_input
     };
     let report = match linter.lint(&input).await {
-        Ok(report) => Some(report),
+        Ok(report) => {
+            record_checks_metrics(&report);
+            Some(report)
+        }
         Err(err) => {
             warn!(?err, "error linting repository");
             errors = Some(format!("error linting repository: {err:#}"));
@@ -181,11 +254,39 @@ async fn track_repository(
     Ok(())
 }
 
+/// Record the checks duration and failures metrics from the report provided.
+#[allow(clippy::cast_precision_loss)]
+fn record_checks_metrics(report: &Report) {
+    for (check_id, duration_ms) in &report.durations {
+        metrics::histogram!("clomonitor_tracker_check_duration", "check" => check_id.clone())
+            .record(*duration_ms as f64 / 1000.0);
+    }
+    for check_id in report.failed_checks() {
+        metrics::counter!("clomonitor_tracker_check_failures", "check" => check_id.to_string())
+            .increment(1);
+    }
+}
+
+/// Get the forge that should be used to lint the repository provided from
+/// the routing rules given. When no rule matches the repository's foundation
+/// and host, the linter will detect the forge from the repository url.
+fn forge_for(forges: &[ForgeRoute], repository: &Repository) -> Option<Forge> {
+    let host = repository.url.split("://").nth(1)?.split('/').next()?;
+    forges
+        .iter()
+        .find(|r| {
+            r.foundation == repository.project.foundation.foundation_id
+                && r.host.eq_ignore_ascii_case(host)
+        })
+        .map(|r| r.forge)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{db::MockDB, git::MockGit};
-    use clomonitor_core::linter::{MockLinter, Report};
+    use clomonitor_core::linter::{Foundation, MockLinter};
+    use config::{File, FileFormat};
     use futures::future;
     use lazy_static::lazy_static;
     use predicates::prelude::{predicate::*, *};
@@ -197,6 +298,8 @@ mod tests {
     const REPOSITORY2_URL: &str = "https://repo2.url";
     const REPOSITORY1_DIGEST: &str = "repo1_digest";
     const REPOSITORY2_DIGEST: &str = "repo2_digest";
+    const REPOSITORY3_URL: &str = "https://git.example.org/owner/repo3";
+    const FOUNDATION: &str = "cncf";
     const FAKE_ERROR: &str = "fake error";
 
     lazy_static! {
@@ -276,6 +379,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -303,6 +407,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: Some(REPOSITORY1_DIGEST.to_string()),
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -330,6 +435,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -361,6 +467,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -407,6 +514,7 @@ mod tests {
                 Repository {
                     repository_id: *REPOSITORY1_ID,
                     url: REPOSITORY1_URL.to_string(),
+                    path: None,
                     check_sets: vec![CheckSet::Code],
                     digest: None,
                     updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
@@ -415,6 +523,7 @@ mod tests {
                 Repository {
                     repository_id: *REPOSITORY2_ID,
                     url: REPOSITORY2_URL.to_string(),
+                    path: None,
                     check_sets: vec![CheckSet::Code],
                     digest: None,
                     updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
@@ -497,10 +606,194 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn repository_linted_using_foundation_settings() {
+        let cfg = Config::builder()
+            .add_source(File::from_str(
+                r"
+                creds:
+                  githubTokens: [ '0001' ]
+                  giteaToken: '0002'
+                tracker:
+                  concurrency: 1
+                  forges:
+                    - foundation: cncf
+                      host: git.example.org
+                      forge: gitea
+                  scorecardThresholds:
+                    cncf:
+                      code_review: 8
+                  responsivenessThresholds:
+                    cncf:
+                      maxMedianResponseHours: 48
+                  releaseCadenceThresholds:
+                    cncf:
+                      maxIntervalDays: 90
+                  checkSets:
+                    - name: spec
+                      checks: [ readme, governance ]
+                      weights:
+                        governance: 5
+                ",
+                FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let mut linter = MockLinter::new();
+
+        db.expect_repositories().times(1).returning(|| {
+            Box::pin(future::ready(Ok(vec![Repository {
+                repository_id: *REPOSITORY1_ID,
+                url: REPOSITORY3_URL.to_string(),
+                path: None,
+                check_sets: vec![CheckSet::Custom("spec".to_string())],
+                digest: None,
+                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                project: setup_test_project(FOUNDATION),
+            }])))
+        });
+        git.expect_remote_digest()
+            .with(eq(REPOSITORY3_URL))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok(REPOSITORY1_DIGEST.to_string()))));
+        git.expect_clone_repository()
+            .with(eq(REPOSITORY3_URL), path::exists().and(path::is_dir()))
+            .times(1)
+            .returning(|_: &str, _: &Path| Box::pin(future::ready(Ok(()))));
+        linter
+            .expect_lint()
+            .withf(move |input: &LinterInput| {
+                input.url == REPOSITORY3_URL
+                    && input.forge == Some(Forge::Gitea)
+                    && input.gitea_token.as_deref() == Some("0002")
+                    && input.gitlab_token.is_none()
+                    && input.scorecard_thresholds.get("code_review") == Some(&8.0)
+                    && input.responsiveness_thresholds
+                        == ResponsivenessThresholds {
+                            max_median_response_hours: 48,
+                            ..ResponsivenessThresholds::default()
+                        }
+                    && input.release_cadence_thresholds.max_interval_days == 90
+                    && input.check_sets == vec![CheckSet::Custom("spec".to_string())]
+                    && input.custom_check_sets[0].name == "spec"
+                    && input.custom_check_sets[0].weights.get("governance") == Some(&5)
+            })
+            .times(1)
+            .returning(|_: &LinterInput| Box::pin(future::ready(Ok(Report::default()))));
+        db.expect_store_results().times(1).returning(
+            |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
+                Box::pin(future::ready(Ok(())))
+            },
+        );
+
+        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn repository_component_linted_using_its_path() {
+        let cfg = setup_test_config(1, &[TOKEN1]);
+        let mut db = MockDB::new();
+        let mut git = MockGit::new();
+        let mut linter = MockLinter::new();
+
+        db.expect_repositories().times(1).returning(|| {
+            Box::pin(future::ready(Ok(vec![Repository {
+                repository_id: *REPOSITORY1_ID,
+                url: REPOSITORY1_URL.to_string(),
+                path: Some("components/app".to_string()),
+                check_sets: vec![CheckSet::Code],
+                digest: None,
+                updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
+                project: Project::default(),
+            }])))
+        });
+        git.expect_remote_digest()
+            .with(eq(REPOSITORY1_URL))
+            .times(1)
+            .returning(|_: &str| Box::pin(future::ready(Ok(REPOSITORY1_DIGEST.to_string()))));
+        git.expect_clone_repository()
+            .with(eq(REPOSITORY1_URL), path::exists().and(path::is_dir()))
+            .times(1)
+            .returning(|_: &str, _: &Path| Box::pin(future::ready(Ok(()))));
+        linter
+            .expect_lint()
+            .withf(move |input: &LinterInput| {
+                input.url == REPOSITORY1_URL
+                    && input.subpath == Some(PathBuf::from("components/app"))
+            })
+            .times(1)
+            .returning(|_: &LinterInput| Box::pin(future::ready(Ok(Report::default()))));
+        db.expect_store_results()
+            .withf(|repository_id, _, _, _, _| *repository_id == *REPOSITORY1_ID)
+            .times(1)
+            .returning(
+                |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
+                    Box::pin(future::ready(Ok(())))
+                },
+            );
+
+        run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn forge_for_rule_not_matching_foundation() {
+        let forges = vec![ForgeRoute {
+            foundation: FOUNDATION.to_string(),
+            host: "git.example.org".to_string(),
+            forge: Forge::Gitea,
+        }];
+        let repository = Repository {
+            repository_id: *REPOSITORY1_ID,
+            url: REPOSITORY3_URL.to_string(),
+            path: None,
+            check_sets: vec![CheckSet::Code],
+            digest: None,
+            updated_at: OffsetDateTime::now_utc(),
+            project: setup_test_project("lfaidata"),
+        };
+
+        assert!(forge_for(&forges, &repository).is_none());
+    }
+
+    fn setup_test_project(foundation_id: &str) -> Project {
+        Project {
+            foundation: Foundation {
+                foundation_id: foundation_id.to_string(),
+                ..Foundation::default()
+            },
+            ..Project::default()
+        }
+    }
+
     fn setup_test_config(concurrency: u8, tokens: &[&str]) -> Config {
         Config::builder()
             .set_default("tracker.concurrency", concurrency)
             .unwrap()
+            .set_default("tracker.forges", Vec::<String>::new())
+            .unwrap()
+            .set_default(
+                "tracker.scorecardThresholds",
+                HashMap::<String, String>::new(),
+            )
+            .unwrap()
+            .set_default(
+                "tracker.responsivenessThresholds",
+                HashMap::<String, String>::new(),
+            )
+            .unwrap()
+            .set_default(
+                "tracker.releaseCadenceThresholds",
+                HashMap::<String, String>::new(),
+            )
+            .unwrap()
+            .set_default("tracker.checkSets", Vec::<String>::new())
+            .unwrap()
             .set_default(
                 "creds.githubTokens",
                 tokens
@@ -0,0 +1,34 @@
+-- Returns some information about the exemptions that will expire in the
+-- number of days provided and haven't been notified yet.
+create or replace function get_pending_exemption_expiry_notifications(p_days integer)
+returns table(
+    repository_id uuid,
+    repository_url text,
+    check_id text,
+    expires text
+) as $$
+    select
+        r.repository_id,
+        r.url as repository_url,
+        c.check_id,
+        c.output->>'exemption_expires' as expires
+    from repository r
+    join report rp using (repository_id)
+    cross join lateral jsonb_each(rp.data) s(section, checks)
+    cross join lateral jsonb_each(
+        case when jsonb_typeof(s.checks) = 'object' then s.checks else '{}' end
+    ) c(check_id, output)
+    where
+        c.output->>'exempt' = 'true'
+        and c.output ? 'exemption_expires'
+        and (c.output->>'exemption_expires')::date
+            between current_date and current_date + p_days
+        and not exists (
+            select 1
+            from exemption_expiry_notification n
+            where n.repository_id = r.repository_id
+            and n.check_id = c.check_id
+            and n.expires = (c.output->>'exemption_expires')::date
+        )
+    order by r.repository_id, c.check_id;
+$$ language sql;
@@ -21,6 +21,7 @@ returns json as $$
                 'repository_id', r.repository_id,
                 'name', r.name,
                 'url', r.url,
+                'path', r.path,
                 'check_sets', r.check_sets,
                 'digest', r.digest,
                 'score', r.score,
@@ -4,7 +4,7 @@ returns void as $$
 declare
     v_project_id uuid;
     v_repository jsonb;
-    v_check_sets check_set[];
+    v_check_sets text[];
 begin
     -- Register project or update existing one
     insert into project (
@@ -54,20 +54,22 @@ begin
         if v_repository->'check_sets' is null then
             v_check_sets = null;
         else
-            v_check_sets = (select array(select jsonb_array_elements_text(v_repository->'check_sets')))::check_set[];
+            v_check_sets = (select array(select jsonb_array_elements_text(v_repository->'check_sets')))::text[];
         end if;
         insert into repository (
             name,
             url,
+            path,
             check_sets,
             project_id
         ) values (
             v_repository->>'name',
             v_repository->>'url',
+            v_repository->>'path',
             v_check_sets,
             v_project_id
         )
-        on conflict (project_id, url) do update
+        on conflict (project_id, url, coalesce(path, '')) do update
         set
             name = excluded.name,
             check_sets = excluded.check_sets,
@@ -77,8 +79,8 @@ begin
     -- Delete repositories that are no longer available
     delete from repository
     where project_id = v_project_id
-    and url not in (
-        select value->>'url'
+    and (url, coalesce(path, '')) not in (
+        select value->>'url', coalesce(value->>'path', '')
         from jsonb_array_elements(p_project->'repositories')
     );
 end
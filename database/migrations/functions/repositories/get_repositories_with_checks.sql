@@ -23,13 +23,17 @@ returns setof text as $$
             (rp.data->'best_practices'->'artifacthub_badge'->'passed')::boolean as artifacthub_badge,
             (rp.data->'best_practices'->'cla'->'passed')::boolean as cla,
             (rp.data->'best_practices'->'community_meeting'->'passed')::boolean as community_meeting,
+            (rp.data->'best_practices'->'contributor_diversity'->'passed')::boolean as contributor_diversity,
             (rp.data->'best_practices'->'dco'->'passed')::boolean as dco,
             (rp.data->'best_practices'->'github_discussions'->'passed')::boolean as github_discussions,
             (rp.data->'best_practices'->'openssf_badge'->'passed')::boolean as openssf_badge,
             (rp.data->'best_practices'->'openssf_scorecard_badge'->'passed')::boolean as openssf_scorecard_badge,
             (rp.data->'best_practices'->'recent_release'->'passed')::boolean as recent_release,
+            (rp.data->'best_practices'->'release_cadence'->'passed')::boolean as release_cadence,
+            (rp.data->'best_practices'->'responsiveness'->'passed')::boolean as responsiveness,
             (rp.data->'best_practices'->'slack_presence'->'passed')::boolean as slack_presence,
             (rp.data->'security'->'binary_artifacts'->'passed')::boolean as binary_artifacts,
+            (rp.data->'security'->'branch_protection'->'passed')::boolean as branch_protection,
             (rp.data->'security'->'code_review'->'passed')::boolean as code_review,
             (rp.data->'security'->'dangerous_workflow'->'passed')::boolean as dangerous_workflow,
             (rp.data->'security'->'dependencies_policy'->'passed')::boolean as dependencies_policy,
@@ -46,7 +50,7 @@ returns setof text as $$
         join report rp using (repository_id)
         order by p.foundation_id asc, p.name asc
     )
-    select 'Foundation,Project,Repository URL,Check Sets,Adopters,Changelog,Code of Conduct,Contributing,Governance,Maintainers,Readme,Roadmap,Summary Table,Website,License Approved,License Scanning,License SPDX ID,ArtifactHub Badge,CLA,Community Meeting,DCO,GitHub discussions,OpenSSF best practices badge,OpenSSF Scorecard badge,Recent Release,Slack Presence,Binary Artifacts,Code Review,Dangerous Workflow,Dependencies Policy,Dependency Update Tool,Maintained,SBOM,Security Insights,Security Policy,Signed Releases,Token Permissions,Trademark Disclaimer'
+    select 'Foundation,Project,Repository URL,Check Sets,Adopters,Changelog,Code of Conduct,Contributing,Governance,Maintainers,Readme,Roadmap,Summary Table,Website,License Approved,License Scanning,License SPDX ID,ArtifactHub Badge,CLA,Community Meeting,Contributor Diversity,DCO,GitHub discussions,OpenSSF best practices badge,OpenSSF Scorecard badge,Recent Release,Release Cadence,Responsiveness,Slack Presence,Binary Artifacts,Branch Protection,Code Review,Dangerous Workflow,Dependencies Policy,Dependency Update Tool,Maintained,SBOM,Security Insights,Security Policy,Signed Releases,Token Permissions,Trademark Disclaimer'
     union all
     select rtrim(ltrim(r.*::text, '('), ')') from repositories r;
 $$ language sql;
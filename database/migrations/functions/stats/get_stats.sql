@@ -172,15 +172,19 @@ returns json as $$
                     'artifacthub_badge', repositories_passing_check(p_foundation, 'best_practices', 'artifacthub_badge'),
                     'cla', repositories_passing_check(p_foundation, 'best_practices', 'cla'),
                     'community_meeting', repositories_passing_check(p_foundation, 'best_practices', 'community_meeting'),
+                    'contributor_diversity', repositories_passing_check(p_foundation, 'best_practices', 'contributor_diversity'),
                     'dco', repositories_passing_check(p_foundation, 'best_practices', 'dco'),
                     'github_discussions', repositories_passing_check(p_foundation, 'best_practices', 'github_discussions'),
                     'openssf_badge', repositories_passing_check(p_foundation, 'best_practices', 'openssf_badge'),
                     'openssf_scorecard_badge', repositories_passing_check(p_foundation, 'best_practices', 'openssf_scorecard_badge'),
                     'recent_release', repositories_passing_check(p_foundation, 'best_practices', 'recent_release'),
+                    'release_cadence', repositories_passing_check(p_foundation, 'best_practices', 'release_cadence'),
+                    'responsiveness', repositories_passing_check(p_foundation, 'best_practices', 'responsiveness'),
                     'slack_presence', repositories_passing_check(p_foundation, 'best_practices', 'slack_presence')
                 ),
                 'security', json_build_object(
                     'binary_artifacts', repositories_passing_check(p_foundation, 'security', 'binary_artifacts'),
+                    'branch_protection', repositories_passing_check(p_foundation, 'security', 'branch_protection'),
                     'code_review', repositories_passing_check(p_foundation, 'security', 'code_review'),
                     'dangerous_workflow', repositories_passing_check(p_foundation, 'security', 'dangerous_workflow'),
                     'dependencies_policy', repositories_passing_check(p_foundation, 'security', 'dependencies_policy'),
@@ -0,0 +1,13 @@
+alter table repository
+    alter column check_sets type text[] using check_sets::text[];
+alter table report
+    alter column check_sets type text[] using check_sets::text[];
+drop type if exists check_set;
+
+---- create above / drop below ----
+
+create type check_set as enum ('code', 'code-lite', 'community', 'docs');
+alter table repository
+    alter column check_sets type check_set[] using check_sets::check_set[];
+alter table report
+    alter column check_sets type check_set[] using check_sets::check_set[];
@@ -0,0 +1,14 @@
+create table if not exists exemption_expiry_notification (
+    exemption_expiry_notification_id uuid primary key default gen_random_uuid(),
+    check_id text not null check (check_id <> ''),
+    expires date not null,
+    issue_number bigint,
+    created_at timestamptz default current_timestamp not null,
+    repository_id uuid not null references repository on delete cascade
+);
+
+create index exemption_expiry_notification_repository_id_idx on exemption_expiry_notification (repository_id);
+
+---- create above / drop below ----
+
+drop table if exists exemption_expiry_notification;
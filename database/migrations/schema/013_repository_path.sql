@@ -0,0 +1,10 @@
+alter table repository add column path text check (path <> '');
+alter table repository drop constraint repository_project_id_url_key;
+create unique index repository_project_id_url_path_key on repository (project_id, url, coalesce(path, ''));
+
+---- create above / drop below ----
+
+drop index if exists repository_project_id_url_path_key;
+delete from repository where path is not null;
+alter table repository add constraint repository_project_id_url_key unique (project_id, url);
+alter table repository drop column if exists path;
@@ -0,0 +1,13 @@
+create table if not exists check_cache (
+    repository_url text not null check (repository_url <> ''),
+    repository_path text not null default '',
+    check_id text not null check (check_id <> ''),
+    fingerprint text not null check (fingerprint <> ''),
+    output jsonb not null,
+    updated_at timestamptz default current_timestamp not null,
+    primary key (repository_url, repository_path, check_id)
+);
+
+---- create above / drop below ----
+
+drop table if exists check_cache;
@@ -0,0 +1,157 @@
+-- Start transaction and plan tests
+begin;
+select plan(1);
+
+-- Seed some data
+insert into foundation values ('cncf', 'CNCF', 'http://127.0.0.1:8080/cncf.yaml');
+insert into project (
+    project_id,
+    name,
+    foundation_id
+) values (
+    '00000000-0001-0000-0000-000000000000',
+    'project1',
+    'cncf'
+);
+
+-- Repository 1 (exemption expiring soon and exemption expiring later)
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0001-0000-000000000000',
+    'repository1',
+    'https://repo1.url',
+    '{code,community}',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into report (
+    report_id,
+    data,
+    repository_id
+) values (
+    '00000000-0000-0000-0001-000000000000',
+    jsonb_build_object(
+        'documentation', jsonb_build_object(
+            'adopters', jsonb_build_object(
+                'passed', false,
+                'exempt', true,
+                'failed', false,
+                'exemption_reason', 'reason',
+                'exemption_expires', to_char(current_date + 10, 'YYYY-MM-DD')
+            ),
+            'roadmap', jsonb_build_object(
+                'passed', false,
+                'exempt', true,
+                'failed', false,
+                'exemption_reason', 'reason',
+                'exemption_expires', to_char(current_date + 60, 'YYYY-MM-DD')
+            ),
+            'readme', null
+        ),
+        'weights', jsonb_build_object('adopters', 5)
+    ),
+    '00000000-0000-0001-0000-000000000000'
+);
+
+-- Repository 2 (exemption expiring soon already notified)
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0002-0000-000000000000',
+    'repository2',
+    'https://repo2.url',
+    '{code}',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into report (
+    report_id,
+    data,
+    repository_id
+) values (
+    '00000000-0000-0000-0002-000000000000',
+    jsonb_build_object(
+        'security', jsonb_build_object(
+            'sbom', jsonb_build_object(
+                'passed', false,
+                'exempt', true,
+                'failed', false,
+                'exemption_reason', 'reason',
+                'exemption_expires', to_char(current_date + 5, 'YYYY-MM-DD')
+            )
+        )
+    ),
+    '00000000-0000-0002-0000-000000000000'
+);
+insert into exemption_expiry_notification (
+    check_id,
+    expires,
+    issue_number,
+    repository_id
+) values (
+    'sbom',
+    current_date + 5,
+    1,
+    '00000000-0000-0002-0000-000000000000'
+);
+
+-- Repository 3 (exemption without expiration date)
+insert into repository (
+    repository_id,
+    name,
+    url,
+    check_sets,
+    project_id
+) values (
+    '00000000-0000-0003-0000-000000000000',
+    'repository3',
+    'https://repo3.url',
+    '{code}',
+    '00000000-0001-0000-0000-000000000000'
+);
+insert into report (
+    report_id,
+    data,
+    repository_id
+) values (
+    '00000000-0000-0000-0003-000000000000',
+    '{
+        "security": {
+            "sbom": {
+                "passed": false,
+                "exempt": true,
+                "failed": false,
+                "exemption_reason": "reason"
+            }
+        }
+    }',
+    '00000000-0000-0003-0000-000000000000'
+);
+
+-- Run some tests
+select results_eq(
+    $$
+        select * from get_pending_exemption_expiry_notifications(30)
+    $$,
+    $$
+        values
+            (
+                '00000000-0000-0001-0000-000000000000'::uuid,
+                'https://repo1.url',
+                'adopters',
+                to_char(current_date + 10, 'YYYY-MM-DD')
+            )
+    $$,
+    'Return pending exemption expiry notifications'
+);
+
+-- Finish tests and rollback transaction
+select * from finish();
+rollback;
@@ -594,15 +594,19 @@ select is(
                     "artifacthub_badge": 0,
                     "cla": 67,
                     "community_meeting": 0,
+                    "contributor_diversity": 0,
                     "dco": 67,
                     "github_discussions": 67,
                     "openssf_badge": 67,
                     "openssf_scorecard_badge": 67,
                     "recent_release": 67,
+                    "release_cadence": 0,
+                    "responsiveness": 0,
                     "slack_presence": 0
                 },
                 "security": {
                     "binary_artifacts": 67,
+                    "branch_protection": 0,
                     "code_review": 67,
                     "dangerous_workflow": 67,
                     "dependencies_policy": 67,
@@ -1,11 +1,12 @@
 -- Start transaction and plan tests
 begin;
-select plan(32);
+select plan(36);
 
 -- Check expected extension exist
 select has_extension('pgcrypto');
 
 -- Check expected tables exist
+select has_table('check_cache');
 select has_table('foundation');
 select has_table('project');
 select has_table('project_snapshot');
@@ -14,6 +15,14 @@ select has_table('report');
 select has_table('repository');
 
 -- Check tables have expected columns
+select columns_are('check_cache', array[
+    'repository_url',
+    'repository_path',
+    'check_id',
+    'fingerprint',
+    'output',
+    'updated_at'
+]);
 select columns_are('foundation', array[
     'foundation_id',
     'display_name',
@@ -68,10 +77,14 @@ select columns_are('repository', array[
     'created_at',
     'updated_at',
     'check_sets',
-    'project_id'
+    'project_id',
+    'path'
 ]);
 
 -- Check tables have expected indexes
+select indexes_are('check_cache', array[
+    'check_cache_pkey'
+]);
 select indexes_are('foundation', array[
     'foundation_pkey'
 ]);
@@ -94,12 +107,13 @@ select indexes_are('report', array[
 select indexes_are('repository', array[
     'repository_pkey',
     'repository_project_id_idx',
-    'repository_project_id_url_key'
+    'repository_project_id_url_path_key'
 ]);
 
 -- Check expected functions exist
 -- Notifications
 select has_function('get_pending_annual_review_notifications');
+select has_function('get_pending_exemption_expiry_notifications');
 -- Projects
 select has_function('get_project_by_id');
 select has_function('get_project_by_name');
@@ -1,6 +1,6 @@
 import { ExternalLink, Foundation, Maturity, SampleQuery } from 'clo-ui';
 import { BiLock, BiMedal, BiShieldQuarter, BiTable, BiTrophy, BiWorld } from 'react-icons/bi';
-import { BsCalendar3, BsUiChecks } from 'react-icons/bs';
+import { BsCalendar3, BsCalendarRange, BsChatDots, BsUiChecks } from 'react-icons/bs';
 import { CgFileDocument, CgReadme } from 'react-icons/cg';
 import {
   FaBalanceScale,
@@ -18,9 +18,9 @@ import {
 } from 'react-icons/fa';
 import { FiHexagon } from 'react-icons/fi';
 import { GiFountainPen, GiStamper, GiTiedScroll } from 'react-icons/gi';
-import { GoCommentDiscussion, GoFileBinary, GoLaw } from 'react-icons/go';
+import { GoCommentDiscussion, GoFileBinary, GoGitBranch, GoLaw } from 'react-icons/go';
 import { GrDocumentLocked, GrDocumentText } from 'react-icons/gr';
-import { HiOutlinePencilAlt, HiTerminal } from 'react-icons/hi';
+import { HiOutlinePencilAlt, HiOutlineUserGroup, HiTerminal } from 'react-icons/hi';
 import { ImOffice } from 'react-icons/im';
 import { IoIosPeople, IoMdRibbon } from 'react-icons/io';
 import { MdOutlineInventory, MdPreview } from 'react-icons/md';
@@ -290,6 +290,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>Whether the project has generated executable (binary) artifacts in the source repository</span>,
     reference: '/docs/topics/checks/#binary-artifacts-from-openssf-scorecard',
   },
+  [ReportOption.BranchProtection]: {
+    icon: <GoGitBranch />,
+    name: 'Branch protection',
+    legend: <span>Whether the default branch requires reviews and status checks to pass before merging</span>,
+    reference: '/docs/topics/checks/#branch-protection',
+  },
   [ReportOption.Changelog]: {
     icon: <CgFileDocument />,
     name: 'Changelog',
@@ -330,6 +336,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     ),
     reference: '/docs/topics/checks/#community-meeting',
   },
+  [ReportOption.ContributorDiversity]: {
+    icon: <HiOutlineUserGroup />,
+    name: 'Contributor diversity',
+    legend: <span>Projects should not depend on a single person or organization</span>,
+    reference: '/docs/topics/checks/#contributor-diversity',
+  },
   [ReportOption.Contributing]: {
     icon: <HiTerminal />,
     name: 'Contributing',
@@ -446,6 +458,18 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>The project should have released at least one version in the last year</span>,
     reference: '/docs/topics/checks/#recent-release',
   },
+  [ReportOption.ReleaseCadence]: {
+    icon: <BsCalendarRange />,
+    name: 'Release cadence',
+    legend: <span>The project should publish releases regularly, using SemVer compliant tags</span>,
+    reference: '/docs/topics/checks/#release-cadence',
+  },
+  [ReportOption.Responsiveness]: {
+    icon: <BsChatDots />,
+    name: 'Responsiveness',
+    legend: <span>Maintainers should respond to the issues and pull requests opened by contributors in a timely manner</span>,
+    reference: '/docs/topics/checks/#responsiveness',
+  },
   [ReportOption.Roadmap]: {
     icon: <RiRoadMapLine />,
     name: 'Roadmap',
@@ -567,15 +591,19 @@ export const CHECKS_PER_CATEGORY: ChecksPerCategory = {
     ReportOption.ArtifactHubBadge,
     ReportOption.CLA,
     ReportOption.CommunityMeeting,
+    ReportOption.ContributorDiversity,
     ReportOption.DCO,
     ReportOption.GithubDiscussions,
     ReportOption.OpenSSFBadge,
     ReportOption.OpenSSFScorecardBadge,
     ReportOption.RecentRelease,
+    ReportOption.ReleaseCadence,
+    ReportOption.Responsiveness,
     ReportOption.SlackPresence,
   ],
   [ScoreType.Security]: [
     ReportOption.BinaryArtifacts,
+    ReportOption.BranchProtection,
     ReportOption.CodeReview,
     ReportOption.DangerousWorkflow,
     ReportOption.DependencyUpdateTool,
@@ -146,12 +146,14 @@ export enum ReportOption {
   ApprovedLicense = 'license_approved',
   ArtifactHubBadge = 'artifacthub_badge',
   BinaryArtifacts = 'binary_artifacts',
+  BranchProtection = 'branch_protection',
   Changelog = 'changelog',
   CLA = 'cla',
   CodeOfConduct = 'code_of_conduct',
   CodeReview = 'code_review',
   CommunityMeeting = 'community_meeting',
   Contributing = 'contributing',
+  ContributorDiversity = 'contributor_diversity',
   DangerousWorkflow = 'dangerous_workflow',
   DependenciesPolicy = 'dependencies_policy',
   DependencyUpdateTool = 'dependency_update_tool',
@@ -165,6 +167,8 @@ export enum ReportOption {
   OpenSSFScorecardBadge = 'openssf_scorecard_badge',
   Readme = 'readme',
   RecentRelease = 'recent_release',
+  ReleaseCadence = 'release_cadence',
+  Responsiveness = 'responsiveness',
   Roadmap = 'roadmap',
   SBOM = 'sbom',
   SecurityInsights = 'security_insights',